@@ -0,0 +1,39 @@
+// Tier "sse" is never used, since "sse2" is always available
+
+#[target_feature(enable = "avx512f")]
+unsafe fn copy_avx512f<'a>(dst: &'a mut [u8], src: &[u8]) -> &'a mut [u8] {
+    copy_impl(dst, src)
+}
+
+#[target_feature(enable = "sse2")]
+unsafe fn copy_sse2<'a>(dst: &'a mut [u8], src: &[u8]) -> &'a mut [u8] {
+    copy_impl(dst, src)
+}
+
+fn copy<'a>(dst: &'a mut [u8], src: &[u8]) -> &'a mut [u8] {
+    if cfg!(all(target_feature = "avx512f")) {
+        // SAFETY: these target features are enabled at compile time
+        return unsafe { copy_avx512f(dst, src) };
+    }
+
+    use std::sync::atomic::{AtomicU8, Ordering};
+    static SELECTED: AtomicU8 = AtomicU8::new(0);
+    let selected = match SELECTED.load(Ordering::Relaxed) {
+        0 => {
+            let selected = if cfg!(all(target_feature = "avx512f")) || (std::arch::is_x86_feature_detected!("avx512f")) {
+                1
+            } else {
+                2
+            };
+            SELECTED.store(selected, Ordering::Relaxed);
+            selected
+        }
+        selected => selected,
+    };
+    match selected {
+        // SAFETY: these target features were detected at runtime
+        1 => unsafe { copy_avx512f(dst, src) },
+        // SAFETY: the target enables these target features by default
+        _ => unsafe { copy_sse2(dst, src) },
+    }
+}
@@ -0,0 +1,44 @@
+#[target_feature(enable = "avx2,fma")]
+unsafe fn sum_avx2_fma(values: &[f32]) -> f32 {
+    sum_impl(values)
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn sum_sse4_1(values: &[f32]) -> f32 {
+    sum_impl(values)
+}
+
+fn sum_fallback(values: &[f32]) -> f32 {
+    sum_impl(values)
+}
+
+pub fn sum(values: &[f32]) -> f32 {
+    if cfg!(all(target_feature = "avx2", target_feature = "fma")) {
+        // SAFETY: these target features are enabled at compile time
+        return unsafe { sum_avx2_fma(values) };
+    }
+
+    use std::sync::atomic::{AtomicU8, Ordering};
+    static SELECTED: AtomicU8 = AtomicU8::new(0);
+    let selected = match SELECTED.load(Ordering::Relaxed) {
+        0 => {
+            let selected = if cfg!(all(target_feature = "avx2", target_feature = "fma")) || (std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")) {
+                1
+            } else if cfg!(all(target_feature = "sse4.1")) || (std::arch::is_x86_feature_detected!("sse4.1")) {
+                2
+            } else {
+                3
+            };
+            SELECTED.store(selected, Ordering::Relaxed);
+            selected
+        }
+        selected => selected,
+    };
+    match selected {
+        // SAFETY: these target features were detected at runtime
+        1 => unsafe { sum_avx2_fma(values) },
+        // SAFETY: these target features were detected at runtime
+        2 => unsafe { sum_sse4_1(values) },
+        _ => sum_fallback(values),
+    }
+}
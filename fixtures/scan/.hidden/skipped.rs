@@ -0,0 +1,2 @@
+#[target_feature(enable = "skipped")]
+fn skipped() {}
@@ -0,0 +1,17 @@
+/* A block comment #[cfg(target_feature = "avx3")]
+   /* nested */ */
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2", enable = "fma")]
+pub(crate) unsafe fn sum_avx2<'a>(values: &'a [f32]) -> f32 {
+    values.iter().sum()
+}
+
+#[cfg(any(target_feature = "avx512", target_feature = "avx512f"))]
+#[target_feature(enable = "avx512vl,avx512bw")]
+pub(crate) unsafe fn sum_avx512(values: &[f32]) -> f32 {
+    values.iter().sum()
+}
+
+#[target_feature(enable = "sse4")]
+pub(crate) unsafe fn typo() {}
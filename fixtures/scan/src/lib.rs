@@ -0,0 +1,25 @@
+//! A crate using target features.
+
+mod arch;
+
+/// Sums `values`, using `target_feature = "avx2"` where possible.
+pub fn sum(values: &[f32]) -> f32 {
+    #[cfg(target_arch = "x86_64")]
+    if is_x86_feature_detected!("avx2") {
+        return unsafe { arch::sum_avx2(values) };
+    }
+    if cfg!(target_feature = "sse4.1") {
+        // /* not a comment */ target_feature = "comment"
+        return values.iter().sum();
+    }
+    values.iter().sum()
+}
+
+#[cfg_attr(all(target_arch = "x86_64", target_feature = "avx512f"), inline(always))]
+#[cfg_attr(target_arch = "aarch64", target_feature(enable = "neon,fp16"))]
+pub fn product(values: &[f32]) -> f32 {
+    let s = "target_feature = \"string\"";
+    let r = r#"#[target_feature(enable = "raw")]"#;
+    let _ = (s, r, 'x', '\'');
+    values.iter().product()
+}
@@ -0,0 +1,183 @@
+use anyhow::{Context, Result, bail};
+use rust_target_feature_data::cargo_config::{self, CargoConfig, Environment};
+use rust_target_feature_data::multiversion;
+use rust_target_feature_data::scan;
+use rust_target_feature_data::toolchain::Toolchain;
+use std::path::Path;
+
+const USAGE: &str = "\
+Usage: rust-target-feature-data <COMMAND> [OPTIONS]
+
+Commands:
+  scan --rust-version <VERSION> --target <TARGET>... [PATH]
+      Check #[target_feature] and cfg(target_feature) usage in .rs files under PATH, which
+      defaults to the current directory. Exits with status 1 if any problems are found.
+
+  config --rust-version <VERSION> [--target <TARGET>]... [PATH]
+      Check target features enabled by RUSTFLAGS, CARGO_ENCODED_RUSTFLAGS, and the Cargo
+      configuration file PATH, which defaults to .cargo/config.toml. Targets configured in the
+      file are always checked. Exits with status 1 if any problems are found.
+
+  multiversion --rust-version <VERSION> --target <TARGET> <SIGNATURE> <TIER>...
+      Print Rust source multiversioning the function SIGNATURE, like \"fn sum(v: &[f32]) -> f32\",
+      over each TIER of comma-separated target features, best first. Every version calls
+      <name>_impl, which must be written by hand and marked #[inline(always)].
+
+If --rust-version or --target is omitted, the channel or targets are read from rust-toolchain.toml
+or rust-toolchain in the current directory.
+";
+
+fn main() {
+    match run(std::env::args().skip(1)) {
+        Ok(true) => {}
+        Ok(false) => std::process::exit(1),
+        Err(e) => {
+            eprintln!("error: {:#}\n\n{}", e, USAGE);
+            std::process::exit(2);
+        }
+    }
+}
+
+/// Run a command, returning whether it succeeded.
+fn run(mut args: impl Iterator<Item = String>) -> Result<bool> {
+    match args.next().as_deref() {
+        Some("scan") => scan(Options::parse(args)?),
+        Some("config") => config(Options::parse(args)?),
+        Some("multiversion") => multiversion(Options::parse(args)?),
+        Some("help" | "--help" | "-h") => {
+            print!("{}", USAGE);
+            Ok(true)
+        }
+        Some(command) => bail!("unknown command {:?}", command),
+        None => bail!("no command given"),
+    }
+}
+
+#[derive(Debug, Default)]
+struct Options {
+    rust_version: Option<String>,
+    targets: Vec<String>,
+    positional: Vec<String>,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self> {
+        let mut options = Options::default();
+        while let Some(arg) = args.next() {
+            let (flag, value) = match arg.split_once('=') {
+                Some((flag, value)) if flag.starts_with("--") => {
+                    (flag.to_string(), Some(value.into()))
+                }
+                _ => (arg, None),
+            };
+            let value = || {
+                value
+                    .or_else(|| args.next())
+                    .with_context(|| format!("{} requires a value", flag))
+            };
+            match flag.as_str() {
+                "--rust-version" => options.rust_version = Some(value()?),
+                "--target" => options.targets.push(value()?),
+                _ if flag.starts_with("--") => bail!("unknown option {:?}", flag),
+                _ => options.positional.push(flag),
+            }
+        }
+        Ok(options)
+    }
+
+    fn toolchain() -> Result<Toolchain> {
+        Toolchain::load(Path::new(".")).context("reading the toolchain file")
+    }
+
+    fn rust_version(&self) -> Result<&str> {
+        match &self.rust_version {
+            Some(rust_version) => Ok(rust_version),
+            None => Ok(Self::toolchain()
+                .context("--rust-version was not given")?
+                .rust_version),
+        }
+    }
+
+    fn targets(&self) -> Result<Vec<String>> {
+        if !self.targets.is_empty() {
+            return Ok(self.targets.clone());
+        }
+        let targets = Self::toolchain().context("--target was not given")?.targets;
+        if targets.is_empty() {
+            bail!("at least one --target is required");
+        }
+        Ok(targets)
+    }
+}
+
+fn scan(options: Options) -> Result<bool> {
+    let path = match options.positional.as_slice() {
+        [] => ".",
+        [path] => path.as_str(),
+        _ => bail!("scan accepts at most one path"),
+    };
+
+    let usages = scan::scan_dir(Path::new(path)).with_context(|| format!("scanning {}", path))?;
+    let targets = options.targets()?;
+    let targets: Vec<&str> = targets.iter().map(String::as_str).collect();
+    let problems = scan::check(options.rust_version()?, &targets, &usages)?;
+    for problem in &problems {
+        println!("{}", problem);
+    }
+    eprintln!(
+        "{} target feature usages, {} problems",
+        usages.len(),
+        problems.len()
+    );
+    Ok(problems.is_empty())
+}
+
+fn config(options: Options) -> Result<bool> {
+    let path = match options.positional.as_slice() {
+        [] => ".cargo/config.toml",
+        [path] => path.as_str(),
+        _ => bail!("config accepts at most one path"),
+    };
+
+    let config = CargoConfig::load(Path::new(path)).with_context(|| format!("reading {}", path))?;
+    let targets: Vec<&str> = options.targets.iter().map(String::as_str).collect();
+    let findings = cargo_config::check(
+        options.rust_version()?,
+        &config,
+        &Environment::from_env(),
+        &targets,
+    )?;
+    for finding in &findings {
+        println!("{}", finding);
+    }
+    eprintln!("{} problems", findings.len());
+    Ok(findings.is_empty())
+}
+
+fn multiversion(options: Options) -> Result<bool> {
+    let Some((signature, tiers)) = options.positional.split_first() else {
+        bail!("multiversion requires a function signature");
+    };
+    let target = match options.targets()?.as_slice() {
+        [target] => target.clone(),
+        _ => bail!("multiversion requires exactly one target"),
+    };
+
+    let tiers: Vec<Vec<&str>> = tiers
+        .iter()
+        .map(|tier| tier.split(',').filter(|f| !f.is_empty()).collect())
+        .collect();
+    let tiers: Vec<&[&str]> = tiers.iter().map(Vec::as_slice).collect();
+    let generated = multiversion::generate(options.rust_version()?, &target, signature, &tiers)?;
+    print!("{}", generated.source);
+    for gate in &generated.feature_gates {
+        eprintln!("requires #![feature({})]", gate);
+    }
+    for tier in &generated.unreachable_tiers {
+        eprintln!(
+            "tier {} is never used, since an earlier tier is always available",
+            tier.join(",")
+        );
+    }
+    Ok(true)
+}
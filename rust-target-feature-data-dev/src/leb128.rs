@@ -0,0 +1,30 @@
+//! Unsigned LEB128, the variable-length integer encoding of the generated `.blob` files.
+//!
+//! Each byte holds 7 bits of the value, least significant first, and has its high bit set if more
+//! bytes follow. Values below 128 take one byte, below 16384 two, and so on, so the blobs have no
+//! fixed limit on the number of names, features, or lists.
+
+#[cfg(test)]
+mod tests;
+
+/// Append `value` to `output`.
+pub fn encode(mut value: usize, output: &mut Vec<u8>) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            output.push(byte);
+            return;
+        }
+        output.push(byte | 0x80);
+    }
+}
+
+/// Encode a sequence of values.
+pub fn encode_all(values: impl IntoIterator<Item = usize>) -> Vec<u8> {
+    let mut output = Vec::new();
+    for value in values {
+        encode(value, &mut output);
+    }
+    output
+}
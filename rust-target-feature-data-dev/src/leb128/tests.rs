@@ -0,0 +1,29 @@
+use super::*;
+
+#[test]
+fn boundaries() {
+    let cases: &[(usize, &[u8])] = &[
+        (0, &[0x00]),
+        (1, &[0x01]),
+        (127, &[0x7f]),
+        (128, &[0x80, 0x01]),
+        (16383, &[0xff, 0x7f]),
+        (16384, &[0x80, 0x80, 0x01]),
+        (32767, &[0xff, 0xff, 0x01]),
+        (32768, &[0x80, 0x80, 0x02]),
+        (65535, &[0xff, 0xff, 0x03]),
+        (65536, &[0x80, 0x80, 0x04]),
+        (u32::MAX as usize, &[0xff, 0xff, 0xff, 0xff, 0x0f]),
+    ];
+    for (value, expected) in cases {
+        assert_eq!(encode_all([*value]), *expected, "{}", value);
+    }
+}
+
+#[test]
+fn sequence() {
+    assert_eq!(
+        encode_all([300, 0, 65536]),
+        [0xac, 0x02, 0x00, 0x80, 0x80, 0x04]
+    );
+}
@@ -1,51 +1,270 @@
 #![forbid(unsafe_code)]
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
 use std::path::PathBuf;
 
+pub mod leb128;
+pub mod rustc_print;
+pub mod rustdoc_json;
+mod validate;
+
+pub use validate::{KNOWN_QUIRKS, Problem, validate};
+
 #[derive(Debug, Clone, Ord, Eq, PartialOrd, PartialEq, serde::Deserialize)]
 pub struct Compiler {
     pub version: String,
+    /// The `data/` directory this compiler was loaded from, e.g. `1.87.0-beta.5`.
+    pub directory: String,
+    /// Other `data/` directories whose contents are byte-identical to this one.
+    pub aliases: BTreeSet<String>,
     pub targets: BTreeSet<Target>,
+    pub llvm: LlvmMapping,
+    pub detect: Detect,
 }
 
 #[derive(Debug, Clone, Ord, Eq, PartialOrd, PartialEq, serde::Deserialize)]
 pub struct Target {
     pub triple: String,
+    /// The file this target was loaded from.
+    #[serde(skip)]
+    pub path: PathBuf,
     pub target_features: BTreeSet<TargetFeature>,
 }
 
 #[derive(Debug, Clone, Ord, Eq, PartialOrd, PartialEq, serde::Deserialize)]
 pub struct TargetFeature {
     pub name: String,
+    // Required even though it's optional, so data ingested without gates fails to load
+    #[serde(deserialize_with = "Option::deserialize")]
     pub unstable_feature_gate: Option<String>,
-    pub implies_features: BTreeSet<String>,
+    /// `None` if the data doesn't record implications, which is the case for 1.81.0.
+    #[serde(deserialize_with = "Option::deserialize")]
+    pub implies_features: Option<BTreeSet<String>>,
     pub globally_enabled: bool,
 }
 
+#[derive(Debug, Clone, Ord, Eq, PartialOrd, PartialEq, serde::Deserialize)]
+pub struct LlvmMapping {
+    pub llvm_version: String,
+    pub features: BTreeMap<String, LlvmFeatures>,
+}
+
+#[derive(Debug, Clone, Ord, Eq, PartialOrd, PartialEq, serde::Deserialize)]
+#[serde(untagged)]
+pub enum LlvmFeatures {
+    All(Vec<String>),
+    ByArch(BTreeMap<String, Vec<String>>),
+}
+
+#[derive(Debug, Clone, Ord, Eq, PartialOrd, PartialEq, serde::Deserialize)]
+pub struct Detect {
+    pub macros: Vec<DetectMacro>,
+}
+
+#[derive(Debug, Clone, Ord, Eq, PartialOrd, PartialEq, serde::Deserialize)]
+pub struct DetectMacro {
+    pub name: String,
+    pub target_arches: Vec<String>,
+    pub unstable_feature_gate: Option<String>,
+    pub features: Vec<DetectableFeature>,
+    pub aliases: BTreeMap<String, String>,
+    pub no_runtime_detection: Vec<String>,
+}
+
+#[derive(Debug, Clone, Ord, Eq, PartialOrd, PartialEq, serde::Deserialize)]
+pub struct DetectableFeature {
+    pub name: String,
+    pub unstable_feature_gate: Option<String>,
+}
+
+/// A Rust version named by a `data/` directory, like `1.86.0` or `1.87.0-beta.5`.
+#[derive(Debug, Clone, Copy, Ord, Eq, PartialOrd, PartialEq, Hash)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+    pub channel: Channel,
+}
+
+/// The release channel of a [`Version`]. Betas sort before the release they lead up to.
+#[derive(Debug, Clone, Copy, Ord, Eq, PartialOrd, PartialEq, Hash)]
+pub enum Channel {
+    Beta(u32),
+    Stable,
+}
+
+impl Version {
+    /// Parse a directory name like `1.86.0` or `1.87.0-beta.5`.
+    pub fn parse(name: &str) -> Option<Self> {
+        let (release, channel) = match name.split_once('-') {
+            Some((release, beta)) => (
+                release,
+                Channel::Beta(beta.strip_prefix("beta.")?.parse().ok()?),
+            ),
+            None => (name, Channel::Stable),
+        };
+        let mut parts = release.split('.').map(|part| part.parse::<u32>().ok());
+        let version = Version {
+            major: parts.next()??,
+            minor: parts.next()??,
+            patch: parts.next()??,
+            channel,
+        };
+        parts.next().is_none().then_some(version)
+    }
+
+    /// The release this version is or leads up to, e.g. `1.87.0` for `1.87.0-beta.5`.
+    pub fn release(&self) -> String {
+        format!("{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+impl std::fmt::Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.release())?;
+        match self.channel {
+            Channel::Beta(n) => write!(f, "-beta.{}", n),
+            Channel::Stable => Ok(()),
+        }
+    }
+}
+
+/// A `data/` directory holding target feature data for one Rust version.
+#[derive(Debug, Clone, Ord, Eq, PartialOrd, PartialEq)]
+pub struct Dataset {
+    pub version: Version,
+    pub path: PathBuf,
+    /// The earlier dataset with byte-identical contents, if any.
+    pub alias_of: Option<Version>,
+}
+
+/// `data/manifest.json`, which lists the datasets to ship.
+#[derive(Debug, Clone, Ord, Eq, PartialOrd, PartialEq, serde::Deserialize)]
+pub struct Manifest {
+    /// `data/` directory names, like `1.87.0-beta.5`. Each ships as its release version.
+    pub ship: Vec<String>,
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum LoadError {
     #[error("reading directory: {0}")]
     ReadDirectory(std::io::Error),
     #[error("reading file: {0}")]
     ReadFile(std::io::Error),
-    #[error("deserializing target {0}: {1}")]
+    #[error("deserializing {0}: {1}")]
     Deserialize(PathBuf, serde_json::Error),
+    #[error("data/manifest.json: {0} is not a dataset in data/")]
+    UnknownDataset(String),
+    #[error("data/manifest.json: {0} and {1} both ship as {2}")]
+    DuplicateRelease(String, String, String),
+    #[error("{}", format_problems(.0))]
+    Invalid(Vec<Problem>),
 }
 
+fn format_problems(problems: &[Problem]) -> String {
+    let mut message = format!("{} problems found", problems.len());
+    for problem in problems {
+        message.push_str(&format!("\n{}", problem));
+    }
+    message
+}
+
+/// Load the datasets listed in `data/manifest.json`.
+///
+/// The data isn't checked; use [`validate`] to find problems, or [`load_strict`] to fail on them.
 pub fn load() -> Result<BTreeSet<Compiler>, LoadError> {
-    [
-        ("1.85.0", "1.85.0"),
-        ("1.86.0", "1.86.0"),
-        ("1.87.0", "1.87.0-beta.5"),
-    ]
-    .into_iter()
-    .map(|(name, path)| {
-        let path = PathBuf::from("data").join(path);
-        load_compiler(name, path)
-    })
-    .collect()
+    load_checked(false)
+}
+
+/// Load the datasets listed in `data/manifest.json`, failing with [`LoadError::Invalid`] if any
+/// of them has a problem, other than [`KNOWN_QUIRKS`].
+pub fn load_strict() -> Result<BTreeSet<Compiler>, LoadError> {
+    load_checked(true)
+}
+
+fn load_checked(strict: bool) -> Result<BTreeSet<Compiler>, LoadError> {
+    let manifest: Manifest = load_json(PathBuf::from("data").join("manifest.json"))?;
+    let datasets = discover()?;
+
+    let mut compilers = BTreeSet::new();
+    let mut releases: BTreeMap<String, &str> = BTreeMap::new();
+    for name in &manifest.ship {
+        let dataset = datasets
+            .iter()
+            .find(|dataset| dataset.version.to_string() == *name)
+            .ok_or_else(|| LoadError::UnknownDataset(name.clone()))?;
+        let release = dataset.version.release();
+        if let Some(other) = releases.insert(release.clone(), name) {
+            return Err(LoadError::DuplicateRelease(
+                other.into(),
+                name.clone(),
+                release,
+            ));
+        }
+
+        let mut compiler = load_compiler(&release, dataset.path.clone())?;
+        compiler.directory = name.clone();
+        compiler.aliases = datasets
+            .iter()
+            .filter(|other| other.alias_of == Some(dataset.version))
+            .map(|other| other.version.to_string())
+            .collect();
+        compilers.insert(compiler);
+    }
+
+    if strict {
+        let problems: Vec<Problem> = compilers
+            .iter()
+            .flat_map(validate)
+            .filter(|problem| !problem.known_quirk)
+            .collect();
+        if !problems.is_empty() {
+            return Err(LoadError::Invalid(problems));
+        }
+    }
+    Ok(compilers)
+}
+
+/// Find every dataset in `data/`, oldest first.
+///
+/// Directories which aren't named like versions, like `data/llvm/`, are skipped. A dataset whose
+/// files are byte-identical to an earlier dataset's is recorded as an alias of it.
+pub fn discover() -> Result<Vec<Dataset>, LoadError> {
+    let mut datasets = Vec::new();
+    for entry in fs::read_dir("data").map_err(LoadError::ReadDirectory)? {
+        let entry = entry.map_err(LoadError::ReadDirectory)?;
+        let file_name = entry.file_name();
+        let Some(version) = file_name.to_str().and_then(Version::parse) else {
+            continue;
+        };
+        if entry.path().is_dir() {
+            datasets.push(Dataset {
+                version,
+                path: entry.path(),
+                alias_of: None,
+            });
+        }
+    }
+    datasets.sort();
+
+    let mut contents: Vec<BTreeMap<std::ffi::OsString, Vec<u8>>> = Vec::new();
+    for i in 0..datasets.len() {
+        let mut files = BTreeMap::new();
+        for entry in fs::read_dir(&datasets[i].path).map_err(LoadError::ReadDirectory)? {
+            let entry = entry.map_err(LoadError::ReadDirectory)?;
+            files.insert(
+                entry.file_name(),
+                fs::read(entry.path()).map_err(LoadError::ReadFile)?,
+            );
+        }
+        datasets[i].alias_of = contents
+            .iter()
+            .position(|earlier| *earlier == files)
+            .map(|j| datasets[j].version);
+        contents.push(files);
+    }
+    Ok(datasets)
 }
 
 fn load_compiler(version: &str, path: PathBuf) -> Result<Compiler, LoadError> {
@@ -70,11 +289,29 @@ fn load_compiler(version: &str, path: PathBuf) -> Result<Compiler, LoadError> {
 
     Ok(Compiler {
         version: version.into(),
+        directory: String::new(),
+        aliases: BTreeSet::new(),
         targets,
+        llvm: load_json(
+            PathBuf::from("data")
+                .join("llvm")
+                .join(format!("{}.json", version)),
+        )?,
+        detect: load_json(
+            PathBuf::from("data")
+                .join("detect")
+                .join(format!("{}.json", version)),
+        )?,
     })
 }
 
 fn load_target(path: PathBuf) -> Result<Target, LoadError> {
+    let mut target: Target = load_json(path.clone())?;
+    target.path = path;
+    Ok(target)
+}
+
+fn load_json<T: serde::de::DeserializeOwned>(path: PathBuf) -> Result<T, LoadError> {
     let bytes: Vec<u8> = std::fs::read(&path).map_err(LoadError::ReadFile)?;
     serde_json::from_slice(&bytes).map_err(|e| LoadError::Deserialize(path, e))
 }
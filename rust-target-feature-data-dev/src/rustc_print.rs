@@ -0,0 +1,203 @@
+//! Reading captured `rustc --print target-features` and `rustc --print cfg` output.
+//!
+//! This works with any compiler, but determines less than rustdoc's JSON does. Target feature
+//! names are printed, but implications and unstable feature gates are not. Globally enabled
+//! features are printed as `target_feature="…"` cfgs, though a stable compiler only prints stable
+//! features unless `RUSTC_BOOTSTRAP=1` is set.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[cfg(test)]
+mod tests;
+
+/// A target's features, as determined from `rustc --print` output.
+#[derive(Debug, Clone, Ord, Eq, PartialOrd, PartialEq, serde::Serialize)]
+pub struct PrintedTarget {
+    pub triple: String,
+    pub target_features: Vec<PrintedFeature>,
+}
+
+/// A target feature, as determined from `rustc --print` output.
+///
+/// Fields which couldn't be determined are `None`. Undetermined gates and `globally_enabled` flags
+/// are left out of the serialized JSON, so the data can't be loaded until they're filled in.
+/// Undetermined implications serialize as `null`, which the data format allows.
+#[derive(Debug, Clone, Ord, Eq, PartialOrd, PartialEq, serde::Serialize)]
+pub struct PrintedFeature {
+    pub name: String,
+    /// Always `None`, since `rustc` doesn't print implications.
+    pub implies_features: Option<BTreeSet<String>>,
+    /// Always `None`, since `rustc` doesn't print gates.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unstable_feature_gate: Option<Option<String>>,
+    /// `None` if the feature may be unstable and `--print cfg` didn't print unstable cfgs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub globally_enabled: Option<bool>,
+}
+
+/// The parts of `rustc --print cfg` output which describe target features.
+#[derive(Debug, Clone, Ord, Eq, PartialOrd, PartialEq)]
+pub struct Cfg {
+    /// The `target_feature="…"` values.
+    pub target_features: BTreeSet<String>,
+    /// Whether unstable cfgs were printed, as by nightly compilers or with `RUSTC_BOOTSTRAP=1`.
+    pub shows_unstable: bool,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum PrintError {
+    #[error("no \"Features supported by rustc for this target\" section")]
+    MissingFeatures,
+    #[error("unexpected line in target features: {0:?}")]
+    UnexpectedFeature(String),
+    #[error("unexpected line in cfg: {0:?}")]
+    UnexpectedCfg(String),
+    #[error("no target_arch cfg")]
+    MissingArch,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum IngestError {
+    #[error("reading {0}: {1}")]
+    Read(PathBuf, std::io::Error),
+    #[error("writing {0}: {1}")]
+    Write(PathBuf, std::io::Error),
+    #[error("parsing {0}: {1}")]
+    Parse(PathBuf, PrintError),
+}
+
+/// Read target feature names from `rustc --print target-features --target <triple>` output.
+///
+/// Only the features supported by rustc are returned, not LLVM's code generation features.
+/// `crt-static` is skipped, since it's a linking option rather than a target feature.
+pub fn parse_target_features(text: &str) -> Result<Vec<String>, PrintError> {
+    let mut lines = text
+        .lines()
+        .skip_while(|line| !line.starts_with("Features supported by rustc for this target:"));
+    if lines.next().is_none() {
+        return Err(PrintError::MissingFeatures);
+    }
+
+    let mut names = Vec::new();
+    for line in lines.take_while(|line| !line.trim().is_empty()) {
+        let name = match line.split_once(" - ") {
+            Some((name, _)) if !name.trim().is_empty() && line.starts_with(' ') => name.trim(),
+            _ => return Err(PrintError::UnexpectedFeature(line.into())),
+        };
+        if name != "crt-static" {
+            names.push(name.to_string());
+        }
+    }
+    Ok(names)
+}
+
+/// Read target feature cfgs from `rustc --print cfg --target <triple>` output.
+pub fn parse_cfg(text: &str) -> Result<Cfg, PrintError> {
+    let mut cfg = Cfg {
+        target_features: BTreeSet::new(),
+        shows_unstable: false,
+    };
+    let mut has_arch = false;
+    for line in text.lines().filter(|line| !line.is_empty()) {
+        let (key, value) = match line.split_once('=') {
+            Some((key, value)) => match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+                Some(value) => (key, Some(value)),
+                None => return Err(PrintError::UnexpectedCfg(line.into())),
+            },
+            None => (line, None),
+        };
+        if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(PrintError::UnexpectedCfg(line.into()));
+        }
+        match (key, value) {
+            ("target_feature", Some(value)) => {
+                cfg.target_features.insert(value.into());
+            }
+            ("target_arch", Some(_)) => has_arch = true,
+            // `relocation_model` is unstable, so it's printed only when unstable cfgs are
+            ("relocation_model", Some(_)) => cfg.shows_unstable = true,
+            _ => {}
+        }
+    }
+    if !has_arch {
+        return Err(PrintError::MissingArch);
+    }
+    Ok(cfg)
+}
+
+/// Combine the `--print target-features` and `--print cfg` output of a target.
+pub fn printed_target(
+    triple: &str,
+    target_features: &str,
+    cfg: &str,
+) -> Result<PrintedTarget, PrintError> {
+    Ok(combine(
+        triple,
+        parse_target_features(target_features)?,
+        &parse_cfg(cfg)?,
+    ))
+}
+
+fn combine(triple: &str, names: Vec<String>, cfg: &Cfg) -> PrintedTarget {
+    let target_features = names
+        .into_iter()
+        .map(|name| {
+            let globally_enabled = if cfg.target_features.contains(&name) {
+                Some(true)
+            } else if cfg.shows_unstable {
+                Some(false)
+            } else {
+                None
+            };
+            PrintedFeature {
+                name,
+                implies_features: None,
+                unstable_feature_gate: None,
+                globally_enabled,
+            }
+        })
+        .collect();
+    PrintedTarget {
+        triple: triple.into(),
+        target_features,
+    }
+}
+
+/// Convert each `<triple>.target-features.txt` and `<triple>.cfg.txt` pair in `input` to
+/// `<triple>.json` in `output`, returning the paths written.
+pub fn ingest(input: &Path, output: &Path) -> Result<Vec<PathBuf>, IngestError> {
+    let read =
+        |path: &Path| fs::read_to_string(path).map_err(|e| IngestError::Read(path.into(), e));
+
+    let mut triples = Vec::new();
+    for entry in fs::read_dir(input).map_err(|e| IngestError::Read(input.into(), e))? {
+        let entry = entry.map_err(|e| IngestError::Read(input.into(), e))?;
+        let file_name = entry.file_name();
+        if let Some(triple) = file_name
+            .to_str()
+            .and_then(|name| name.strip_suffix(".target-features.txt"))
+        {
+            triples.push(triple.to_string());
+        }
+    }
+    triples.sort();
+
+    fs::create_dir_all(output).map_err(|e| IngestError::Write(output.into(), e))?;
+    let mut written = Vec::new();
+    for triple in triples {
+        let features_path = input.join(format!("{}.target-features.txt", triple));
+        let cfg_path = input.join(format!("{}.cfg.txt", triple));
+        let names = parse_target_features(&read(&features_path)?)
+            .map_err(|e| IngestError::Parse(features_path, e))?;
+        let cfg = parse_cfg(&read(&cfg_path)?).map_err(|e| IngestError::Parse(cfg_path, e))?;
+
+        let path = output.join(format!("{}.json", triple));
+        let mut json = serde_json::to_string_pretty(&combine(&triple, names, &cfg)).unwrap();
+        json.push('\n');
+        fs::write(&path, json).map_err(|e| IngestError::Write(path.clone(), e))?;
+        written.push(path);
+    }
+    Ok(written)
+}
@@ -0,0 +1,115 @@
+use super::*;
+use crate::Target;
+
+const X86_64_FEATURES: &str = include_str!(
+    "../../../fixtures/rustc_print/stable/x86_64-unknown-linux-gnu.target-features.txt"
+);
+const X86_64_CFG: &str =
+    include_str!("../../../fixtures/rustc_print/stable/x86_64-unknown-linux-gnu.cfg.txt");
+const X86_64_BOOTSTRAP_CFG: &str =
+    include_str!("../../../fixtures/rustc_print/bootstrap/x86_64-unknown-linux-gnu.cfg.txt");
+const X86_64_JSON: &str = include_str!("../../../data/1.86.0/x86_64-unknown-linux-gnu.json");
+
+fn names(target: &Target) -> Vec<&str> {
+    target
+        .target_features
+        .iter()
+        .map(|f| f.name.as_str())
+        .collect()
+}
+
+#[test]
+fn target_features() {
+    let expected: Target = serde_json::from_str(X86_64_JSON).unwrap();
+    assert_eq!(
+        parse_target_features(X86_64_FEATURES).unwrap(),
+        names(&expected)
+    );
+
+    assert!(matches!(
+        parse_target_features(X86_64_CFG),
+        Err(PrintError::MissingFeatures)
+    ));
+}
+
+#[test]
+fn cfg() {
+    let cfg = parse_cfg(X86_64_CFG).unwrap();
+    assert_eq!(
+        cfg.target_features,
+        BTreeSet::from(["fxsr".into(), "sse".into(), "sse2".into()])
+    );
+    assert!(!cfg.shows_unstable);
+
+    let cfg = parse_cfg(X86_64_BOOTSTRAP_CFG).unwrap();
+    assert!(cfg.target_features.contains("x87"));
+    assert!(cfg.shows_unstable);
+
+    assert!(matches!(
+        parse_cfg(X86_64_FEATURES),
+        Err(PrintError::UnexpectedCfg(_))
+    ));
+}
+
+#[test]
+fn globally_enabled() {
+    let expected: Target = serde_json::from_str(X86_64_JSON).unwrap();
+
+    // Unstable cfgs are printed, so every feature is determined
+    let target = printed_target(
+        "x86_64-unknown-linux-gnu",
+        X86_64_FEATURES,
+        X86_64_BOOTSTRAP_CFG,
+    )
+    .unwrap();
+    for (printed, expected) in target.target_features.iter().zip(&expected.target_features) {
+        assert_eq!(printed.globally_enabled, Some(expected.globally_enabled));
+        assert_eq!(printed.implies_features, None);
+        assert_eq!(printed.unstable_feature_gate, None);
+    }
+
+    // x87 is unstable, so it's missing from stable cfg output
+    let target = printed_target("x86_64-unknown-linux-gnu", X86_64_FEATURES, X86_64_CFG).unwrap();
+    let feature = |name| {
+        target
+            .target_features
+            .iter()
+            .find(|f| f.name == name)
+            .unwrap()
+            .globally_enabled
+    };
+    assert_eq!(feature("sse2"), Some(true));
+    assert_eq!(feature("x87"), None);
+    assert_eq!(feature("avx2"), None);
+}
+
+#[test]
+fn ingest_marks_undetermined() {
+    let output = std::env::temp_dir().join(format!("rustc_print-{}", std::process::id()));
+    let written = ingest(Path::new("../fixtures/rustc_print/bootstrap"), &output).unwrap();
+    assert_eq!(
+        written,
+        [
+            output.join("aarch64-unknown-linux-gnu.json"),
+            output.join("x86_64-unknown-linux-gnu.json"),
+        ]
+    );
+
+    // Gates are undetermined, so the ingested data can't be loaded until they're filled in
+    let json = fs::read_to_string(&written[1]).unwrap();
+    let error = serde_json::from_str::<Target>(&json).unwrap_err();
+    assert!(error.to_string().contains("unstable_feature_gate"));
+
+    let json = json.replace(
+        "\"implies_features\": null,",
+        "\"implies_features\": null,\n      \"unstable_feature_gate\": null,",
+    );
+    let target: Target = serde_json::from_str(&json).unwrap();
+    assert!(
+        target
+            .target_features
+            .iter()
+            .all(|f| f.implies_features.is_none())
+    );
+    fs::remove_dir_all(output).unwrap();
+}
@@ -0,0 +1,149 @@
+//! Reading target feature data from rustdoc's JSON output.
+//!
+//! Since 1.88, `rustdoc --output-format json` describes the target it documented for, in the same
+//! format as `data/`. Only the `target` object is read; the rest of the crate's documentation is
+//! ignored.
+
+use crate::Target;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[cfg(test)]
+mod tests;
+
+/// The newest rustdoc JSON format version known to describe targets as `data/` expects.
+///
+/// Newer versions are rejected rather than risk misreading them. If the `target` object is
+/// unchanged, raise this.
+pub const LATEST_FORMAT_VERSION: u32 = 57;
+
+#[derive(thiserror::Error, Debug)]
+pub enum RustdocError {
+    #[error("reading {0}: {1}")]
+    Read(PathBuf, std::io::Error),
+    #[error("writing {0}: {1}")]
+    Write(PathBuf, std::io::Error),
+    #[error("deserializing {0}: {1}")]
+    Deserialize(PathBuf, serde_json::Error),
+    #[error("{0}: format version {1} is newer than {LATEST_FORMAT_VERSION}")]
+    UnsupportedFormatVersion(PathBuf, u32),
+    #[error("{0}: format version {1} doesn't describe the target")]
+    MissingTarget(PathBuf, u32),
+    #[error("{0} and {1} describe {2} differently")]
+    Conflict(PathBuf, PathBuf, String),
+}
+
+#[derive(serde::Deserialize)]
+struct Crate {
+    format_version: u32,
+    target: Option<serde_json::Value>,
+}
+
+/// Read the target described by a rustdoc JSON file.
+pub fn read(path: &Path) -> Result<Target, RustdocError> {
+    Ok(load(path)?.0)
+}
+
+/// Read the targets described by a rustdoc JSON file, or every `.json` file in a directory.
+///
+/// Files describing the same target must agree.
+pub fn read_all(path: &Path) -> Result<Vec<Target>, RustdocError> {
+    Ok(load_all(path)?
+        .into_iter()
+        .map(|(target, _)| target)
+        .collect())
+}
+
+/// Write the targets described by rustdoc JSON at `input` to `<triple>.json` files in `output`,
+/// returning the paths written.
+///
+/// Files are written like the rest of `data/`, keeping rustdoc's order of implied features.
+pub fn ingest(input: &Path, output: &Path) -> Result<Vec<PathBuf>, RustdocError> {
+    let targets = load_all(input)?;
+    fs::create_dir_all(output).map_err(|e| RustdocError::Write(output.into(), e))?;
+
+    let mut written = Vec::new();
+    for (target, value) in targets {
+        let file = TargetFile::deserialize(&value)
+            .map_err(|e| RustdocError::Deserialize(target.path.clone(), e))?;
+        let path = output.join(format!("{}.json", target.triple));
+        let mut json = serde_json::to_string_pretty(&file).unwrap();
+        json.push('\n');
+        fs::write(&path, json).map_err(|e| RustdocError::Write(path.clone(), e))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+/// A target as written in `data/`, with fields and implied features in rustdoc's order.
+#[derive(serde::Deserialize, serde::Serialize)]
+struct TargetFile {
+    triple: String,
+    target_features: Vec<FeatureFile>,
+}
+
+#[derive(serde::Deserialize, serde::Serialize)]
+struct FeatureFile {
+    name: String,
+    implies_features: Option<Vec<String>>,
+    unstable_feature_gate: Option<String>,
+    globally_enabled: bool,
+}
+
+/// Read a rustdoc JSON file, returning its target and the target's JSON.
+fn load(path: &Path) -> Result<(Target, serde_json::Value), RustdocError> {
+    let bytes = fs::read(path).map_err(|e| RustdocError::Read(path.into(), e))?;
+    let krate: Crate =
+        serde_json::from_slice(&bytes).map_err(|e| RustdocError::Deserialize(path.into(), e))?;
+    if krate.format_version > LATEST_FORMAT_VERSION {
+        return Err(RustdocError::UnsupportedFormatVersion(
+            path.into(),
+            krate.format_version,
+        ));
+    }
+    let value = krate.target.ok_or(RustdocError::MissingTarget(
+        path.into(),
+        krate.format_version,
+    ))?;
+    let mut target =
+        Target::deserialize(&value).map_err(|e| RustdocError::Deserialize(path.into(), e))?;
+    target.path = path.into();
+    Ok((target, value))
+}
+
+fn load_all(path: &Path) -> Result<Vec<(Target, serde_json::Value)>, RustdocError> {
+    let paths = if path.is_dir() {
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(path).map_err(|e| RustdocError::Read(path.into(), e))? {
+            let entry = entry.map_err(|e| RustdocError::Read(path.into(), e))?;
+            if entry.path().extension().is_some_and(|ext| ext == "json") {
+                paths.push(entry.path());
+            }
+        }
+        paths.sort();
+        paths
+    } else {
+        vec![path.to_path_buf()]
+    };
+
+    let mut targets: BTreeMap<String, (Target, serde_json::Value)> = BTreeMap::new();
+    for path in paths {
+        let (target, value) = load(&path)?;
+        match targets.get(&target.triple) {
+            Some((other, _)) if other.target_features != target.target_features => {
+                return Err(RustdocError::Conflict(
+                    other.path.clone(),
+                    path,
+                    target.triple,
+                ));
+            }
+            Some(_) => {}
+            None => {
+                targets.insert(target.triple.clone(), (target, value));
+            }
+        }
+    }
+    Ok(targets.into_values().collect())
+}
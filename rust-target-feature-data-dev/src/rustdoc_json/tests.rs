@@ -0,0 +1,85 @@
+use super::*;
+use std::collections::BTreeSet;
+
+const FIXTURES: &str = "../fixtures/rustdoc_json";
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rustdoc_json-{}-{}", name, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn read_directory() {
+    let targets = read_all(Path::new(FIXTURES)).unwrap();
+    let triples: Vec<&str> = targets.iter().map(|t| t.triple.as_str()).collect();
+    assert_eq!(
+        triples,
+        ["aarch64-unknown-linux-gnu", "x86_64-unknown-linux-gnu"]
+    );
+
+    let aes = targets[1]
+        .target_features
+        .iter()
+        .find(|f| f.name == "aes")
+        .unwrap();
+    assert_eq!(aes.implies_features, Some(BTreeSet::from(["sse2".into()])));
+    assert_eq!(aes.unstable_feature_gate, None);
+}
+
+#[test]
+fn written_like_data() {
+    let path = Path::new("../data/1.86.0/x86_64-unknown-linux-gnu.json");
+    let expected = fs::read_to_string(path).unwrap();
+    let file: TargetFile = serde_json::from_str(&expected).unwrap();
+    let actual = serde_json::to_string_pretty(&file).unwrap() + "\n";
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn format_version() {
+    let json =
+        fs::read_to_string(Path::new(FIXTURES).join("x86_64_unknown_linux_gnu.json")).unwrap();
+    let dir = temp_dir("format_version");
+
+    let newer = dir.join("newer.json");
+    fs::write(
+        &newer,
+        json.replace("\"format_version\":57", "\"format_version\":58"),
+    )
+    .unwrap();
+    assert!(matches!(
+        read(&newer),
+        Err(RustdocError::UnsupportedFormatVersion(_, 58))
+    ));
+
+    let older = dir.join("older.json");
+    let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    value.as_object_mut().unwrap().remove("target");
+    value["format_version"] = 39.into();
+    fs::write(&older, value.to_string()).unwrap();
+    assert!(matches!(
+        read(&older),
+        Err(RustdocError::MissingTarget(_, 39))
+    ));
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn conflict() {
+    let json =
+        fs::read_to_string(Path::new(FIXTURES).join("x86_64_unknown_linux_gnu.json")).unwrap();
+    let dir = temp_dir("conflict");
+    fs::write(dir.join("a.json"), &json).unwrap();
+    fs::write(
+        dir.join("b.json"),
+        json.replace("\"name\":\"adx\"", "\"name\":\"adx2\""),
+    )
+    .unwrap();
+    assert!(matches!(
+        read_all(&dir),
+        Err(RustdocError::Conflict(_, _, triple)) if triple == "x86_64-unknown-linux-gnu"
+    ));
+    fs::remove_dir_all(dir).unwrap();
+}
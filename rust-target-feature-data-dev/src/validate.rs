@@ -0,0 +1,208 @@
+//! Checking that data files are consistent before generating code from them.
+//!
+//! Each target is checked for implied features which don't exist, implication cycles, globally
+//! enabled features implying features which aren't, and a triple which doesn't match its file name.
+//! Across a compiler's targets, a feature must have the same unstable feature gate on every target
+//! with the same architecture.
+//!
+//! A few of rustc's own implications break these rules. [`KNOWN_QUIRKS`] lists them, so that
+//! problems which faithfully record rustc are reported as notes rather than failing
+//! [`load_strict()`](crate::load_strict).
+
+use crate::{Compiler, Target, TargetFeature};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+#[cfg(test)]
+mod tests;
+
+/// Implications which rustc really has, though they break the rules checked here, as
+/// `(architecture, feature, implied feature)`.
+pub const KNOWN_QUIRKS: &[(&str, &str, &str)] = &[
+    // csky's `e2` implies itself
+    ("csky", "e2", "e2"),
+    // riscv's `a` is globally enabled without `zaamo` and `zalrsc` in 1.83 through 1.86
+    ("riscv32", "a", "zaamo"),
+    ("riscv32", "a", "zalrsc"),
+    ("riscv64", "a", "zaamo"),
+    ("riscv64", "a", "zalrsc"),
+];
+
+/// A problem found in a data file.
+#[derive(Debug, Clone, Ord, Eq, PartialOrd, PartialEq)]
+pub struct Problem {
+    pub path: PathBuf,
+    pub message: String,
+    /// Whether the problem is faithful to rustc, because it's listed in [`KNOWN_QUIRKS`].
+    pub known_quirk: bool,
+}
+
+impl std::fmt::Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.message)
+    }
+}
+
+/// Check every target of a compiler, returning every problem found.
+pub fn validate(compiler: &Compiler) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    for target in &compiler.targets {
+        validate_target(target, &mut problems);
+    }
+    validate_gates(compiler, &mut problems);
+    problems
+}
+
+fn validate_target(target: &Target, problems: &mut Vec<Problem>) {
+    let arch = target_arch(&target.triple);
+    let quirk = |feature: &str, implied: &str| KNOWN_QUIRKS.contains(&(arch, feature, implied));
+    let mut problem = |message: String, known_quirk: bool| {
+        problems.push(Problem {
+            path: target.path.clone(),
+            message,
+            known_quirk,
+        })
+    };
+
+    let file_stem = target.path.file_stem().and_then(|stem| stem.to_str());
+    if file_stem != Some(target.triple.as_str()) {
+        problem(
+            format!("triple {:?} does not match the file name", target.triple),
+            false,
+        );
+    }
+
+    let features: BTreeMap<&str, &TargetFeature> = target
+        .target_features
+        .iter()
+        .map(|f| (f.name.as_str(), f))
+        .collect();
+    for feature in features.values() {
+        for implied in feature.implies_features.iter().flatten() {
+            match features.get(implied.as_str()) {
+                None => problem(
+                    format!(
+                        "{} implies {}, which is not a target feature",
+                        feature.name, implied
+                    ),
+                    false,
+                ),
+                Some(implied) if feature.globally_enabled && !implied.globally_enabled => problem(
+                    format!(
+                        "{} is globally enabled, but implies {}, which is not",
+                        feature.name, implied.name
+                    ),
+                    quirk(&feature.name, &implied.name),
+                ),
+                Some(_) => {}
+            }
+        }
+    }
+
+    for cycle in find_cycles(&features) {
+        let known_quirk = cycle
+            .iter()
+            .zip(cycle.iter().cycle().skip(1))
+            .all(|(feature, implied)| quirk(feature, implied));
+        problem(
+            format!("implication cycle: {} -> {}", cycle.join(" -> "), cycle[0]),
+            known_quirk,
+        );
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Visit {
+    OnStack,
+    Done,
+}
+
+/// Find implication cycles with a depth-first search, visiting each feature once.
+///
+/// Each cycle is returned once, starting from the feature where the search entered it.
+fn find_cycles<'a>(features: &BTreeMap<&'a str, &'a TargetFeature>) -> Vec<Vec<&'a str>> {
+    fn visit<'a>(
+        name: &'a str,
+        features: &BTreeMap<&'a str, &'a TargetFeature>,
+        visits: &mut BTreeMap<&'a str, Visit>,
+        stack: &mut Vec<&'a str>,
+        cycles: &mut Vec<Vec<&'a str>>,
+    ) {
+        visits.insert(name, Visit::OnStack);
+        stack.push(name);
+        for implied in features[name].implies_features.iter().flatten() {
+            let Some((&implied, _)) = features.get_key_value(implied.as_str()) else {
+                continue;
+            };
+            match visits.get(implied) {
+                None => visit(implied, features, visits, stack, cycles),
+                Some(Visit::OnStack) => {
+                    let start = stack.iter().position(|n| *n == implied).unwrap();
+                    cycles.push(stack[start..].to_vec());
+                }
+                Some(Visit::Done) => {}
+            }
+        }
+        stack.pop();
+        visits.insert(name, Visit::Done);
+    }
+
+    let mut visits = BTreeMap::new();
+    let mut cycles = Vec::new();
+    for &name in features.keys() {
+        if !visits.contains_key(name) {
+            visit(name, features, &mut visits, &mut Vec::new(), &mut cycles);
+        }
+    }
+    cycles
+}
+
+/// Check that each feature has the same gate on every target of an architecture.
+fn validate_gates(compiler: &Compiler, problems: &mut Vec<Problem>) {
+    let mut first_seen: BTreeMap<(&str, &str), (&Option<String>, &Target)> = BTreeMap::new();
+    for target in &compiler.targets {
+        let arch = target_arch(&target.triple);
+        for feature in &target.target_features {
+            let (gate, other) = *first_seen
+                .entry((arch, feature.name.as_str()))
+                .or_insert((&feature.unstable_feature_gate, target));
+            if *gate != feature.unstable_feature_gate {
+                problems.push(Problem {
+                    path: target.path.clone(),
+                    message: format!(
+                        "{} is gated by {:?}, but by {:?} in {}",
+                        feature.name,
+                        feature.unstable_feature_gate,
+                        gate,
+                        other.path.display()
+                    ),
+                    known_quirk: false,
+                });
+            }
+        }
+    }
+}
+
+/// Determine the `cfg(target_arch)` value of a target triple, like
+/// `rust_target_feature_data::triple` does.
+fn target_arch(triple: &str) -> &str {
+    let arch = triple.split('-').next().unwrap_or(triple);
+    match arch {
+        "i386" | "i586" | "i686" => "x86",
+        "x86_64h" => "x86_64",
+        "aarch64_be" | "arm64_32" | "arm64e" => "aarch64",
+        "arm64ec" => "arm64ec",
+        "bpfeb" | "bpfel" => "bpf",
+        "mipsel" => "mips",
+        "mips64el" => "mips64",
+        "mipsisa32r6" | "mipsisa32r6el" => "mips32r6",
+        "mipsisa64r6" | "mipsisa64r6el" => "mips64r6",
+        "powerpc64le" => "powerpc64",
+        "sparcv9" => "sparc64",
+        "wasm32v1" => "wasm32",
+        _ if arch.starts_with("riscv32") => "riscv32",
+        _ if arch.starts_with("riscv64") => "riscv64",
+        _ if arch.starts_with("arm") || arch.starts_with("thumb") => "arm",
+        _ => arch,
+    }
+}
@@ -0,0 +1,199 @@
+use super::*;
+use crate::{Detect, LlvmMapping};
+use std::collections::BTreeSet;
+
+/// A feature implying `implies`, gated by `gate` if it's not empty.
+fn feature(name: &str, implies: &[&str], gate: &str, globally_enabled: bool) -> TargetFeature {
+    TargetFeature {
+        name: name.into(),
+        unstable_feature_gate: (!gate.is_empty()).then(|| gate.into()),
+        implies_features: Some(implies.iter().map(|s| s.to_string()).collect()),
+        globally_enabled,
+    }
+}
+
+fn target(triple: &str, features: Vec<TargetFeature>) -> Target {
+    Target {
+        triple: triple.into(),
+        path: PathBuf::from(format!("data/1.86.0/{}.json", triple)),
+        target_features: features.into_iter().collect(),
+    }
+}
+
+fn compiler(targets: Vec<Target>) -> Compiler {
+    Compiler {
+        version: "1.86.0".into(),
+        directory: "1.86.0".into(),
+        aliases: BTreeSet::new(),
+        targets: targets.into_iter().collect(),
+        llvm: LlvmMapping {
+            llvm_version: "19".into(),
+            features: BTreeMap::new(),
+        },
+        detect: Detect { macros: Vec::new() },
+    }
+}
+
+fn messages(targets: Vec<Target>) -> Vec<(String, bool)> {
+    validate(&compiler(targets))
+        .into_iter()
+        .map(|problem| (problem.to_string(), problem.known_quirk))
+        .collect()
+}
+
+#[test]
+fn valid() {
+    let features = vec![
+        feature("sse", &[], "", true),
+        feature("sse2", &["sse"], "", true),
+        feature("avx", &["sse2"], "", false),
+    ];
+    assert_eq!(
+        messages(vec![target("x86_64-unknown-linux-gnu", features)]),
+        []
+    );
+}
+
+#[test]
+fn missing_implied_feature() {
+    let features = vec![feature("avx", &["sse9"], "", false)];
+    assert_eq!(
+        messages(vec![target("x86_64-unknown-linux-gnu", features)]),
+        [(
+            "data/1.86.0/x86_64-unknown-linux-gnu.json: avx implies sse9, which is not a target feature"
+                .into(),
+            false
+        )]
+    );
+}
+
+#[test]
+fn implication_cycles() {
+    let features = vec![
+        feature("a", &["b"], "", false),
+        feature("b", &["c"], "", false),
+        feature("c", &["a", "d"], "", false),
+        feature("d", &["d"], "", false),
+    ];
+    assert_eq!(
+        messages(vec![target("x86_64-unknown-linux-gnu", features)]),
+        [
+            (
+                "data/1.86.0/x86_64-unknown-linux-gnu.json: implication cycle: a -> b -> c -> a"
+                    .into(),
+                false
+            ),
+            (
+                "data/1.86.0/x86_64-unknown-linux-gnu.json: implication cycle: d -> d".into(),
+                false
+            ),
+        ]
+    );
+
+    // Many paths between two features are searched once
+    let mut features: Vec<_> = (0..64)
+        .flat_map(|n| {
+            let implies = [format!("{}a", n + 1), format!("{}b", n + 1)];
+            let implies: Vec<&str> = implies.iter().map(String::as_str).collect();
+            [
+                feature(&format!("{}a", n), &implies, "", false),
+                feature(&format!("{}b", n), &implies, "", false),
+            ]
+        })
+        .collect();
+    features.extend([
+        feature("64a", &[], "", false),
+        feature("64b", &[], "", false),
+    ]);
+    assert_eq!(
+        messages(vec![target("x86_64-unknown-linux-gnu", features)]),
+        []
+    );
+}
+
+#[test]
+fn globally_enabled_closure() {
+    let features = vec![
+        feature("avx", &[], "", false),
+        feature("avx2", &["avx"], "", true),
+    ];
+    assert_eq!(
+        messages(vec![target("x86_64-unknown-linux-gnu", features)]),
+        [(
+            "data/1.86.0/x86_64-unknown-linux-gnu.json: avx2 is globally enabled, but implies avx, which is not"
+                .into(),
+            false
+        )]
+    );
+}
+
+#[test]
+fn triple_mismatch() {
+    let mut target = target("x86_64-unknown-linux-gnu", vec![]);
+    target.path = PathBuf::from("data/1.86.0/x86_64-unknown-linux-musl.json");
+    assert_eq!(
+        messages(vec![target]),
+        [(
+            "data/1.86.0/x86_64-unknown-linux-musl.json: triple \"x86_64-unknown-linux-gnu\" does not match the file name"
+                .into(),
+            false
+        )]
+    );
+}
+
+#[test]
+fn gate_mismatch() {
+    let targets = vec![
+        target(
+            "i586-unknown-linux-gnu",
+            vec![feature("avx512f", &[], "avx512_target_feature", false)],
+        ),
+        target(
+            "i686-unknown-linux-gnu",
+            vec![feature("avx512f", &[], "", false)],
+        ),
+        // Another architecture may gate it differently
+        target(
+            "x86_64-unknown-linux-gnu",
+            vec![feature("avx512f", &[], "", false)],
+        ),
+    ];
+    assert_eq!(
+        messages(targets),
+        [(
+            "data/1.86.0/i686-unknown-linux-gnu.json: avx512f is gated by None, but by Some(\"avx512_target_feature\") in data/1.86.0/i586-unknown-linux-gnu.json"
+                .into(),
+            false
+        )]
+    );
+}
+
+#[test]
+fn known_quirks() {
+    let targets = vec![
+        target(
+            "csky-unknown-linux-gnuabiv2",
+            vec![feature("e2", &["e2"], "csky_target_feature", false)],
+        ),
+        target(
+            "riscv64gc-unknown-linux-gnu",
+            vec![
+                feature("a", &["zaamo"], "", true),
+                feature("zaamo", &[], "", false),
+            ],
+        ),
+        // Only the listed implications are quirks
+        target(
+            "csky-unknown-linux-gnuabiv2hf",
+            vec![
+                feature("e1", &["e2"], "csky_target_feature", false),
+                feature("e2", &["e1"], "csky_target_feature", false),
+            ],
+        ),
+    ];
+    let quirks: Vec<bool> = messages(targets)
+        .into_iter()
+        .map(|(_, quirk)| quirk)
+        .collect();
+    assert_eq!(quirks, [true, false, true]);
+}
@@ -4,6 +4,9 @@ use rust_target_feature_data_dev as dev;
 use std::collections::{BTreeMap, BTreeSet};
 use std::iter;
 
+#[cfg(test)]
+mod tests;
+
 trait VecExt<T> {
     fn push_once(&mut self, value: T) -> usize;
     fn find_once(&self, value: &T) -> Option<usize>;
@@ -29,8 +32,196 @@ impl<T: Eq> VecExt<T> for Vec<T> {
     }
 }
 
+/// Convert an index for one of the tables written into `generated.rs`, rather than a blob.
+fn index(n: usize) -> u32 {
+    u32::try_from(n).expect("more than u32::MAX entries")
+}
+
 fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.as_slice() {
+        [] => generate(),
+        [command] if command == "report" => print!("{}", report(&dev::load().unwrap())),
+        [command, input, version] if command == "ingest" => ingest(input, version),
+        [command, input, version] if command == "ingest-rustdoc" => ingest_rustdoc(input, version),
+        _ => {
+            eprintln!("usage: rust-target-feature-data-gen [report]");
+            eprintln!("       rust-target-feature-data-gen [ingest <DIRECTORY> <VERSION>]");
+            eprintln!("       rust-target-feature-data-gen [ingest-rustdoc <PATH> <VERSION>]");
+            std::process::exit(2);
+        }
+    }
+}
+
+/// Convert captured `rustc --print target-features` and `rustc --print cfg` output into
+/// `data/<version>/`.
+fn ingest(input: &str, version: &str) {
+    let output = std::path::Path::new("data").join(version);
+    for path in dev::rustc_print::ingest(input.as_ref(), &output).unwrap() {
+        println!("{}", path.display());
+    }
+    eprintln!(
+        "gates and some globally enabled flags can't be determined from rustc --print output; add \
+         them to these files before loading them"
+    );
+}
+
+/// Extract targets from rustdoc JSON output, which describes targets since 1.88, into
+/// `data/<version>/`.
+fn ingest_rustdoc(input: &str, version: &str) {
+    let output = std::path::Path::new("data").join(version);
+    for path in dev::rustdoc_json::ingest(input.as_ref(), &output).unwrap() {
+        println!("{}", path.display());
+    }
+}
+
+/// Where [`report()`] is written when generating.
+const REPORT_PATH: &str = "data/report.txt";
+
+fn generate() {
     let compilers = dev::load().unwrap();
+    for problem in compilers.iter().flat_map(dev::validate) {
+        if problem.known_quirk {
+            eprintln!("note: {} (faithful to rustc)", problem);
+        } else {
+            eprintln!("warning: {}", problem);
+        }
+    }
+    let generated = build(&compilers);
+    for (path, contents) in generated.files.into_iter().chain(generated.const_files) {
+        std::fs::write(path, contents).unwrap();
+    }
+    std::fs::write(REPORT_PATH, report(&compilers)).unwrap();
+}
+
+/// Describe the size of the generated data, so changes to it can be reviewed.
+fn report(compilers: &BTreeSet<dev::Compiler>) -> String {
+    let versions: Vec<&str> = compilers.iter().map(|c| c.directory.as_str()).collect();
+    let targets: usize = compilers.iter().map(|c| c.targets.len()).sum();
+
+    // Everything the generated data was built from
+    let raw_paths = compilers.iter().flat_map(|compiler| {
+        let data = std::path::Path::new("data");
+        let json = format!("{}.json", compiler.version);
+        compiler
+            .targets
+            .iter()
+            .map(|target| target.path.clone())
+            .chain([
+                data.join("llvm").join(&json),
+                data.join("detect").join(json),
+            ])
+    });
+    let raw = raw_paths.fold((0, 0), |(files, bytes), path| {
+        (files + 1, bytes + std::fs::metadata(path).unwrap().len())
+    });
+
+    // Build each prefix of the versions, to see what each version adds to the versions before it
+    let builds: Vec<Generated> = (0..=compilers.len())
+        .map(|i| build(&compilers.iter().take(i).cloned().collect()))
+        .collect();
+    format_report(&versions, targets, raw, &builds)
+}
+
+/// Format a report on `versions`, given the number of target files, the number and total size of
+/// the raw JSON files, and `builds`, where `builds[i]` was built from the first `i` versions.
+fn format_report(
+    versions: &[&str],
+    targets: usize,
+    (raw_files, raw_bytes): (usize, u64),
+    builds: &[Generated],
+) -> String {
+    use std::fmt::Write;
+
+    let generated = &builds[versions.len()];
+    let mut report = String::new();
+    writeln!(
+        report,
+        "# Generated by rust-target-feature-data-gen. Do not edit."
+    )
+    .unwrap();
+    writeln!(report).unwrap();
+    writeln!(report, "versions: {}", versions.join(", ")).unwrap();
+    writeln!(report, "targets: {}", targets).unwrap();
+
+    writeln!(report).unwrap();
+    writeln!(report, "unique:").unwrap();
+    for (what, count) in &generated.counts {
+        writeln!(report, "  {:<24}{:>8}", what, count).unwrap();
+    }
+
+    writeln!(report).unwrap();
+    writeln!(report, "files:").unwrap();
+    for (path, contents) in &generated.files {
+        writeln!(report, "  {:<40}{:>8} bytes", path, contents.len()).unwrap();
+    }
+    let total = generated.size();
+    writeln!(report, "  {:<40}{:>8} bytes", "total", total).unwrap();
+
+    // Only compiled with the `const` feature, so kept out of the default build's numbers
+    writeln!(report).unwrap();
+    writeln!(report, "const feature files:").unwrap();
+    for (path, contents) in &generated.const_files {
+        writeln!(report, "  {:<40}{:>8} bytes", path, contents.len()).unwrap();
+    }
+
+    writeln!(report).unwrap();
+    writeln!(
+        report,
+        "raw JSON: {} bytes in {} files",
+        raw_bytes, raw_files
+    )
+    .unwrap();
+    writeln!(
+        report,
+        "deduplication ratio: {:.1}x",
+        raw_bytes as f64 / total as f64
+    )
+    .unwrap();
+
+    writeln!(report).unwrap();
+    writeln!(report, "marginal cost:{:>26}{:>14}", "default", "const").unwrap();
+    for ((version, previous), next) in versions.iter().zip(builds).zip(&builds[1..]) {
+        writeln!(
+            report,
+            "  {:<24}{:>+8} bytes{:>+8} bytes",
+            version,
+            next.size() as i64 - previous.size() as i64,
+            next.const_size() as i64 - previous.const_size() as i64
+        )
+        .unwrap();
+    }
+    report
+}
+
+/// Generated files, and what they contain.
+struct Generated {
+    /// Each file's path and contents.
+    files: Vec<(&'static str, Vec<u8>)>,
+    /// Each file's path and contents, for files only compiled with the `const` feature.
+    const_files: Vec<(&'static str, Vec<u8>)>,
+    /// The number of unique things of each kind.
+    counts: Vec<(&'static str, usize)>,
+}
+
+impl Generated {
+    /// The total size of the generated files in the default build.
+    fn size(&self) -> usize {
+        self.files.iter().map(|(_, contents)| contents.len()).sum()
+    }
+
+    /// The total size of the generated files only compiled with the `const` feature.
+    fn const_size(&self) -> usize {
+        self.const_files
+            .iter()
+            .map(|(_, contents)| contents.len())
+            .sum()
+    }
+}
+
+fn build(compilers: &BTreeSet<dev::Compiler>) -> Generated {
+    let mut files = Vec::new();
+    let mut const_files = Vec::new();
 
     // The main ideas here are to deduplicate identical data, and to present this data as something
     // that's fast and small to compile. Runtime performance doesn't matter here or in the generated
@@ -38,21 +229,59 @@ fn main() {
     //
     // Concretely:
     //   1. Strings are duplicated. Factor them out into a `NAMES` array, and refer to strings by
-    //      index. We don't have that many strings, so indices can be `u16`.
+    //      index. Indices in `.blob` files are unsigned LEB128, so they're usually one or two bytes
+    //      but aren't limited to any width. Indices in tables in `generated.rs` are `u32`.
     //   2. Many features imply the same list of features. Factor those lists out into something we
-    //      can `include_bytes!()`, and generate a `get_implies_features(n)` function.
+    //      can `include_bytes!()`, and generate a `get_implies_features(n)` function. Features refer
+    //      to lists by ID + 1, so 0 can mean the data doesn't record their implications.
     //   3. Many features are identical. Factor features out into something we can
-    //      `include_bytes!()`, and generate a `get_feature(n)` function.
+    //      `include_bytes!()`, and generate a `get_feature(offset)` function. Features vary in
+    //      length, so they're referred to by offset.
     //   4. Many targets refer to the same list of features. Factor feature lists out into something
     //      we can `include_bytes!()`, and generate a `get_feature_list(n)` function.
     //   5. Generate a `pub(crate) all()` function, iterating over compiler versions, iterating over
-    //      targets, iterating over target features.
+    //      targets, iterating over target features. Targets with identical features share a feature
+    //      list, so `pub(crate) feature_list_ids()` also exposes each target's feature list ID.
+    //   6. Generate a `pub(crate) llvm()` function, iterating over compiler versions, iterating over
+    //      Rust target features and the LLVM features they enable. LLVM feature lists are factored
+    //      out like implied feature lists.
+    //   7. Generate a `pub(crate) feature_index()` function, iterating over compiler versions,
+    //      iterating over target feature names and the IDs of the lists of targets where that
+    //      feature exists, is globally enabled, and is gated. Target lists are factored out like
+    //      feature lists, and read with a `get_target_list(n)` function.
+    //   8. Generate a `pub(crate) detect()` function, iterating over compiler versions, iterating over
+    //      `is_*_feature_detected!` macros. Macros are usually identical between versions, so they
+    //      are deduplicated into a `DETECT_MACROS` table, and their name lists are factored out like
+    //      implied feature lists.
+    //   9. Separately, generate `generated_const.rs` for the `const` feature. This has the same
+    //      names, features, implied feature lists, and feature lists as above, but as plain arrays
+    //      with `const fn` lookups, so they can be read during const evaluation.
+
+    // Every target feature must have an LLVM mapping, and every LLVM mapping must describe a target
+    // feature
+    for compiler in compilers {
+        let rust_names: BTreeSet<&String> = compiler
+            .targets
+            .iter()
+            .flat_map(|target| target.target_features.iter().map(|f| &f.name))
+            .collect();
+        let mapped: BTreeSet<&String> = compiler.llvm.features.keys().collect();
+        let missing: Vec<_> = rust_names.difference(&mapped).collect();
+        let extra: Vec<_> = mapped.difference(&rust_names).collect();
+        assert!(
+            missing.is_empty() && extra.is_empty(),
+            "data/llvm/{}.json: missing {:?}, extra {:?}",
+            compiler.version,
+            missing,
+            extra
+        );
+    }
 
     // Accumulate unique features, their implications, and feature name strings
     let mut features = BTreeSet::new();
     let mut feature_implies_features = BTreeSet::new();
     let mut names = BTreeSet::new();
-    for compiler in &compilers {
+    for compiler in compilers {
         for target in &compiler.targets {
             names.insert(target.triple.clone());
             for feature in &target.target_features {
@@ -61,11 +290,60 @@ fn main() {
                     names.insert(gate.clone());
                 }
                 features.insert(feature.clone());
-                feature_implies_features.insert(feature.implies_features.clone());
+                feature_implies_features.extend(feature.implies_features.clone());
             }
         }
     }
 
+    // Accumulate LLVM feature lists, and a map of (compiler, LLVM version) => entries of
+    // (Rust feature, architecture, LLVM feature list ID)
+    let mut llvm_lists: Vec<Vec<String>> = Vec::new();
+    let mut llvm_maps: BTreeMap<_, Vec<_>> = BTreeMap::new();
+    for compiler in compilers {
+        let entries = llvm_maps
+            .entry((
+                compiler.version.as_str(),
+                compiler.llvm.llvm_version.as_str(),
+            ))
+            .or_default();
+        for (name, llvm) in &compiler.llvm.features {
+            let by_arch: Vec<(Option<&str>, &Vec<String>)> = match llvm {
+                dev::LlvmFeatures::All(list) => vec![(None, list)],
+                dev::LlvmFeatures::ByArch(map) => map
+                    .iter()
+                    .map(|(arch, list)| (Some(arch.as_str()), list))
+                    .collect(),
+            };
+            for (arch, list) in by_arch {
+                names.extend(arch.map(String::from));
+                names.extend(list.iter().cloned());
+                entries.push((name.as_str(), arch, llvm_lists.push_once(list.clone())));
+            }
+        }
+    }
+
+    // Accumulate unique `is_*_feature_detected!` macros, and a map of compiler => macro IDs
+    let mut detect_macros: Vec<&dev::DetectMacro> = Vec::new();
+    let mut detect_maps: BTreeMap<&str, Vec<usize>> = BTreeMap::new();
+    for compiler in compilers {
+        let ids = detect_maps.entry(compiler.version.as_str()).or_default();
+        for detect_macro in &compiler.detect.macros {
+            names.insert(detect_macro.name.clone());
+            names.extend(detect_macro.target_arches.iter().cloned());
+            names.extend(detect_macro.unstable_feature_gate.iter().cloned());
+            for feature in &detect_macro.features {
+                names.insert(feature.name.clone());
+                names.extend(feature.unstable_feature_gate.iter().cloned());
+            }
+            for (alias, feature) in &detect_macro.aliases {
+                names.insert(alias.clone());
+                names.insert(feature.clone());
+            }
+            names.extend(detect_macro.no_runtime_detection.iter().cloned());
+            ids.push(detect_macros.push_once(detect_macro));
+        }
+    }
+
     // Convert into Vecs to get meaningful indices
     let features = Vec::from_iter(features);
     let feature_implies_features = Vec::from_iter(feature_implies_features);
@@ -94,6 +372,15 @@ fn main() {
         })
         .collect();
 
+    let mut counts = vec![
+        ("names", names.len()),
+        ("features", features.len()),
+        ("implication lists", feature_implies_features.len()),
+        ("feature lists", feature_lists.len()),
+        ("LLVM feature lists", llvm_lists.len()),
+        ("detect macros", detect_macros.len()),
+    ];
+
     // Start preparing the output
     let mut output = quote! {
         use super::*;
@@ -109,15 +396,13 @@ fn main() {
 
     // Output `get_implies_features(n)` and supporting data
     {
-        let implies_features_names: Vec<u16> = feature_implies_features
+        let implies_features_names: Vec<u32> = feature_implies_features
             .iter()
-            .map(|list| {
+            .flat_map(|list| {
                 list.iter()
-                    .map(|s| names.find_once(s).unwrap())
-                    .map(|v| u16::try_from(v + 1).unwrap())
+                    .map(|s| index(names.find_once(s).unwrap() + 1))
                     .chain(iter::once(0))
             })
-            .flatten()
             .collect();
 
         let implies_features_names: TokenStream = implies_features_names
@@ -125,10 +410,14 @@ fn main() {
             .map(|x| quote! { #x,})
             .collect();
         output.extend(quote! {
-            static IMPLIES_FEATURES_NAMES: &[u16] = &[#implies_features_names];
+            static IMPLIES_FEATURES_NAMES: &[u32] = &[#implies_features_names];
 
-            fn get_implies_features(mut n: usize) -> impl Iterator<Item = &'static str> {
-                let mut values = IMPLIES_FEATURES_NAMES.iter().skip_while(move |v| {
+            fn get_implies_features(n: usize) -> impl Iterator<Item = &'static str> {
+                get_name_list(IMPLIES_FEATURES_NAMES, n)
+            }
+
+            fn get_name_list(lists: &'static [u32], mut n: usize) -> impl Iterator<Item = &'static str> {
+                let mut values = lists.iter().skip_while(move |v| {
                     if n == 0 {
                         false
                     } else {
@@ -144,60 +433,71 @@ fn main() {
                 })
                 .fuse()
             }
+
+            /// Decode a sequence of unsigned LEB128 values.
+            ///
+            /// The sequence ends early at a truncated value, or at one which doesn't fit in `usize`.
+            pub(crate) fn read_values(blob: &[u8]) -> impl Iterator<Item = usize> {
+                let mut bytes = blob.iter();
+                std::iter::from_fn(move || read_value(&mut bytes)).fuse()
+            }
+
+            /// Decode one unsigned LEB128 value.
+            fn read_value(bytes: &mut std::slice::Iter<u8>) -> Option<usize> {
+                let mut value = 0;
+                let mut shift = 0;
+                loop {
+                    let byte = *bytes.next()?;
+                    let bits = usize::from(byte & 0x7f);
+                    let shifted = bits.checked_shl(shift)?;
+                    if shifted >> shift != bits {
+                        return None;
+                    }
+                    value |= shifted;
+                    if byte & 0x80 == 0 {
+                        return Some(value);
+                    }
+                    shift += 7;
+                }
+            }
         });
     }
 
-    // Output `get_feature(n)` and supporting data
+    // Output `get_feature(offset)` and supporting data
+    let mut feature_offsets = Vec::new();
     {
-        let features_parts: Vec<Vec<u16>> = features
-            .iter()
-            .map(|feature| {
-                let unstable_feature_gate = u16::try_from(
-                    feature
-                        .unstable_feature_gate
-                        .as_ref()
-                        .map(|str| names.find_once(str).unwrap() + 1)
-                        .unwrap_or(0),
-                )
-                .unwrap();
-                let packed =
-                    unstable_feature_gate | if feature.globally_enabled { 0x8000 } else { 0 };
-
-                [
-                    u16::try_from(names.find_once(&feature.name).unwrap()).unwrap(),
-                    packed,
-                    u16::try_from(
-                        feature_implies_features
-                            .find_once(&feature.implies_features)
-                            .unwrap(),
-                    )
-                    .unwrap(),
-                ]
-                .into_iter()
-                .collect::<Vec<u16>>()
-            })
-            .collect();
-
-        let features_blob = features_parts
-            .into_iter()
-            .flatten()
-            .map(|v| v.to_le_bytes())
-            .flatten()
-            .collect::<Vec<u8>>();
-        std::fs::write("src/generated_features.blob", features_blob).unwrap();
+        let mut features_blob = Vec::new();
+        for feature in &features {
+            feature_offsets.push(features_blob.len());
+            let unstable_feature_gate = feature
+                .unstable_feature_gate
+                .as_ref()
+                .map(|str| names.find_once(str).unwrap() + 1)
+                .unwrap_or(0);
+            let packed = unstable_feature_gate << 1 | usize::from(feature.globally_enabled);
+            features_blob.extend(dev::leb128::encode_all([
+                names.find_once(&feature.name).unwrap(),
+                packed,
+                // 0 means the implications are unknown
+                feature
+                    .implies_features
+                    .as_ref()
+                    .map(|list| feature_implies_features.find_once(list).unwrap() + 1)
+                    .unwrap_or(0),
+            ]));
+        }
+        files.push(("src/generated_features.blob", features_blob));
 
         output.extend(quote! {
             static FEATURES_BLOB: &[u8] = include_bytes!("generated_features.blob");
 
-            fn get_feature(n: usize) -> TargetFeature {
-                let offset = n * 6;
-                let blob = &FEATURES_BLOB[offset..];
-                let mut list = blob.chunks(2).map(|bytes| u16::from_le_bytes(bytes.try_into().unwrap()));
-                let name = NAMES[list.next().unwrap() as usize];
-                let packed = list.next().unwrap();
-                let unstable_feature_gate = (packed & 0x7fff).checked_sub(1).map(|idx| NAMES[idx as usize]);
-                let globally_enabled = (packed & 0x8000) != 0;
-                let implies_features = get_implies_features(list.next().unwrap() as usize).collect();
+            fn get_feature(offset: usize) -> TargetFeature {
+                let mut values = read_values(&FEATURES_BLOB[offset..]);
+                let name = NAMES[values.next().unwrap()];
+                let packed = values.next().unwrap();
+                let unstable_feature_gate = (packed >> 1).checked_sub(1).map(|idx| NAMES[idx]);
+                let globally_enabled = (packed & 1) != 0;
+                let implies_features = values.next().unwrap().checked_sub(1).map(|idx| get_implies_features(idx).collect());
                 TargetFeature {
                     name,
                     unstable_feature_gate,
@@ -210,32 +510,17 @@ fn main() {
 
     // Output `get_feature_list(n)` and supporting data
     {
-        let feature_lists_parts: Vec<Vec<u16>> = feature_lists
-            .iter()
-            .map(|list| {
+        let mut feature_list_blob = Vec::new();
+        let mut feature_lists_offsets = Vec::new();
+        for list in &feature_lists {
+            feature_lists_offsets.push(feature_list_blob.len());
+            feature_list_blob.extend(dev::leb128::encode_all(
                 list.iter()
-                    .map(|v| u16::try_from(*v + 1).unwrap())
-                    .chain(iter::once(0))
-                    .collect::<Vec<u16>>()
-            })
-            .collect();
-
-        let mut n = 0;
-        let feature_lists_offsets: Vec<usize> = feature_lists_parts
-            .iter()
-            .map(|vec| {
-                let my_n = n;
-                n += vec.len() * 2;
-                my_n
-            })
-            .collect();
-        let feature_list_blob = feature_lists_parts
-            .into_iter()
-            .flatten()
-            .map(|v| v.to_le_bytes())
-            .flatten()
-            .collect::<Vec<u8>>();
-        std::fs::write("src/generated_feature_lists.blob", feature_list_blob).unwrap();
+                    .map(|id| feature_offsets[*id] + 1)
+                    .chain(iter::once(0)),
+            ));
+        }
+        files.push(("src/generated_feature_lists.blob", feature_list_blob));
 
         let feature_lists_offsets: TokenStream = feature_lists_offsets
             .iter()
@@ -247,88 +532,425 @@ fn main() {
 
             fn get_feature_list(n: usize) -> impl Iterator<Item=TargetFeature> {
                 let offset = FEATURE_LISTS_OFFSETS[n];
-                let blob = &FEATURE_LISTS_BLOB[offset..];
-                let mut list = blob.chunks(2).map(|bytes| u16::from_le_bytes(bytes.try_into().unwrap()));
+                let mut values = read_values(&FEATURE_LISTS_BLOB[offset..]);
                 std::iter::from_fn(move || {
-                    list.next().unwrap().checked_sub(1).map(|id| get_feature(id as usize))
+                    values.next().unwrap().checked_sub(1).map(get_feature)
                 }).fuse()
             }
         });
     }
 
     // Output `all()` and supporting data
-    let target_maps_parts: Vec<_> = maps
-        .iter()
-        .map(|(version, targets)| {
-            let u16s: Vec<u16> = targets
-                .iter()
-                .map(|(target, feature_list)| {
-                    let target = String::from(*target);
-                    let target = u16::try_from(names.find_once(&target).unwrap()).unwrap();
-                    let feature_list = u16::try_from(*feature_list).unwrap();
-                    [target, feature_list]
-                })
-                .flatten()
-                .collect();
-            (version, u16s)
-        })
-        .collect();
-
-    let target_maps_offsets: Vec<(&str, usize)> = {
-        let mut n = 0;
-        target_maps_parts
-            .iter()
-            .map(|(ver, vec)| {
-                let my_n = n;
-                n += vec.len() * 2;
-                (**ver, my_n)
-            })
-            .collect()
-    };
-
-    let target_maps_blob = target_maps_parts
-        .into_iter()
-        .map(|(_, vec)| vec)
-        .flatten()
-        .map(|v| v.to_le_bytes())
-        .flatten()
-        .collect::<Vec<u8>>();
+    let mut target_maps_blob = Vec::new();
+    let mut target_maps_offsets = Vec::new();
+    for (version, targets) in &maps {
+        target_maps_offsets.push((*version, target_maps_blob.len()));
+        for (target, feature_list) in targets {
+            let target = names.find_once(&target.to_string()).unwrap();
+            target_maps_blob.extend(dev::leb128::encode_all([target, *feature_list]));
+        }
+    }
     let target_maps_offsets: TokenStream = target_maps_offsets
         .iter()
         .chain(std::iter::once(&("", target_maps_blob.len())))
         .map(|(version, offset)| quote! { (#version, #offset),})
         .collect();
-    std::fs::write("src/generated_target_maps.blob", target_maps_blob).unwrap();
+    files.push(("src/generated_target_maps.blob", target_maps_blob));
 
     output.extend(quote! {
         static TARGET_MAPS_BLOB: &[u8] = include_bytes!("generated_target_maps.blob");
         static TARGET_MAPS_OFFSETS: &[(&str, usize)] = &[#target_maps_offsets];
 
         pub(crate) fn all() -> impl Iterator<Item=(&'static str, impl Iterator<Item=(&'static str, impl Iterator<Item=TargetFeature>)>)> {
+            feature_list_ids().map(|(version, targets)| {
+                let targets = targets.map(|(target, feature_list)| (target, get_feature_list(feature_list)));
+                (version, targets)
+            })
+        }
+
+        pub(crate) fn feature_list_ids() -> impl Iterator<Item=(&'static str, impl Iterator<Item=(&'static str, usize)>)> {
             TARGET_MAPS_OFFSETS.windows(2).map(|window| {
                 let &[(version, start), (_, end)] = window else {
                     unreachable!()
                 };
-                let slice = &TARGET_MAPS_BLOB[start..end];
-                let targets = slice.chunks(4).map(|bytes| {
-                    let target_name = u16::from_le_bytes([bytes[0], bytes[1]]);
-                    let feature_list = u16::from_le_bytes([bytes[2], bytes[3]]);
-                    (
-                        NAMES[target_name as usize],
-                        get_feature_list(feature_list.into()),
-                    )
+                let mut values = read_values(&TARGET_MAPS_BLOB[start..end]);
+                let targets = std::iter::from_fn(move || {
+                    let target_name = values.next()?;
+                    let feature_list = values.next().unwrap();
+                    Some((NAMES[target_name], feature_list))
                 });
                 (version, targets)
             })
         }
     });
 
-    // Pretty-print the file and write it to disk
+    // Output `llvm()` and supporting data
+    {
+        let llvm_features_names: TokenStream = llvm_lists
+            .iter()
+            .flat_map(|list| {
+                list.iter()
+                    .map(|s| index(names.find_once(s).unwrap() + 1))
+                    .chain(iter::once(0))
+            })
+            .map(|x| quote! { #x,})
+            .collect();
+
+        let mut llvm_blob = Vec::new();
+        let mut llvm_offsets = Vec::new();
+        for ((version, llvm_version), entries) in &llvm_maps {
+            llvm_offsets.push((*version, *llvm_version, llvm_blob.len()));
+            for (name, arch, list) in entries {
+                let name = names.find_once(&name.to_string()).unwrap();
+                let arch = arch
+                    .map(|arch| names.find_once(&arch.to_string()).unwrap() + 1)
+                    .unwrap_or(0);
+                llvm_blob.extend(dev::leb128::encode_all([name, arch, *list]));
+            }
+        }
+        let llvm_offsets: TokenStream = llvm_offsets
+            .iter()
+            .chain(iter::once(&("", "", llvm_blob.len())))
+            .map(|(version, llvm_version, offset)| quote! { (#version, #llvm_version, #offset),})
+            .collect();
+        files.push(("src/generated_llvm.blob", llvm_blob));
+
+        output.extend(quote! {
+            static LLVM_FEATURES_NAMES: &[u32] = &[#llvm_features_names];
+            static LLVM_BLOB: &[u8] = include_bytes!("generated_llvm.blob");
+            static LLVM_OFFSETS: &[(&str, &str, usize)] = &[#llvm_offsets];
+
+            pub(crate) fn llvm() -> impl Iterator<Item=(&'static str, &'static str, impl Iterator<Item=(&'static str, Option<&'static str>, impl Iterator<Item=&'static str>)>)> {
+                LLVM_OFFSETS.windows(2).map(|window| {
+                    let &[(version, llvm_version, start), (_, _, end)] = window else {
+                        unreachable!()
+                    };
+                    let mut values = read_values(&LLVM_BLOB[start..end]);
+                    let entries = std::iter::from_fn(move || {
+                        let name = values.next()?;
+                        let arch = values.next().unwrap();
+                        let list = values.next().unwrap();
+                        Some((
+                            NAMES[name],
+                            arch.checked_sub(1).map(|idx| NAMES[idx]),
+                            get_name_list(LLVM_FEATURES_NAMES, list),
+                        ))
+                    });
+                    (version, llvm_version, entries)
+                })
+            }
+        });
+    }
+
+    // Output `feature_index()`, `get_target_list(n)`, and supporting data
+    {
+        let mut target_lists: Vec<Vec<&str>> = Vec::new();
+        let mut feature_index_blob = Vec::new();
+        let mut feature_index_offsets = Vec::new();
+        for compiler in compilers {
+            feature_index_offsets.push((compiler.version.as_str(), feature_index_blob.len()));
+            let mut by_feature: BTreeMap<&str, [Vec<&str>; 3]> = BTreeMap::new();
+            for target in &compiler.targets {
+                for feature in &target.target_features {
+                    let [exists, enabled, gated] =
+                        by_feature.entry(feature.name.as_str()).or_default();
+                    exists.push(target.triple.as_str());
+                    if feature.globally_enabled {
+                        enabled.push(target.triple.as_str());
+                    }
+                    if feature.unstable_feature_gate.is_some() {
+                        gated.push(target.triple.as_str());
+                    }
+                }
+            }
+            for (name, lists) in by_feature {
+                let name = names.find_once(&name.to_string()).unwrap();
+                let ids = lists.map(|list| target_lists.push_once(list));
+                feature_index_blob.extend(dev::leb128::encode_all(iter::once(name).chain(ids)));
+            }
+        }
+
+        let mut target_lists_blob = Vec::new();
+        let mut target_lists_offsets = Vec::new();
+        for list in &target_lists {
+            target_lists_offsets.push(target_lists_blob.len());
+            target_lists_blob.extend(dev::leb128::encode_all(
+                list.iter()
+                    .map(|target| names.find_once(&target.to_string()).unwrap() + 1)
+                    .chain(iter::once(0)),
+            ));
+        }
+
+        let feature_index_offsets: TokenStream = feature_index_offsets
+            .iter()
+            .chain(iter::once(&("", feature_index_blob.len())))
+            .map(|(version, offset)| quote! { (#version, #offset),})
+            .collect();
+        let target_lists_offsets: TokenStream =
+            target_lists_offsets.iter().map(|x| quote! { #x,}).collect();
+        files.push(("src/generated_feature_index.blob", feature_index_blob));
+        files.push(("src/generated_target_lists.blob", target_lists_blob));
+        counts.push(("target lists", target_lists.len()));
+
+        output.extend(quote! {
+            static FEATURE_INDEX_BLOB: &[u8] = include_bytes!("generated_feature_index.blob");
+            static FEATURE_INDEX_OFFSETS: &[(&str, usize)] = &[#feature_index_offsets];
+            static TARGET_LISTS_BLOB: &[u8] = include_bytes!("generated_target_lists.blob");
+            static TARGET_LISTS_OFFSETS: &[usize] = &[#target_lists_offsets];
+
+            pub(crate) fn feature_index() -> impl Iterator<Item=(&'static str, impl Iterator<Item=(&'static str, [usize; 3])>)> {
+                FEATURE_INDEX_OFFSETS.windows(2).map(|window| {
+                    let &[(version, start), (_, end)] = window else {
+                        unreachable!()
+                    };
+                    let mut values = read_values(&FEATURE_INDEX_BLOB[start..end]);
+                    let features = std::iter::from_fn(move || {
+                        let name = NAMES[values.next()?];
+                        let lists = [(); 3].map(|_| values.next().unwrap());
+                        Some((name, lists))
+                    });
+                    (version, features)
+                })
+            }
+
+            pub(crate) fn get_target_list(n: usize) -> impl Iterator<Item=&'static str> {
+                let offset = TARGET_LISTS_OFFSETS[n];
+                let mut values = read_values(&TARGET_LISTS_BLOB[offset..]);
+                std::iter::from_fn(move || {
+                    values.next().unwrap().checked_sub(1).map(|id| NAMES[id])
+                }).fuse()
+            }
+        });
+    }
+
+    // Output `detect()` and supporting data
+    {
+        let name = |s: &String| index(names.find_once(s).unwrap());
+        let gate = |s: &Option<String>| {
+            index(
+                s.as_ref()
+                    .map(|s| names.find_once(s).unwrap() + 1)
+                    .unwrap_or(0),
+            )
+        };
+
+        // Lists of names, and aliases flattened into lists of (alias, feature) pairs
+        let mut detect_lists: Vec<Vec<&String>> = Vec::new();
+        let detect_macros: TokenStream = detect_macros
+            .iter()
+            .map(|detect_macro| {
+                let macro_name = name(&detect_macro.name);
+                let macro_gate = gate(&detect_macro.unstable_feature_gate);
+                let target_arches =
+                    index(detect_lists.push_once(detect_macro.target_arches.iter().collect()));
+                let features: TokenStream = detect_macro
+                    .features
+                    .iter()
+                    .map(|feature| {
+                        let feature_name = name(&feature.name);
+                        let feature_gate = gate(&feature.unstable_feature_gate);
+                        quote! { (#feature_name, #feature_gate), }
+                    })
+                    .collect();
+                let aliases = index(
+                    detect_lists.push_once(
+                        detect_macro
+                            .aliases
+                            .iter()
+                            .flat_map(|(alias, feature)| [alias, feature])
+                            .collect(),
+                    ),
+                );
+                let no_runtime_detection = index(
+                    detect_lists.push_once(detect_macro.no_runtime_detection.iter().collect()),
+                );
+                quote! {
+                    (#macro_name, #macro_gate, #target_arches, &[#features], #aliases, #no_runtime_detection),
+                }
+            })
+            .collect();
+        let detect_names: TokenStream = detect_lists
+            .iter()
+            .flat_map(|list| list.iter().map(|s| name(s) + 1).chain(iter::once(0)))
+            .map(|x| quote! { #x,})
+            .collect();
+        let detect_maps: TokenStream = detect_maps
+            .iter()
+            .map(|(version, ids)| {
+                let ids = ids.iter().map(|id| index(*id));
+                quote! { (#version, &[#(#ids),*]), }
+            })
+            .collect();
+
+        output.extend(quote! {
+            static DETECT_NAMES: &[u32] = &[#detect_names];
+            /// Name, gate, architectures list, (feature, gate) pairs, aliases list, no detection list
+            type DetectMacroEntry = (u32, u32, u32, &'static [(u32, u32)], u32, u32);
+            static DETECT_MACROS: &[DetectMacroEntry] = &[#detect_macros];
+            static DETECT_MAPS: &[(&str, &[u32])] = &[#detect_maps];
+
+            pub(crate) fn detect() -> impl Iterator<Item=(&'static str, impl Iterator<Item=detect::DetectMacro>)> {
+                DETECT_MAPS.iter().map(|(version, ids)| {
+                    let macros = ids.iter().map(|id| {
+                        let (name, macro_gate, target_arches, features, aliases, no_runtime_detection) =
+                            DETECT_MACROS[*id as usize];
+                        let gate = |gate: u32| gate.checked_sub(1).map(|idx| NAMES[idx as usize]);
+                        let aliases: Vec<&'static str> = get_name_list(DETECT_NAMES, aliases as usize).collect();
+                        detect::DetectMacro {
+                            name: NAMES[name as usize],
+                            unstable_feature_gate: gate(macro_gate),
+                            target_arches: get_name_list(DETECT_NAMES, target_arches as usize).collect(),
+                            features: features
+                                .iter()
+                                .map(|&(name, feature_gate)| detect::DetectableFeature {
+                                    name: NAMES[name as usize],
+                                    unstable_feature_gate: gate(feature_gate),
+                                })
+                                .collect(),
+                            aliases: aliases.chunks(2).map(|pair| (pair[0], pair[1])).collect(),
+                            no_runtime_detection: get_name_list(DETECT_NAMES, no_runtime_detection as usize).collect(),
+                        }
+                    });
+                    (*version, macros)
+                })
+            }
+        });
+    }
+
+    files.insert(0, ("src/generated.rs", unparse(output)));
+
+    // Output `generated_const.rs`
+    {
+        let name = |s: &String| index(names.find_once(s).unwrap());
+        let names_len = names.len();
+        let name_literals: TokenStream = names.iter().map(|s| quote! { #s, }).collect();
+
+        let implies_features_len = feature_implies_features.len();
+        let implies_features: TokenStream = feature_implies_features
+            .iter()
+            .map(|list| {
+                let list = list.iter().map(|s| {
+                    let idx = name(s);
+                    quote! { NAMES[#idx as usize] }
+                });
+                quote! { &[#(#list),*], }
+            })
+            .collect();
+
+        let features_len = features.len();
+        let features: TokenStream = features
+            .iter()
+            .map(|feature| {
+                let feature_name = name(&feature.name);
+                let gate = index(
+                    feature
+                        .unstable_feature_gate
+                        .as_ref()
+                        .map(|str| names.find_once(str).unwrap() + 1)
+                        .unwrap_or(0),
+                );
+                let globally_enabled = feature.globally_enabled;
+                let implies_features = index(
+                    feature
+                        .implies_features
+                        .as_ref()
+                        .map(|list| feature_implies_features.find_once(list).unwrap() + 1)
+                        .unwrap_or(0),
+                );
+                quote! { feature(#feature_name, #gate, #globally_enabled, #implies_features), }
+            })
+            .collect();
+
+        let feature_lists_len = feature_lists.len();
+        let feature_lists: TokenStream = feature_lists
+            .iter()
+            .map(|list| {
+                let list = list.iter().map(|id| index(*id));
+                quote! { &[#(#list),*], }
+            })
+            .collect();
+
+        let targets_len = maps.len();
+        let targets: TokenStream = maps
+            .iter()
+            .map(|(version, targets)| {
+                let targets = targets.iter().map(|(target, feature_list)| {
+                    let target = name(&target.to_string());
+                    let feature_list = index(*feature_list);
+                    quote! { (#target, #feature_list) }
+                });
+                quote! { (#version, &[#(#targets),*]), }
+            })
+            .collect();
+
+        let output = quote! {
+            use super::*;
+
+            static NAMES: [&str; #names_len] = [#name_literals];
+            static IMPLIES_FEATURES: [&[&str]; #implies_features_len] = [#implies_features];
+            /// Name, gate + 1, globally enabled, implied features + 1
+            static FEATURES: [consts::Feature; #features_len] = [#features];
+            static FEATURE_LISTS: [&[u32]; #feature_lists_len] = [#feature_lists];
+            /// Each version's targets, and their feature list IDs
+            static TARGETS: [(&str, &[(u32, u32)]); #targets_len] = [#targets];
+
+            const fn feature(name: u32, gate: u32, globally_enabled: bool, implies_features: u32) -> consts::Feature {
+                consts::Feature {
+                    name: NAMES[name as usize],
+                    unstable_feature_gate: match gate.checked_sub(1) {
+                        Some(idx) => Some(NAMES[idx as usize]),
+                        None => None,
+                    },
+                    globally_enabled,
+                    implies_features: match implies_features.checked_sub(1) {
+                        Some(idx) => Some(IMPLIES_FEATURES[idx as usize]),
+                        None => None,
+                    },
+                }
+            }
+
+            /// Find a target, returning its name and the IDs of its features.
+            pub(crate) const fn find(rust_version: &str, target: &str) -> Option<(&'static str, &'static [u32])> {
+                let mut v = 0;
+                while v < TARGETS.len() {
+                    let (version, targets) = TARGETS[v];
+                    if consts::str_eq(version, rust_version) {
+                        let mut t = 0;
+                        while t < targets.len() {
+                            let (name, feature_list) = targets[t];
+                            if consts::str_eq(NAMES[name as usize], target) {
+                                return Some((NAMES[name as usize], FEATURE_LISTS[feature_list as usize]));
+                            }
+                            t += 1;
+                        }
+                        return None;
+                    }
+                    v += 1;
+                }
+                None
+            }
+
+            pub(crate) const fn get_feature(id: u32) -> consts::Feature {
+                FEATURES[id as usize]
+            }
+        };
+        const_files.push(("src/generated_const.rs", unparse(output)));
+    }
+
+    Generated {
+        files,
+        const_files,
+        counts,
+    }
+}
+
+/// Pretty-print a generated file.
+fn unparse(output: TokenStream) -> Vec<u8> {
     let file = syn::parse_file(&output.to_string()).unwrap();
     let generated = prettyplease::unparse(&file);
     let with_header = format!(
         "// WARNING: This file was generated automatically by rust-target-feature-data-gen.\n\n{}",
         generated
     );
-    std::fs::write("src/generated.rs", with_header.into_bytes()).unwrap()
+    with_header.into_bytes()
 }
@@ -0,0 +1,53 @@
+use super::*;
+
+/// A build with one default file and one `const` file of the given sizes.
+fn generated(size: usize, const_size: usize) -> Generated {
+    Generated {
+        files: vec![("src/generated.rs", vec![0; size])],
+        const_files: vec![("src/generated_const.rs", vec![0; const_size])],
+        counts: vec![("names", size / 10)],
+    }
+}
+
+#[test]
+fn sizes() {
+    let generated = Generated {
+        files: vec![("a", vec![0; 3]), ("b", vec![0; 4])],
+        const_files: vec![("c", vec![0; 20])],
+        counts: Vec::new(),
+    };
+    assert_eq!(generated.size(), 7);
+    assert_eq!(generated.const_size(), 20);
+}
+
+#[test]
+fn report() {
+    let builds = [generated(10, 0), generated(60, 200), generated(80, 230)];
+    let report = format_report(&["1.85.0", "1.87.0-beta.5"], 12, (14, 1600), &builds);
+    assert_eq!(
+        report,
+        "\
+# Generated by rust-target-feature-data-gen. Do not edit.
+
+versions: 1.85.0, 1.87.0-beta.5
+targets: 12
+
+unique:
+  names                          8
+
+files:
+  src/generated.rs                              80 bytes
+  total                                         80 bytes
+
+const feature files:
+  src/generated_const.rs                       230 bytes
+
+raw JSON: 1600 bytes in 14 files
+deduplication ratio: 20.0x
+
+marginal cost:                   default         const
+  1.85.0                       +50 bytes    +200 bytes
+  1.87.0-beta.5                +20 bytes     +30 bytes
+"
+    );
+}
@@ -0,0 +1,217 @@
+//! Target feature information for build scripts.
+//!
+//! Cargo describes the target being compiled to build scripts through environment variables:
+//! `TARGET` names the target, `CARGO_CFG_TARGET_FEATURE` lists the enabled target features, and
+//! `RUSTC` names the compiler. [`BuildContext::from_env()`] reads all of these, asks the compiler
+//! for its version, and pairs them with the embedded data for that version and target.
+//!
+//! ```no_run
+//! // build.rs
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use rust_target_feature_data::build_script::BuildContext;
+//!
+//! let context = BuildContext::from_env()?;
+//! for feature in context.enabled_beyond_baseline() {
+//!     println!("cargo::warning=building with non-default target feature {}", feature);
+//! }
+//! # Ok(()) }
+//! ```
+
+use crate::{NotFoundError, TargetFeature};
+use std::collections::BTreeSet;
+
+#[cfg(test)]
+mod tests;
+
+/// The target features of a build, as seen by a build script.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BuildContext {
+    /// The compiler release, as reported by `rustc -vV`, e.g. `"1.86.0"` or `"1.87.0-beta.5"`.
+    pub rustc_release: String,
+    /// The embedded Rust version describing this compiler, suitable for [`find()`](crate::find).
+    ///
+    /// Pre-release compilers are described by the data for the release they lead up to, and patch
+    /// releases by the data for their minor release.
+    pub rust_version: &'static str,
+    /// The target triple.
+    pub target: String,
+    /// The target features enabled for this build, according to Cargo.
+    pub enabled: BTreeSet<String>,
+    /// The target's features, including which features it enables by default.
+    pub features: Vec<TargetFeature>,
+}
+
+impl BuildContext {
+    /// Describe the current build from inside a build script.
+    ///
+    /// This reads `TARGET`, `CARGO_CFG_TARGET_FEATURE`, and `RUSTC` from the environment, and runs
+    /// `$RUSTC -vV` to determine the compiler version.
+    pub fn from_env() -> Result<Self, BuildError> {
+        let var = |name| std::env::var(name).map_err(|_| BuildError::MissingVariable(name));
+        let target = var("TARGET")?;
+        // Cargo omits this variable entirely when no target features are enabled
+        let enabled = std::env::var("CARGO_CFG_TARGET_FEATURE").unwrap_or_default();
+        let rustc = var("RUSTC")?;
+
+        let output = std::process::Command::new(rustc)
+            .arg("-vV")
+            .output()
+            .map_err(BuildError::RunRustc)?;
+        let output = String::from_utf8_lossy(&output.stdout);
+        let release = output
+            .lines()
+            .find_map(|line| line.strip_prefix("release: "))
+            .ok_or_else(|| BuildError::UnexpectedRustcOutput(output.clone().into_owned()))?;
+
+        Self::new(release.trim(), &target, &enabled)
+    }
+
+    /// Describe a build from a `rustc` release, a target, and a comma-separated list of enabled
+    /// target features in the format of `CARGO_CFG_TARGET_FEATURE`.
+    pub fn new(
+        rustc_release: &str,
+        target: &str,
+        cargo_cfg_target_feature: &str,
+    ) -> Result<Self, BuildError> {
+        let rust_version = crate::resolve_version(rustc_release)
+            .ok_or_else(|| NotFoundError::CompilerNotFound(rustc_release.into()))?;
+        let features = crate::find(rust_version, target)?.collect();
+        let enabled = cargo_cfg_target_feature
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(String::from)
+            .collect();
+
+        Ok(BuildContext {
+            rustc_release: rustc_release.into(),
+            rust_version,
+            target: target.into(),
+            enabled,
+            features,
+        })
+    }
+
+    /// Find the enabled target features which the target does not enable by default.
+    ///
+    /// These are typically the result of `-Ctarget-feature` or `-Ctarget-cpu`. Cargo also reports
+    /// `cfg(target_feature)` values which are not target features, like `crt-static`, which are
+    /// included here.
+    pub fn enabled_beyond_baseline(&self) -> BTreeSet<&str> {
+        self.enabled
+            .iter()
+            .map(String::as_str)
+            .filter(|name| {
+                !self
+                    .features
+                    .iter()
+                    .any(|f| f.name == *name && f.globally_enabled)
+            })
+            .collect()
+    }
+
+    /// Find the enabled target features which are unstable in this compiler version.
+    ///
+    /// Only nightly compilers report unstable target features to build scripts, so this is always
+    /// empty for stable and beta compilers.
+    pub fn enabled_unstable(&self) -> BTreeSet<&'static str> {
+        self.features
+            .iter()
+            .filter(|f| f.unstable_feature_gate.is_some() && self.enabled.contains(f.name))
+            .map(|f| f.name)
+            .collect()
+    }
+
+    /// Find the target features which the target enables by default, but which are disabled in
+    /// this build.
+    ///
+    /// Unstable target features are only considered when using a nightly compiler, since other
+    /// compilers never report them as enabled.
+    pub fn disabled_defaults(&self) -> BTreeSet<&'static str> {
+        let nightly = self.is_nightly();
+        self.features
+            .iter()
+            .filter(|f| f.globally_enabled && (nightly || f.unstable_feature_gate.is_none()))
+            .filter(|f| !self.enabled.contains(f.name))
+            .map(|f| f.name)
+            .collect()
+    }
+
+    /// Translate the enabled target features into C compiler flags, as described in
+    /// [`cc`](crate::cc).
+    ///
+    /// Compilers other than nightly do not report unstable target features, so unstable features
+    /// which the target enables by default are assumed to still be enabled.
+    pub fn cc_flags(&self) -> crate::cc::CcFlags {
+        let nightly = self.is_nightly();
+        let unreported_defaults = self
+            .features
+            .iter()
+            .filter(|f| !nightly && f.globally_enabled && f.unstable_feature_gate.is_some())
+            .map(|f| f.name);
+        let enabled = self
+            .enabled
+            .iter()
+            .map(String::as_str)
+            .chain(unreported_defaults);
+        crate::cc::translate(&self.target, &self.features, enabled)
+    }
+
+    /// Generate multiversioning code for the target being built, as described in
+    /// [`multiversion`](crate::multiversion).
+    pub fn multiversion(
+        &self,
+        signature: &str,
+        tiers: &[&[&str]],
+    ) -> Result<crate::multiversion::Multiversioned, crate::multiversion::MultiversionError> {
+        crate::multiversion::generate(self.rust_version, &self.target, signature, tiers)
+    }
+
+    fn is_nightly(&self) -> bool {
+        self.rustc_release.ends_with("-nightly") || self.rustc_release.ends_with("-dev")
+    }
+}
+
+/// An error describing a build.
+#[derive(Debug)]
+pub enum BuildError {
+    /// A required environment variable was not set
+    MissingVariable(&'static str),
+    /// The compiler could not be run
+    RunRustc(std::io::Error),
+    /// The compiler did not report its release
+    UnexpectedRustcOutput(String),
+    /// The compiler version or target was not found
+    NotFound(NotFoundError),
+}
+
+impl std::error::Error for BuildError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BuildError::RunRustc(e) => Some(e),
+            BuildError::NotFound(e) => Some(e),
+            BuildError::MissingVariable(_) | BuildError::UnexpectedRustcOutput(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for BuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            BuildError::MissingVariable(name) => {
+                write!(f, "environment variable {} is not set", name)
+            }
+            BuildError::RunRustc(e) => write!(f, "running rustc: {}", e),
+            BuildError::UnexpectedRustcOutput(output) => {
+                write!(f, "rustc -vV did not report a release: {:?}", output)
+            }
+            BuildError::NotFound(e) => e.fmt(f),
+        }
+    }
+}
+
+impl From<NotFoundError> for BuildError {
+    fn from(value: NotFoundError) -> Self {
+        BuildError::NotFound(value)
+    }
+}
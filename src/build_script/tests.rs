@@ -0,0 +1,85 @@
+use super::*;
+
+#[test]
+fn baseline() {
+    let context = BuildContext::new("1.86.0", "x86_64-unknown-linux-gnu", "fxsr,sse,sse2").unwrap();
+    assert_eq!(context.rust_version, "1.86.0");
+    assert!(context.enabled_beyond_baseline().is_empty());
+    assert!(context.enabled_unstable().is_empty());
+    assert!(context.disabled_defaults().is_empty());
+}
+
+#[test]
+fn beyond_baseline() {
+    let context = BuildContext::new(
+        "1.86.0",
+        "x86_64-unknown-linux-gnu",
+        "avx,avx2,crt-static,fxsr,sse,sse2,sse3,sse4.1,sse4.2,ssse3",
+    )
+    .unwrap();
+    assert_eq!(
+        context.enabled_beyond_baseline(),
+        BTreeSet::from([
+            "avx",
+            "avx2",
+            "crt-static",
+            "sse3",
+            "sse4.1",
+            "sse4.2",
+            "ssse3"
+        ])
+    );
+    assert!(context.disabled_defaults().is_empty());
+}
+
+#[test]
+fn disabled_defaults() {
+    let context = BuildContext::new("1.86.0", "aarch64-unknown-linux-gnu", "").unwrap();
+    assert_eq!(context.disabled_defaults(), BTreeSet::from(["neon"]));
+}
+
+#[test]
+fn nightly() {
+    let context = BuildContext::new(
+        "1.87.0-nightly",
+        "x86_64-unknown-linux-gnu",
+        "avx512f,fxsr,sse,sse2",
+    )
+    .unwrap();
+    assert_eq!(context.rust_version, "1.87.0");
+    assert_eq!(context.enabled_unstable(), BTreeSet::from(["avx512f"]));
+    assert_eq!(context.disabled_defaults(), BTreeSet::from(["x87"]));
+}
+
+#[test]
+fn versions() {
+    for (release, version) in [
+        ("1.85.0", "1.85.0"),
+        ("1.85.1", "1.85.0"),
+        ("1.87.0-beta.5", "1.87.0"),
+        ("1.87.0", "1.87.0"),
+    ] {
+        let context = BuildContext::new(release, "x86_64-unknown-linux-gnu", "").unwrap();
+        assert_eq!(context.rust_version, version);
+    }
+
+    assert!(matches!(
+        BuildContext::new("1.84.0", "x86_64-unknown-linux-gnu", ""),
+        Err(BuildError::NotFound(NotFoundError::CompilerNotFound(_)))
+    ));
+}
+
+#[test]
+fn cc_flags() {
+    // Stable compilers do not report the unstable `d` and `f` features
+    let context = BuildContext::new(
+        "1.86.0",
+        "riscv64gc-unknown-linux-gnu",
+        "a,c,m,zaamo,zalrsc,zba,zbb",
+    )
+    .unwrap();
+    assert_eq!(
+        context.cc_flags().flags,
+        ["-march=rv64imafdc_zicsr_zifencei_zba_zbb"]
+    );
+}
@@ -0,0 +1,377 @@
+//! Checking target features configured through Cargo.
+//!
+//! Cargo passes compiler flags from several places: the `CARGO_ENCODED_RUSTFLAGS` and `RUSTFLAGS`
+//! environment variables, `target.<triple>.rustflags` in `.cargo/config.toml`, and
+//! `build.rustflags`. The first of these which is set is used. Target features are toggled by
+//! flags like `-C target-feature=+avx2,-fma`, and nothing checks that those names are correct
+//! until the compiler sees them.
+//!
+//! ```
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use rust_target_feature_data::cargo_config::{self, CargoConfig, Environment, FindingKind};
+//!
+//! let config = CargoConfig::parse(r#"
+//!     [target.x86_64-unknown-linux-gnu]
+//!     rustflags = ["-C", "target-feature=+avx2,+sse2,+avx3"]
+//! "#)?;
+//! let findings = cargo_config::check("1.86.0", &config, &Environment::default(), &[])?;
+//!
+//! let findings: Vec<_> = findings
+//!     .iter()
+//!     .map(|finding| (finding.toggle.feature.as_str(), &finding.kind))
+//!     .collect();
+//! assert_eq!(findings, [("sse2", &FindingKind::NoOp), ("avx3", &FindingKind::Unknown)]);
+//! # Ok(()) }
+//! ```
+//!
+//! Only a subset of TOML is supported, which is enough for typical Cargo configuration files.
+//! `[target.'cfg(…)']` tables are not evaluated, and are ignored.
+
+use crate::NotFoundError;
+use crate::toml::Value;
+use std::collections::BTreeMap;
+
+#[cfg(test)]
+mod tests;
+
+/// The parts of a Cargo configuration file which affect target features.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct CargoConfig {
+    /// `build.target`, which may name one or more targets.
+    pub build_target: Vec<String>,
+    /// `build.rustflags`.
+    pub build_rustflags: Option<Vec<String>>,
+    /// `target.<triple>.rustflags`, by triple.
+    pub target_rustflags: BTreeMap<String, Vec<String>>,
+}
+
+/// Compiler flags set through environment variables.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Environment {
+    /// `CARGO_ENCODED_RUSTFLAGS`, which separates flags with `0x1f` characters.
+    pub cargo_encoded_rustflags: Option<String>,
+    /// `RUSTFLAGS`, which separates flags with whitespace.
+    pub rustflags: Option<String>,
+}
+
+impl Environment {
+    /// Read `CARGO_ENCODED_RUSTFLAGS` and `RUSTFLAGS` from the environment.
+    pub fn from_env() -> Self {
+        Environment {
+            cargo_encoded_rustflags: std::env::var("CARGO_ENCODED_RUSTFLAGS").ok(),
+            rustflags: std::env::var("RUSTFLAGS").ok(),
+        }
+    }
+}
+
+/// Where a set of compiler flags came from.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum FlagSource {
+    /// The `CARGO_ENCODED_RUSTFLAGS` environment variable
+    CargoEncodedRustflags,
+    /// The `RUSTFLAGS` environment variable
+    Rustflags,
+    /// `target.<triple>.rustflags`
+    Target(String),
+    /// `build.rustflags`
+    Build,
+}
+
+impl std::fmt::Display for FlagSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            FlagSource::CargoEncodedRustflags => write!(f, "CARGO_ENCODED_RUSTFLAGS"),
+            FlagSource::Rustflags => write!(f, "RUSTFLAGS"),
+            FlagSource::Target(triple) => write!(f, "target.{}.rustflags", triple),
+            FlagSource::Build => write!(f, "build.rustflags"),
+        }
+    }
+}
+
+/// One `+feature` or `-feature` in a `-C target-feature` flag.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Toggle {
+    /// Whether the feature is enabled (`+`) or disabled (`-`).
+    pub enable: bool,
+    /// The target feature name.
+    pub feature: String,
+}
+
+/// A questionable target feature toggle.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Finding {
+    /// The target this toggle applies to.
+    pub triple: String,
+    /// Where this toggle was configured.
+    pub source: FlagSource,
+    /// The toggle.
+    pub toggle: Toggle,
+    /// What is questionable about it.
+    pub kind: FindingKind,
+}
+
+/// What is questionable about a target feature toggle.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum FindingKind {
+    /// The target feature does not exist for this target
+    Unknown,
+    /// The target feature is unstable, so the compiler warns about toggling it. This is its
+    /// language feature gate, though `-C target-feature` doesn't require it
+    Unstable(&'static str),
+    /// The toggle enables a feature which is already enabled by default, or disables a feature
+    /// which is already disabled by default
+    NoOp,
+    /// The target is not in this crate's data for this Rust version, like a custom target, so the
+    /// toggle can't be checked
+    UnknownTarget,
+    /// The item has no `+` or `-` prefix, so the compiler warns about it and ignores it. The
+    /// toggle's `feature` is the whole item
+    Malformed,
+}
+
+impl std::fmt::Display for Finding {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}: {}: ", self.triple, self.source)?;
+        if self.kind == FindingKind::Malformed {
+            return write!(
+                f,
+                "{:?} is ignored, since it isn't +feature or -feature",
+                self.toggle.feature
+            );
+        }
+        write!(
+            f,
+            "{}{} ",
+            if self.toggle.enable { '+' } else { '-' },
+            self.toggle.feature
+        )?;
+        match &self.kind {
+            FindingKind::Unknown => write!(f, "does not exist"),
+            FindingKind::Unstable(gate) => {
+                write!(f, "is unstable ({}), so the compiler warns about it", gate)
+            }
+            FindingKind::NoOp => write!(f, "has no effect"),
+            FindingKind::UnknownTarget => {
+                write!(f, "cannot be checked, since the target is unknown")
+            }
+            FindingKind::Malformed => Ok(()),
+        }
+    }
+}
+
+/// An error parsing a Cargo configuration file.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ConfigError {
+    /// The 1-based line number where the error was found.
+    pub line: usize,
+    /// A description of the error.
+    pub message: String,
+}
+
+impl std::error::Error for ConfigError {}
+
+impl From<crate::toml::Error> for ConfigError {
+    fn from(error: crate::toml::Error) -> Self {
+        ConfigError {
+            line: error.line,
+            message: error.message,
+        }
+    }
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl CargoConfig {
+    /// Read a Cargo configuration file, like `.cargo/config.toml`.
+    pub fn load(path: &std::path::Path) -> std::io::Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+        Self::parse(&contents).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    /// Parse the contents of a Cargo configuration file.
+    pub fn parse(toml: &str) -> Result<Self, ConfigError> {
+        let mut config = CargoConfig::default();
+        for entry in crate::toml::parse(toml)? {
+            let error = |message: &str| ConfigError {
+                line: entry.line,
+                message: message.into(),
+            };
+            let flags = |value: Value| match value {
+                Value::String(s) => Ok(s.split_whitespace().map(String::from).collect()),
+                value => value
+                    .into_strings()
+                    .ok_or_else(|| error("expected a string or array of strings")),
+            };
+            let key: Vec<&str> = entry.key.iter().map(String::as_str).collect();
+            match key.as_slice() {
+                ["build", "rustflags"] => config.build_rustflags = Some(flags(entry.value)?),
+                ["build", "target"] => config.build_target = flags(entry.value)?,
+                ["target", triple, "rustflags"] if !triple.starts_with("cfg(") => {
+                    config
+                        .target_rustflags
+                        .insert(triple.to_string(), flags(entry.value)?);
+                }
+                _ => {}
+            }
+        }
+        Ok(config)
+    }
+
+    /// Determine which flags Cargo would pass to the compiler for `triple`, and where they came
+    /// from.
+    pub fn rustflags(
+        &self,
+        environment: &Environment,
+        triple: &str,
+    ) -> Option<(FlagSource, Vec<String>)> {
+        if let Some(flags) = &environment.cargo_encoded_rustflags {
+            let flags = flags
+                .split('\x1f')
+                .filter(|flag| !flag.is_empty())
+                .map(String::from)
+                .collect();
+            Some((FlagSource::CargoEncodedRustflags, flags))
+        } else if let Some(flags) = &environment.rustflags {
+            let flags = flags.split_whitespace().map(String::from).collect();
+            Some((FlagSource::Rustflags, flags))
+        } else if let Some(flags) = self.target_rustflags.get(triple) {
+            Some((FlagSource::Target(triple.into()), flags.clone()))
+        } else {
+            self.build_rustflags
+                .as_ref()
+                .map(|flags| (FlagSource::Build, flags.clone()))
+        }
+    }
+}
+
+/// Extract the target feature toggles from a list of compiler flags.
+///
+/// This understands `-C target-feature=…`, `-Ctarget-feature=…`, and
+/// `--codegen target-feature=…`. Items without a `+` or `-` prefix are ignored, like the compiler
+/// does.
+pub fn target_feature_toggles(flags: &[String]) -> Vec<Toggle> {
+    target_feature_items(flags)
+        .into_iter()
+        .filter_map(Result::ok)
+        .collect()
+}
+
+/// Extract the items of `-C target-feature` flags, as toggles or, without a `+` or `-` prefix, as
+/// they were written.
+fn target_feature_items(flags: &[String]) -> Vec<Result<Toggle, String>> {
+    let mut toggles = Vec::new();
+    let mut flags = flags.iter().map(String::as_str);
+    while let Some(flag) = flags.next() {
+        let codegen = match flag {
+            "-C" | "--codegen" => flags.next().unwrap_or_default(),
+            _ => match flag
+                .strip_prefix("-C")
+                .or_else(|| flag.strip_prefix("--codegen="))
+            {
+                Some(codegen) => codegen,
+                None => continue,
+            },
+        };
+        let Some(list) = codegen.strip_prefix("target-feature=") else {
+            continue;
+        };
+        for item in list.split(',').filter(|item| !item.is_empty()) {
+            let (enable, feature) = match item.split_at_checked(1) {
+                Some(("+", feature)) => (true, feature),
+                Some(("-", feature)) => (false, feature),
+                _ => {
+                    toggles.push(Err(item.into()));
+                    continue;
+                }
+            };
+            toggles.push(Ok(Toggle {
+                enable,
+                feature: feature.into(),
+            }));
+        }
+    }
+    toggles
+}
+
+/// Check the target feature toggles which apply to each configured target.
+///
+/// The configured targets are the `[target.<triple>]` tables and `build.target`, plus any
+/// additional `triples`. Toggles for targets which this crate doesn't know are reported as
+/// [`FindingKind::UnknownTarget`].
+pub fn check(
+    rust_version: &str,
+    config: &CargoConfig,
+    environment: &Environment,
+    triples: &[&str],
+) -> Result<Vec<Finding>, NotFoundError> {
+    let mut all_triples: Vec<&str> = config
+        .target_rustflags
+        .keys()
+        .chain(&config.build_target)
+        .map(String::as_str)
+        .chain(triples.iter().copied())
+        .collect();
+    all_triples.sort();
+    all_triples.dedup();
+
+    let mut findings = Vec::new();
+    for triple in all_triples {
+        let features: Option<Vec<_>> = match crate::find(rust_version, triple) {
+            Ok(features) => Some(features.collect()),
+            Err(NotFoundError::TargetNotFound(_)) => None,
+            Err(e) => return Err(e),
+        };
+        let Some((source, flags)) = config.rustflags(environment, triple) else {
+            continue;
+        };
+        for item in target_feature_items(&flags) {
+            let toggle = match item {
+                Ok(toggle) => toggle,
+                Err(item) => {
+                    findings.push(Finding {
+                        triple: triple.into(),
+                        source: source.clone(),
+                        toggle: Toggle {
+                            enable: true,
+                            feature: item,
+                        },
+                        kind: FindingKind::Malformed,
+                    });
+                    continue;
+                }
+            };
+            // `crt-static` is a target feature to the compiler, but not a codegen feature
+            if toggle.feature == "crt-static" {
+                continue;
+            }
+            let Some(features) = &features else {
+                findings.push(Finding {
+                    triple: triple.into(),
+                    source: source.clone(),
+                    toggle,
+                    kind: FindingKind::UnknownTarget,
+                });
+                continue;
+            };
+            let kind = match features.iter().find(|f| f.name == toggle.feature) {
+                None => FindingKind::Unknown,
+                Some(feature) => match feature.unstable_feature_gate {
+                    Some(gate) => FindingKind::Unstable(gate),
+                    None if feature.globally_enabled == toggle.enable => FindingKind::NoOp,
+                    None => continue,
+                },
+            };
+            findings.push(Finding {
+                triple: triple.into(),
+                source: source.clone(),
+                toggle,
+                kind,
+            });
+        }
+    }
+    Ok(findings)
+}
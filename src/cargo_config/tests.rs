@@ -0,0 +1,230 @@
+use super::*;
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn parse_fixture() {
+    let config =
+        CargoConfig::load(std::path::Path::new("fixtures/cargo_config/config.toml")).unwrap();
+    assert_eq!(
+        config.build_target,
+        strings(&["x86_64-unknown-linux-gnu", "aarch64-unknown-linux-gnu"])
+    );
+    assert_eq!(
+        config.build_rustflags,
+        Some(strings(&["-C", "target-cpu=native"]))
+    );
+    assert_eq!(
+        config.target_rustflags,
+        BTreeMap::from([
+            (
+                "aarch64-unknown-linux-gnu".to_string(),
+                strings(&["-C", "target-feature=+neon,+sve,-sve3,lse"]),
+            ),
+            (
+                "x86_64-unknown-linux-gnu".to_string(),
+                strings(&[
+                    "-C",
+                    "target-feature=+avx2,+fma,+sse2",
+                    "-Ctarget-feature=+avx512f,-x87,+crt-static",
+                ]),
+            ),
+        ])
+    );
+}
+
+#[test]
+fn parse_dotted_keys() {
+    let config = CargoConfig::parse(
+        r#"
+        build.rustflags = ['-C', 'target-feature=+avx']
+        target."riscv64gc-unknown-linux-gnu".rustflags = ["-C", "target-feature=+zba"]
+        target.'cfg(target_arch = "x86")'.rustflags = ["-C", "target-feature=+sse3"]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        config.build_rustflags,
+        Some(strings(&["-C", "target-feature=+avx"]))
+    );
+    assert_eq!(
+        config.target_rustflags.keys().collect::<Vec<_>>(),
+        ["riscv64gc-unknown-linux-gnu"]
+    );
+
+    assert_eq!(
+        CargoConfig::parse("[build]\nrustflags = [\"-C\",\n").unwrap_err(),
+        ConfigError {
+            line: 2,
+            message: "unterminated array".into()
+        }
+    );
+    assert_eq!(
+        CargoConfig::parse("[build]\nrustflags = 1")
+            .unwrap_err()
+            .line,
+        2
+    );
+}
+
+#[test]
+fn parse_unneeded_values() {
+    let config = CargoConfig::parse(
+        r#"
+        [env]
+        FOO = { value = "x", relative = true }
+        NESTED = { value = ["a, b", "]"], force = { deep = '}' } } # comment
+        BANNER = """
+        Built with "quotes", [brackets] and {braces} # not a comment
+        """
+        RAW = '''
+        C:\path\'''
+
+        [build]
+        rustflags = """-C target-feature=+avx,\
+                       +fma"""
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        config.build_rustflags,
+        Some(strings(&["-C", "target-feature=+avx,+fma"]))
+    );
+
+    assert_eq!(
+        CargoConfig::parse("[env]\nFOO = { value = \"x\" \n").unwrap_err(),
+        ConfigError {
+            line: 2,
+            message: "expected , or } in inline table".into()
+        }
+    );
+    // Unicode escapes are decoded, and invalid escapes are errors rather than passed on
+    let config =
+        CargoConfig::parse(r#"build.rustflags = "-C\u0020target-feature=+avx\U0000002C+fma\t""#)
+            .unwrap();
+    assert_eq!(
+        config.build_rustflags,
+        Some(strings(&["-C", "target-feature=+avx,+fma"]))
+    );
+    for escape in [r"\uD800", r"\u12", r"\u+123", r"\U00110000", r"\q"] {
+        let error = CargoConfig::parse(&format!("build.rustflags = \"{}\"", escape)).unwrap_err();
+        assert!(error.message.starts_with("invalid"), "{}", escape);
+    }
+    assert_eq!(
+        CargoConfig::parse("[env]\nFOO = \"\"\"x\n").unwrap_err(),
+        ConfigError {
+            line: 2,
+            message: "unterminated string".into()
+        }
+    );
+}
+
+#[test]
+fn toggles() {
+    let flags = strings(&[
+        "-C",
+        "opt-level=3",
+        "-C",
+        "target-feature=+a,-b",
+        "-Ctarget-feature=+c",
+        "--codegen",
+        "target-feature=-d",
+        "--codegen=target-feature=+e,,f",
+    ]);
+    let toggles: Vec<_> = target_feature_toggles(&flags)
+        .into_iter()
+        .map(|toggle| (toggle.enable, toggle.feature))
+        .collect();
+    assert_eq!(
+        toggles,
+        [
+            (true, "a".to_string()),
+            (false, "b".to_string()),
+            (true, "c".to_string()),
+            (false, "d".to_string()),
+            (true, "e".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn check_fixture() {
+    let config =
+        CargoConfig::load(std::path::Path::new("fixtures/cargo_config/config.toml")).unwrap();
+    let findings = check("1.86.0", &config, &Environment::default(), &[]).unwrap();
+    let findings: Vec<String> = findings.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        findings,
+        [
+            "aarch64-unknown-linux-gnu: target.aarch64-unknown-linux-gnu.rustflags: +neon has no effect",
+            "aarch64-unknown-linux-gnu: target.aarch64-unknown-linux-gnu.rustflags: -sve3 does not exist",
+            "aarch64-unknown-linux-gnu: target.aarch64-unknown-linux-gnu.rustflags: \"lse\" is ignored, since it isn't +feature or -feature",
+            "x86_64-unknown-linux-gnu: target.x86_64-unknown-linux-gnu.rustflags: +sse2 has no effect",
+            "x86_64-unknown-linux-gnu: target.x86_64-unknown-linux-gnu.rustflags: +avx512f is unstable (avx512_target_feature), so the compiler warns about it",
+            "x86_64-unknown-linux-gnu: target.x86_64-unknown-linux-gnu.rustflags: -x87 is unstable (x87_target_feature), so the compiler warns about it",
+        ]
+    );
+}
+
+#[test]
+fn environment_precedence() {
+    let config = CargoConfig::parse(
+        r#"
+        [build]
+        rustflags = ["-C", "target-feature=+avx2"]
+        "#,
+    )
+    .unwrap();
+    let triple = "x86_64-unknown-linux-gnu";
+
+    let (source, _) = config.rustflags(&Environment::default(), triple).unwrap();
+    assert_eq!(source, FlagSource::Build);
+
+    let environment = Environment {
+        cargo_encoded_rustflags: None,
+        rustflags: Some("-C target-feature=+sse".into()),
+    };
+    let findings = check("1.86.0", &config, &environment, &[triple]).unwrap();
+    assert_eq!(
+        findings,
+        [Finding {
+            triple: triple.into(),
+            source: FlagSource::Rustflags,
+            toggle: Toggle {
+                enable: true,
+                feature: "sse".into()
+            },
+            kind: FindingKind::NoOp,
+        }]
+    );
+
+    let environment = Environment {
+        cargo_encoded_rustflags: Some("-C\x1ftarget-feature=+avx3".into()),
+        rustflags: Some("-C target-feature=+sse".into()),
+    };
+    let findings = check("1.86.0", &config, &environment, &[triple]).unwrap();
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].source, FlagSource::CargoEncodedRustflags);
+    assert_eq!(findings[0].kind, FindingKind::Unknown);
+
+    // Unknown targets are reported, without stopping other targets from being checked
+    let findings = check("1.86.0", &config, &environment, &["nonexistent", triple]).unwrap();
+    let kinds: Vec<_> = findings
+        .iter()
+        .map(|finding| (finding.triple.as_str(), &finding.kind))
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            ("nonexistent", &FindingKind::UnknownTarget),
+            (triple, &FindingKind::Unknown),
+        ]
+    );
+    assert_eq!(
+        findings[0].to_string(),
+        "nonexistent: CARGO_ENCODED_RUSTFLAGS: +avx3 cannot be checked, since the target is unknown"
+    );
+    assert!(check("1.0.0", &config, &environment, &[triple]).is_err());
+}
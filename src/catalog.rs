@@ -0,0 +1,198 @@
+//! Target features grouped by architecture.
+//!
+//! Target features are defined per architecture, and almost every target of an architecture has
+//! the same features, differing only in which are globally enabled. An [`ArchCatalog`] describes
+//! each feature once, using its most common definition among the architecture's targets, and
+//! lists the targets which deviate from it.
+//!
+//! ```
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use rust_target_feature_data::catalog;
+//!
+//! let aarch64 = catalog::catalog("1.86.0", "aarch64")?.unwrap();
+//! assert!(aarch64.targets.contains(&"aarch64-unknown-none-softfloat"));
+//!
+//! let neon = aarch64.feature("neon").unwrap();
+//! assert!(neon.globally_enabled_by.contains(&"aarch64-unknown-linux-gnu"));
+//! assert!(!neon.globally_enabled_by.contains(&"aarch64-unknown-none-softfloat"));
+//! # Ok(()) }
+//! ```
+
+use crate::{NotFoundError, TargetFeature};
+use std::collections::BTreeMap;
+
+#[cfg(test)]
+mod tests;
+
+/// The target features of every target of one architecture, in one Rust version.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ArchCatalog {
+    /// The `cfg(target_arch)` value, e.g. `"aarch64"`.
+    pub arch: &'static str,
+    /// The targets of this architecture.
+    pub targets: Vec<&'static str>,
+    /// Every target feature of any of these targets, by name.
+    pub features: Vec<CatalogFeature>,
+}
+
+/// A target feature, as defined by most targets of an architecture.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CatalogFeature {
+    /// The name of the target feature.
+    pub name: &'static str,
+    /// The most common language feature gate required to use this target feature.
+    pub unstable_feature_gate: Option<&'static str>,
+    /// The most common list of target features this feature implies, sorted by name, or `None` if
+    /// implications are unknown for this Rust version.
+    pub implies_features: Option<Vec<&'static str>>,
+    /// The targets which enable this feature by default.
+    pub globally_enabled_by: Vec<&'static str>,
+    /// The targets which define this feature differently, or not at all.
+    pub deviations: Vec<Deviation>,
+}
+
+/// A target which deviates from the most common definition of a target feature.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Deviation {
+    /// The target.
+    pub target: &'static str,
+    /// How this target differs.
+    pub kind: DeviationKind,
+}
+
+/// How a target deviates from the most common definition of a target feature.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum DeviationKind {
+    /// The target does not have this feature
+    Missing,
+    /// The target gates this feature differently
+    UnstableFeatureGate(Option<&'static str>),
+    /// The target's feature implies a different list of features
+    ImpliesFeatures(Option<Vec<&'static str>>),
+}
+
+impl ArchCatalog {
+    /// Find a target feature by name.
+    pub fn feature(&self, name: &str) -> Option<&CatalogFeature> {
+        self.features.iter().find(|f| f.name == name)
+    }
+
+    /// Find the targets which deviate from any feature's most common definition.
+    pub fn deviating_targets(&self) -> Vec<&'static str> {
+        let mut targets: Vec<&'static str> = self
+            .features
+            .iter()
+            .flat_map(|f| f.deviations.iter().map(|d| d.target))
+            .collect();
+        targets.sort();
+        targets.dedup();
+        targets
+    }
+}
+
+/// Describe the target features of every architecture in a Rust version, ordered by
+/// architecture.
+pub fn catalogs(rust_version: &str) -> Result<Vec<ArchCatalog>, NotFoundError> {
+    let (_, targets) = crate::generated::all()
+        .find(|(version, _)| *version == rust_version)
+        .ok_or_else(|| NotFoundError::CompilerNotFound(rust_version.into()))?;
+
+    let mut by_arch: BTreeMap<&'static str, Vec<(&'static str, Vec<TargetFeature>)>> =
+        BTreeMap::new();
+    for (triple, features) in targets {
+        by_arch
+            .entry(crate::target_arch(triple))
+            .or_default()
+            .push((triple, features.collect()));
+    }
+    Ok(by_arch
+        .into_iter()
+        .map(|(arch, targets)| build(arch, &targets))
+        .collect())
+}
+
+/// Describe the target features of one architecture in a Rust version.
+///
+/// Returns `None` if no embedded target has this `cfg(target_arch)`.
+pub fn catalog(rust_version: &str, arch: &str) -> Result<Option<ArchCatalog>, NotFoundError> {
+    Ok(catalogs(rust_version)?
+        .into_iter()
+        .find(|catalog| catalog.arch == arch))
+}
+
+/// A definition of a target feature, apart from whether it's globally enabled.
+type Definition = (Option<&'static str>, Option<Vec<&'static str>>);
+
+fn build(arch: &'static str, targets: &[(&'static str, Vec<TargetFeature>)]) -> ArchCatalog {
+    let mut definitions: BTreeMap<&'static str, Vec<(&'static str, Definition)>> = BTreeMap::new();
+    for (triple, features) in targets {
+        for feature in features {
+            let implies_features = feature
+                .implies_features
+                .as_ref()
+                .map(|implies| implies.iter().copied().collect());
+            definitions
+                .entry(feature.name)
+                .or_default()
+                .push((triple, (feature.unstable_feature_gate, implies_features)));
+        }
+    }
+
+    let features = definitions
+        .into_iter()
+        .map(|(name, by_target)| {
+            // Pick the most common definition, breaking ties by the definition itself
+            let mut counts: BTreeMap<&Definition, usize> = BTreeMap::new();
+            for (_, definition) in &by_target {
+                *counts.entry(definition).or_default() += 1;
+            }
+            let common = counts
+                .iter()
+                .max_by(|(a, a_count), (b, b_count)| a_count.cmp(b_count).then(b.cmp(a)))
+                .map(|(definition, _)| (*definition).clone())
+                .unwrap();
+
+            let mut deviations = Vec::new();
+            for (triple, _) in targets {
+                let kind = match by_target.iter().find(|(t, _)| t == triple) {
+                    None => DeviationKind::Missing,
+                    Some((_, (gate, _))) if *gate != common.0 => {
+                        DeviationKind::UnstableFeatureGate(*gate)
+                    }
+                    Some((_, (_, implies))) if *implies != common.1 => {
+                        DeviationKind::ImpliesFeatures(implies.clone())
+                    }
+                    Some(_) => continue,
+                };
+                deviations.push(Deviation {
+                    target: triple,
+                    kind,
+                });
+            }
+
+            let globally_enabled_by = targets
+                .iter()
+                .filter(|(_, features)| {
+                    features
+                        .iter()
+                        .any(|f| f.name == name && f.globally_enabled)
+                })
+                .map(|(triple, _)| *triple)
+                .collect();
+
+            CatalogFeature {
+                name,
+                unstable_feature_gate: common.0,
+                implies_features: common.1,
+                globally_enabled_by,
+                deviations,
+            }
+        })
+        .collect();
+
+    ArchCatalog {
+        arch,
+        targets: targets.iter().map(|(triple, _)| *triple).collect(),
+        features,
+    }
+}
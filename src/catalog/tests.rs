@@ -0,0 +1,49 @@
+use super::*;
+
+#[test]
+fn union_of_targets() {
+    for version in crate::versions() {
+        for catalog in catalogs(version).unwrap() {
+            for target in &catalog.targets {
+                for feature in crate::find(version, target).unwrap() {
+                    let entry = catalog.feature(feature.name).unwrap();
+                    assert_eq!(
+                        entry.globally_enabled_by.contains(target),
+                        feature.globally_enabled,
+                        "{} {} {}",
+                        version,
+                        target,
+                        feature.name
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn deviations() {
+    let x86_64 = catalog("1.85.0", "x86_64").unwrap().unwrap();
+    let x87 = x86_64.feature("x87").unwrap();
+    assert!(x87.deviations.contains(&Deviation {
+        target: "x86_64-apple-darwin",
+        kind: DeviationKind::Missing,
+    }));
+    assert!(
+        x86_64
+            .deviating_targets()
+            .contains(&"x86_64-unknown-linux-gnu")
+    );
+
+    let aarch64 = catalog("1.86.0", "aarch64").unwrap().unwrap();
+    assert!(aarch64.deviating_targets().is_empty());
+    let sve2 = aarch64.feature("sve2").unwrap();
+    assert_eq!(sve2.implies_features, Some(vec!["sve"]));
+    assert_eq!(sve2.unstable_feature_gate, None);
+}
+
+#[test]
+fn not_found() {
+    assert_eq!(catalog("1.86.0", "nonexistent"), Ok(None));
+    assert!(catalogs("1.0.0").is_err());
+}
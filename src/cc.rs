@@ -0,0 +1,261 @@
+//! Translating target features into C compiler flags.
+//!
+//! Crates which build C code, typically using the `cc` crate, may want that code compiled with the
+//! same instruction set extensions as their Rust code. These flags are understood by both GCC and
+//! Clang:
+//!
+//! * x86 features become `-m` flags, like `-mavx2`. The target's default features are included,
+//!   since the C compiler's defaults may differ, like when it's configured with another `-march`.
+//! * AArch64 features become an `-march` value, like `-march=armv8.2-a+fp16+sve`.
+//! * RISC-V features become an `-march` ISA string, like `-march=rv64imafdc_zicsr_zifencei_zba_zbb`.
+//!   `zicsr` and `zifencei` are always named, since they were split out of `i` and current GCC no
+//!   longer assumes them.
+//!
+//! Target features without a C compiler flag, and target features for other architectures, are
+//! reported as untranslated.
+//!
+//! ```
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use rust_target_feature_data::cc;
+//!
+//! let flags = cc::cc_flags("1.86.0", "x86_64-unknown-linux-gnu", &["avx2", "bmi1"])?;
+//! assert_eq!(
+//!     flags.flags,
+//!     [
+//!         "-m80387", "-mavx", "-mavx2", "-mbmi", "-mfxsr", "-msse", "-msse2", "-msse3", "-msse4.1",
+//!         "-msse4.2", "-mssse3",
+//!     ],
+//! );
+//! assert!(flags.untranslated.is_empty());
+//!
+//! let flags = cc::cc_flags("1.86.0", "riscv64gc-unknown-linux-gnu", &["zba", "zbb"])?;
+//! assert_eq!(flags.flags, ["-march=rv64imafdc_zicsr_zifencei_zba_zbb"]);
+//! # Ok(()) }
+//! ```
+//!
+//! In a build script, [`BuildContext::cc_flags()`](crate::build_script::BuildContext::cc_flags)
+//! translates the target features enabled for the build.
+
+use crate::{QueryError, TargetFeature};
+use std::collections::BTreeSet;
+
+#[cfg(test)]
+mod tests;
+
+/// C compiler flags equivalent to a set of target features.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct CcFlags {
+    /// Flags to pass to the C compiler.
+    pub flags: Vec<String>,
+    /// Enabled target features with no C compiler equivalent.
+    pub untranslated: BTreeSet<&'static str>,
+}
+
+/// Translate `enable`, the target features and everything they imply, along with the target's
+/// default features, into C compiler flags.
+pub fn cc_flags(rust_version: &str, target: &str, enable: &[&str]) -> Result<CcFlags, QueryError> {
+    let features: Vec<_> = crate::find(rust_version, target)?.collect();
+    let mut enabled = crate::implied_closure(&features, enable.iter().copied())?;
+    enabled.extend(
+        features
+            .iter()
+            .filter(|f| f.globally_enabled)
+            .map(|f| f.name),
+    );
+    Ok(translate(target, &features, enabled))
+}
+
+/// Translate a complete set of enabled target features into C compiler flags.
+///
+/// `features` are the target's features, and `enabled` should include both the target's default
+/// features and any features they imply, like `CARGO_CFG_TARGET_FEATURE` does. Names in `enabled`
+/// which are not target features are ignored.
+pub fn translate<'a>(
+    target: &str,
+    features: &[TargetFeature],
+    enabled: impl IntoIterator<Item = &'a str>,
+) -> CcFlags {
+    let enabled: BTreeSet<&str> = enabled.into_iter().collect();
+    let enabled: Vec<&TargetFeature> = features
+        .iter()
+        .filter(|f| enabled.contains(f.name))
+        .collect();
+    match crate::target_arch(target) {
+        "x86" | "x86_64" => x86(&enabled),
+        "aarch64" => aarch64(features, &enabled),
+        arch @ ("riscv32" | "riscv64") => riscv(arch, features, &enabled),
+        _ => CcFlags {
+            flags: Vec::new(),
+            untranslated: enabled
+                .iter()
+                .filter(|f| !f.globally_enabled)
+                .map(|f| f.name)
+                .collect(),
+        },
+    }
+}
+
+/// Find the GCC and Clang `-m` flag corresponding to an x86 target feature.
+fn x86_flag(name: &str) -> Option<&str> {
+    Some(match name {
+        "bmi1" => "bmi",
+        "cmpxchg16b" => "cx16",
+        "lahfsahf" => "sahf",
+        "pclmulqdq" => "pclmul",
+        "rdrand" => "rdrnd",
+        "x87" => "80387",
+        "adx" | "aes" | "amx-bf16" | "amx-complex" | "amx-fp16" | "amx-int8" | "amx-tile"
+        | "avx" | "avx2" | "avx512bf16" | "avx512bitalg" | "avx512bw" | "avx512cd" | "avx512dq"
+        | "avx512f" | "avx512fp16" | "avx512ifma" | "avx512vbmi" | "avx512vbmi2" | "avx512vl"
+        | "avx512vnni" | "avx512vp2intersect" | "avx512vpopcntdq" | "avxifma" | "avxneconvert"
+        | "avxvnni" | "avxvnniint16" | "avxvnniint8" | "bmi2" | "f16c" | "fma" | "fxsr"
+        | "gfni" | "kl" | "lzcnt" | "movbe" | "popcnt" | "prfchw" | "rdseed" | "rtm" | "sha"
+        | "sha512" | "sm3" | "sm4" | "sse" | "sse2" | "sse3" | "sse4.1" | "sse4.2" | "sse4a"
+        | "ssse3" | "tbm" | "vaes" | "vpclmulqdq" | "widekl" | "xop" | "xsave" | "xsavec"
+        | "xsaveopt" | "xsaves" => name,
+        _ => return None,
+    })
+}
+
+fn x86(enabled: &[&TargetFeature]) -> CcFlags {
+    let mut flags = CcFlags::default();
+    for feature in enabled {
+        match x86_flag(feature.name) {
+            Some(flag) => flags.flags.push(format!("-m{}", flag)),
+            None if !feature.globally_enabled => {
+                flags.untranslated.insert(feature.name);
+            }
+            None => {}
+        }
+    }
+    flags.flags.sort();
+    flags
+}
+
+/// AArch64 architecture versions, oldest first.
+const AARCH64_VERSIONS: &[(&str, &str)] = &[
+    ("v8.1a", "armv8.1-a"),
+    ("v8.2a", "armv8.2-a"),
+    ("v8.3a", "armv8.3-a"),
+    ("v8.4a", "armv8.4-a"),
+    ("v8.5a", "armv8.5-a"),
+    ("v8.6a", "armv8.6-a"),
+    ("v8.7a", "armv8.7-a"),
+    ("v8.8a", "armv8.8-a"),
+    ("v8.9a", "armv8.9-a"),
+    ("v9a", "armv9-a"),
+    ("v9.1a", "armv9.1-a"),
+    ("v9.2a", "armv9.2-a"),
+    ("v9.3a", "armv9.3-a"),
+    ("v9.4a", "armv9.4-a"),
+    ("v9.5a", "armv9.5-a"),
+];
+
+/// Find the AArch64 `-march` extension corresponding to a target feature.
+fn aarch64_extension(name: &str) -> Option<&str> {
+    Some(match name {
+        "fhm" => "fp16fml",
+        "mte" => "memtag",
+        "neon" => "simd",
+        "paca" | "pacg" => "pauth",
+        "rand" => "rng",
+        "rdm" => "rdma",
+        "spe" => "profile",
+        "aes" | "bf16" | "crc" | "cssc" | "dotprod" | "f32mm" | "f64mm" | "faminmax" | "flagm"
+        | "fp16" | "fp8" | "i8mm" | "lse" | "lse128" | "lut" | "mops" | "rcpc" | "rcpc3" | "sb"
+        | "sha2" | "sha3" | "sm4" | "sme" | "sme-f64f64" | "sme-i16i64" | "sme2" | "sme2p1"
+        | "ssbs" | "sve" | "sve2" | "sve2-aes" | "sve2-bitperm" | "sve2-sha3" | "sve2-sm4"
+        | "sve2p1" | "tme" | "wfxt" => name,
+        _ => return None,
+    })
+}
+
+fn aarch64(features: &[TargetFeature], enabled: &[&TargetFeature]) -> CcFlags {
+    let mut flags = CcFlags::default();
+    let (version, march) = AARCH64_VERSIONS
+        .iter()
+        .rev()
+        .find(|(version, _)| enabled.iter().any(|f| f.name == *version))
+        .copied()
+        .unwrap_or(("", "armv8-a"));
+    let covered = crate::implied_closure(features, [version].into_iter().filter(|v| !v.is_empty()))
+        .unwrap_or_default();
+
+    let mut extensions = Vec::new();
+    for feature in enabled {
+        if feature.globally_enabled || covered.contains(feature.name) {
+            continue;
+        }
+        if feature.name == "reserve-x18" {
+            flags.flags.push("-ffixed-x18".into());
+        } else if let Some(extension) = aarch64_extension(feature.name) {
+            if !extensions.contains(&extension) {
+                extensions.push(extension);
+            }
+        } else if !AARCH64_VERSIONS.iter().any(|(v, _)| *v == feature.name) {
+            flags.untranslated.insert(feature.name);
+        }
+    }
+    extensions.sort();
+
+    let mut march = format!("-march={}", march);
+    for extension in extensions {
+        march.push('+');
+        march.push_str(extension);
+    }
+    flags.flags.insert(0, march);
+    flags
+}
+
+/// The canonical order of single-letter RISC-V extensions.
+const RISCV_ORDER: &str = "iemafdqlcbkjtpvh";
+
+fn riscv(arch: &str, features: &[TargetFeature], enabled: &[&TargetFeature]) -> CcFlags {
+    let mut flags = CcFlags::default();
+    let order = |c: char| RISCV_ORDER.find(c).unwrap_or(RISCV_ORDER.len());
+
+    let mut letters: Vec<char> = enabled
+        .iter()
+        .filter(|f| f.name.len() == 1)
+        .filter_map(|f| f.name.chars().next())
+        .collect();
+    if !letters.contains(&'e') {
+        letters.push('i');
+    }
+    letters.sort_by_key(|c| order(*c));
+
+    // Multi-letter extensions implied by single-letter extensions are left implicit
+    let implied_by_letters = crate::implied_closure(
+        features,
+        enabled.iter().filter(|f| f.name.len() == 1).map(|f| f.name),
+    )
+    .unwrap_or_default();
+
+    let mut extensions = Vec::new();
+    for feature in enabled {
+        match feature.name {
+            name if name.len() == 1 || implied_by_letters.contains(name) => {}
+            name if name.starts_with('z') => extensions.push(name),
+            "relax" => flags.flags.push("-mrelax".into()),
+            "unaligned-scalar-mem" => flags.flags.push("-mno-strict-align".into()),
+            name => {
+                flags.untranslated.insert(name);
+            }
+        }
+    }
+    for name in ["zicsr", "zifencei"] {
+        if !extensions.contains(&name) {
+            extensions.push(name);
+        }
+    }
+    extensions.sort_by_key(|name| (name[1..].chars().next().map(order), *name));
+
+    let mut march = format!("-march=rv{}", &arch["riscv".len()..]);
+    march.extend(letters);
+    for extension in extensions {
+        march.push('_');
+        march.push_str(extension);
+    }
+    flags.flags.insert(0, march);
+    flags
+}
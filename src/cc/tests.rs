@@ -0,0 +1,96 @@
+use super::*;
+
+#[test]
+fn x86() {
+    let flags = cc_flags(
+        "1.86.0",
+        "x86_64-unknown-linux-gnu",
+        &["pclmulqdq", "rdrand", "cmpxchg16b"],
+    )
+    .unwrap();
+    assert_eq!(
+        flags.flags,
+        [
+            "-m80387", "-mcx16", "-mfxsr", "-mpclmul", "-mrdrnd", "-msse", "-msse2"
+        ]
+    );
+
+    // i586 does not enable fxsr or SSE by default
+    let flags = cc_flags("1.86.0", "i586-unknown-linux-gnu", &["sse2"]).unwrap();
+    assert_eq!(flags.flags, ["-m80387", "-msse", "-msse2"]);
+
+    // Features without a flag are reported, rather than guessed at
+    let flags = cc_flags(
+        "1.86.0",
+        "x86_64-unknown-linux-gnu",
+        &["ermsb", "soft-float"],
+    )
+    .unwrap();
+    assert_eq!(flags.flags, ["-m80387", "-mfxsr", "-msse", "-msse2"]);
+    assert_eq!(flags.untranslated, BTreeSet::from(["ermsb", "soft-float"]));
+
+    // Every feature either has a flag or is reported
+    let features: Vec<_> = crate::find("1.86.0", "x86_64-unknown-linux-gnu")
+        .unwrap()
+        .collect();
+    let flags = translate(
+        "x86_64-unknown-linux-gnu",
+        &features,
+        features.iter().map(|f| f.name),
+    );
+    assert_eq!(flags.flags.len() + flags.untranslated.len(), features.len());
+}
+
+#[test]
+fn aarch64() {
+    let flags = cc_flags("1.86.0", "aarch64-unknown-linux-gnu", &[]).unwrap();
+    assert_eq!(flags.flags, ["-march=armv8-a"]);
+
+    let flags = cc_flags(
+        "1.86.0",
+        "aarch64-unknown-linux-gnu",
+        &["fp16", "rand", "crc"],
+    )
+    .unwrap();
+    assert_eq!(flags.flags, ["-march=armv8-a+crc+fp16+rng"]);
+
+    // Features implied by the architecture version are left implicit
+    let flags = cc_flags(
+        "1.86.0",
+        "aarch64-unknown-linux-gnu",
+        &["v8.2a", "sve", "lse", "paca", "pacg"],
+    )
+    .unwrap();
+    assert_eq!(flags.flags, ["-march=armv8.2-a+pauth+sve"]);
+    assert!(flags.untranslated.is_empty());
+
+    let flags = cc_flags("1.86.0", "aarch64-unknown-linux-gnu", &["jsconv", "bti"]).unwrap();
+    assert_eq!(flags.untranslated, BTreeSet::from(["bti", "jsconv"]));
+}
+
+#[test]
+fn riscv() {
+    let flags = cc_flags("1.86.0", "riscv64gc-unknown-linux-gnu", &[]).unwrap();
+    assert_eq!(flags.flags, ["-march=rv64imafdc_zicsr_zifencei"]);
+
+    let flags = cc_flags(
+        "1.86.0",
+        "riscv32imc-unknown-none-elf",
+        &["zkt", "zbs", "zfinx", "unaligned-scalar-mem"],
+    )
+    .unwrap();
+    assert_eq!(
+        flags.flags,
+        [
+            "-march=rv32imc_zicsr_zifencei_zfinx_zbs_zkt",
+            "-mno-strict-align"
+        ]
+    );
+}
+
+#[test]
+fn untranslated_architecture() {
+    let flags = cc_flags("1.86.0", "powerpc64-unknown-linux-gnu", &["vsx"]).unwrap();
+    assert!(flags.flags.is_empty());
+    assert_eq!(flags.untranslated, BTreeSet::from(["vsx"]));
+}
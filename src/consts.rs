@@ -0,0 +1,121 @@
+//! Target feature data in `const` contexts.
+//!
+//! The rest of this crate decodes its data at runtime, so it can't be queried during const
+//! evaluation. With the `const` feature enabled, this module provides the same data as plain
+//! tables with `const fn` lookups instead, for computing constants, static dispatch tables, and the
+//! like.
+//!
+//! ```
+//! use rust_target_feature_data::consts;
+//!
+//! const HAS_AVX2_DEFAULT: bool =
+//!     consts::is_globally_enabled("1.86.0", "x86_64-unknown-linux-gnu", "avx2");
+//! const HAS_SSE2_DEFAULT: bool =
+//!     consts::is_globally_enabled("1.86.0", "x86_64-unknown-linux-gnu", "sse2");
+//! assert!(!HAS_AVX2_DEFAULT);
+//! assert!(HAS_SSE2_DEFAULT);
+//!
+//! const AVX2: consts::Feature = consts::find("1.86.0", "x86_64-unknown-linux-gnu")
+//!     .unwrap()
+//!     .feature("avx2")
+//!     .unwrap();
+//! assert_eq!(AVX2.implies_features, Some(&["avx"][..]));
+//! ```
+//!
+//! These tables duplicate the rest of the crate's data in a less compact form, so crates which
+//! don't need them can leave the feature disabled.
+
+#[cfg(test)]
+mod tests;
+
+/// A target feature, as in [`TargetFeature`](crate::TargetFeature).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Feature {
+    /// The target feature name, e.g. `"avx2"`.
+    pub name: &'static str,
+    /// If the feature is unstable, the language feature gate required to use it.
+    pub unstable_feature_gate: Option<&'static str>,
+    /// Whether the target enables this feature by default.
+    pub globally_enabled: bool,
+    /// The features this feature implies, or `None` if they are unknown.
+    pub implies_features: Option<&'static [&'static str]>,
+}
+
+/// A target's features in one Rust version.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Target {
+    /// The target triple, e.g. `"x86_64-unknown-linux-gnu"`.
+    pub triple: &'static str,
+    features: &'static [u32],
+}
+
+impl Target {
+    /// The number of target features.
+    pub const fn len(&self) -> usize {
+        self.features.len()
+    }
+
+    /// Whether the target has no target features.
+    pub const fn is_empty(&self) -> bool {
+        self.features.is_empty()
+    }
+
+    /// Get a target feature by position, in the same order as [`find()`](crate::find).
+    pub const fn get(&self, index: usize) -> Option<Feature> {
+        if index < self.features.len() {
+            Some(crate::generated_const::get_feature(self.features[index]))
+        } else {
+            None
+        }
+    }
+
+    /// Get a target feature by name.
+    pub const fn feature(&self, name: &str) -> Option<Feature> {
+        let mut i = 0;
+        while i < self.features.len() {
+            let feature = crate::generated_const::get_feature(self.features[i]);
+            if str_eq(feature.name, name) {
+                return Some(feature);
+            }
+            i += 1;
+        }
+        None
+    }
+}
+
+/// Find a target in a Rust version, like [`find()`](crate::find).
+pub const fn find(rust_version: &str, target: &str) -> Option<Target> {
+    match crate::generated_const::find(rust_version, target) {
+        Some((triple, features)) => Some(Target { triple, features }),
+        None => None,
+    }
+}
+
+/// Whether a target enables a target feature by default in a Rust version.
+///
+/// Returns `false` if the Rust version, target, or feature is not found.
+pub const fn is_globally_enabled(rust_version: &str, target: &str, feature: &str) -> bool {
+    match find(rust_version, target) {
+        Some(target) => match target.feature(feature) {
+            Some(feature) => feature.globally_enabled,
+            None => false,
+        },
+        None => false,
+    }
+}
+
+/// Compare strings, since `==` isn't available in `const fn`.
+pub(crate) const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
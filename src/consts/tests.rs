@@ -0,0 +1,55 @@
+use super::*;
+
+#[test]
+fn matches_runtime_data() {
+    for (version, targets) in crate::generated::all() {
+        for (triple, features) in targets {
+            let target = find(version, triple).unwrap();
+            assert_eq!(target.triple, triple);
+
+            let features: Vec<_> = features.collect();
+            assert_eq!(target.len(), features.len(), "{} {}", version, triple);
+            for (i, expected) in features.iter().enumerate() {
+                let actual = target.get(i).unwrap();
+                assert_eq!(actual.name, expected.name);
+                assert_eq!(actual.unstable_feature_gate, expected.unstable_feature_gate);
+                assert_eq!(actual.globally_enabled, expected.globally_enabled);
+                assert_eq!(
+                    actual.implies_features.map(<[_]>::to_vec),
+                    expected
+                        .implies_features
+                        .as_ref()
+                        .map(|implies| implies.iter().copied().collect())
+                );
+                assert_eq!(target.feature(expected.name), Some(actual));
+            }
+            assert_eq!(target.get(features.len()), None);
+        }
+    }
+}
+
+#[test]
+fn not_found() {
+    assert_eq!(find("1.0.0", "x86_64-unknown-linux-gnu"), None);
+    assert_eq!(find("1.86.0", "i686-unknown-redox"), None);
+    let target = find("1.86.0", "x86_64-unknown-linux-gnu").unwrap();
+    assert_eq!(target.feature("neon"), None);
+    assert!(!is_globally_enabled(
+        "1.86.0",
+        "x86_64-unknown-linux-gnu",
+        "neon"
+    ));
+}
+
+#[test]
+fn const_evaluation() {
+    const {
+        assert!(!is_globally_enabled("1.85.0", "i686-linux-android", "fxsr"));
+        assert!(is_globally_enabled("1.86.0", "i686-linux-android", "fxsr"));
+    }
+    const SVE: Option<Feature> = match find("1.86.0", "aarch64-unknown-linux-gnu") {
+        Some(target) => target.feature("sve"),
+        None => None,
+    };
+    assert_eq!(SVE.unwrap().implies_features, Some(&["neon"][..]));
+}
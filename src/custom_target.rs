@@ -0,0 +1,289 @@
+//! Target features of custom targets.
+//!
+//! A [custom target][1] is described by a JSON file naming an architecture, an LLVM target, and a
+//! `features` string of toggles like `+v7,+thumb2,-neon`. This crate has no data for custom
+//! targets, but a custom target typically has the features of a built-in target with the same
+//! architecture, except that its `features` string changes which are globally enabled.
+//!
+//! ```
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use rust_target_feature_data::custom_target::CustomTarget;
+//! use rust_target_feature_data::safe_call::{self, CallSafety};
+//!
+//! let custom = CustomTarget::parse("1.86.0", r#"{
+//!     "llvm-target": "thumbv7em-none-eabihf",
+//!     "arch": "arm",
+//!     "features": "+vfp4,-d32"
+//! }"#)?;
+//! assert_eq!(custom.base_target, "thumbv7em-none-eabihf");
+//!
+//! // Disabling d32 also disabled vfp3 and vfp4, which imply it, leaving vfp2
+//! let vfp2 = custom.features.iter().find(|f| f.name == "vfp2").unwrap();
+//! assert!(vfp2.globally_enabled);
+//! assert_eq!(
+//!     safe_call::check_features(custom.features.clone(), &[], &["vfp2"])?,
+//!     CallSafety::Safe,
+//! );
+//! # Ok(()) }
+//! ```
+//!
+//! The synthesized [`features`](CustomTarget::features) can be used wherever a target's features
+//! are accepted, like [`safe_call::check_features()`](crate::safe_call::check_features),
+//! [`cc::translate()`](crate::cc::translate), and
+//! [`Detection::with_features()`](crate::detect::Detection::with_features). Queries which don't
+//! depend on which features are globally enabled, like [`gates`](crate::gates) and
+//! [`msrv`](crate::msrv), can use the [`base_target`](CustomTarget::base_target).
+//!
+//! [1]: https://doc.rust-lang.org/rustc/targets/custom.html
+
+use crate::cargo_config::Toggle;
+use crate::{NotFoundError, QueryError, TargetFeature};
+use std::collections::BTreeSet;
+
+#[cfg(test)]
+mod tests;
+
+/// A custom target, described by the features of the closest built-in target.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CustomTarget {
+    /// The spec's `llvm-target`, e.g. `"thumbv7em-none-eabihf"`.
+    pub llvm_target: String,
+    /// The spec's `arch`, which is the `cfg(target_arch)` value, e.g. `"arm"`.
+    pub arch: String,
+    /// The embedded target with the same architecture which most resembles the spec.
+    pub base_target: &'static str,
+    /// The base target's features, with the spec's `features` toggles applied.
+    pub features: Vec<TargetFeature>,
+    /// Problems with the spec's `features` toggles.
+    pub warnings: Vec<Warning>,
+}
+
+/// A problem with a custom target's `features` toggles.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Warning {
+    /// The toggle names a feature the base target doesn't have, so it was ignored
+    UnknownFeature(Toggle),
+    /// The toggle's implications are unknown in this Rust version, so only the named feature was
+    /// toggled
+    UnknownImplications(Toggle),
+    /// An item in the `features` string isn't `+feature` or `-feature`, so it was ignored
+    Malformed(String),
+}
+
+impl std::fmt::Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let sign = |toggle: &Toggle| if toggle.enable { '+' } else { '-' };
+        match self {
+            Warning::UnknownFeature(toggle) => {
+                write!(
+                    f,
+                    "{}{}: unknown target feature",
+                    sign(toggle),
+                    toggle.feature
+                )
+            }
+            Warning::UnknownImplications(toggle) => write!(
+                f,
+                "{}{}: implications unknown, toggling only this feature",
+                sign(toggle),
+                toggle.feature
+            ),
+            Warning::Malformed(item) => write!(f, "{:?}: expected +feature or -feature", item),
+        }
+    }
+}
+
+/// An error reading a custom target specification.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum CustomTargetError {
+    /// The specification is not valid JSON
+    Json {
+        /// The 1-based line number where the error was found.
+        line: usize,
+        /// A description of the error.
+        message: String,
+    },
+    /// A required field is missing, or not a string
+    MissingField(&'static str),
+    /// No embedded target has the specification's architecture
+    NoBaseTarget(String),
+    /// The compiler version was not found
+    NotFound(NotFoundError),
+}
+
+impl std::error::Error for CustomTargetError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CustomTargetError::NotFound(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for CustomTargetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CustomTargetError::Json { line, message } => write!(f, "line {}: {}", line, message),
+            CustomTargetError::MissingField(field) => {
+                write!(f, "missing string field {:?}", field)
+            }
+            CustomTargetError::NoBaseTarget(arch) => {
+                write!(f, "no target with architecture {:?}", arch)
+            }
+            CustomTargetError::NotFound(e) => e.fmt(f),
+        }
+    }
+}
+
+impl From<NotFoundError> for CustomTargetError {
+    fn from(value: NotFoundError) -> Self {
+        CustomTargetError::NotFound(value)
+    }
+}
+
+impl From<crate::json::Error> for CustomTargetError {
+    fn from(error: crate::json::Error) -> Self {
+        CustomTargetError::Json {
+            line: error.line,
+            message: error.message,
+        }
+    }
+}
+
+impl CustomTarget {
+    /// Read a custom target specification file.
+    pub fn load(rust_version: &str, path: &std::path::Path) -> std::io::Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+        Self::parse(rust_version, &contents)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    /// Parse a custom target specification, and synthesize its features in a Rust version.
+    pub fn parse(rust_version: &str, json: &str) -> Result<Self, CustomTargetError> {
+        let spec = crate::json::parse(json)?;
+        let field = |name| spec.get(name).and_then(|value| value.as_str());
+        let llvm_target =
+            field("llvm-target").ok_or(CustomTargetError::MissingField("llvm-target"))?;
+        let arch = field("arch").ok_or(CustomTargetError::MissingField("arch"))?;
+
+        // Describe the spec by the components of its LLVM target, plus any it names explicitly
+        let mut components = llvm_target.split('-');
+        let sub_arch = components.next().unwrap_or_default();
+        let components: BTreeSet<&str> = components
+            .chain(["vendor", "os", "env", "abi"].into_iter().filter_map(field))
+            .filter(|component| !component.is_empty())
+            .collect();
+
+        let (_, targets) = crate::generated::all()
+            .find(|(version, _)| *version == rust_version)
+            .ok_or_else(|| NotFoundError::CompilerNotFound(rust_version.into()))?;
+        let mut candidates: Vec<&'static str> = targets
+            .map(|(triple, _)| triple)
+            .filter(|triple| crate::target_arch(triple) == arch)
+            .collect();
+        candidates.sort();
+
+        // Prefer the most similar sub-architecture, like `thumbv7em` for `thumbv7em` or
+        // `riscv32imafc` for `riscv32imafdc`, then the most shared components, then the fewest
+        // other components
+        let base_target = candidates
+            .into_iter()
+            .min_by_key(|triple| {
+                let mut parts = triple.split('-');
+                let common_prefix = parts
+                    .next()
+                    .unwrap_or_default()
+                    .bytes()
+                    .zip(sub_arch.bytes())
+                    .take_while(|(a, b)| a == b)
+                    .count();
+                let (shared, other): (Vec<&str>, Vec<&str>) =
+                    parts.partition(|part| components.contains(part));
+                (
+                    std::cmp::Reverse(common_prefix),
+                    std::cmp::Reverse(shared.len()),
+                    other.len(),
+                )
+            })
+            .ok_or_else(|| CustomTargetError::NoBaseTarget(arch.into()))?;
+
+        let mut features: Vec<TargetFeature> = crate::find(rust_version, base_target)?.collect();
+        let warnings = apply(&mut features, field("features").unwrap_or_default());
+        Ok(CustomTarget {
+            llvm_target: llvm_target.into(),
+            arch: arch.into(),
+            base_target,
+            features,
+            warnings,
+        })
+    }
+}
+
+/// Apply a `features` string to `features`, in order, like the compiler does.
+///
+/// Enabling a feature enables everything it implies, and disabling a feature disables everything
+/// which implies it.
+fn apply(features: &mut [TargetFeature], toggles: &str) -> Vec<Warning> {
+    let mut warnings = Vec::new();
+    for item in toggles
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+    {
+        let toggle = match item.split_at_checked(1) {
+            Some(("+", feature)) => Toggle {
+                enable: true,
+                feature: feature.into(),
+            },
+            Some(("-", feature)) => Toggle {
+                enable: false,
+                feature: feature.into(),
+            },
+            _ => {
+                warnings.push(Warning::Malformed(item.into()));
+                continue;
+            }
+        };
+        let Some(name) = features
+            .iter()
+            .map(|f| f.name)
+            .find(|name| *name == toggle.feature)
+        else {
+            warnings.push(Warning::UnknownFeature(toggle));
+            continue;
+        };
+
+        let mut implications_unknown = false;
+        let affected: BTreeSet<&'static str> = if toggle.enable {
+            match crate::implied_closure(features, [name]) {
+                Ok(closure) => closure,
+                Err(_) => {
+                    implications_unknown = true;
+                    BTreeSet::from([name])
+                }
+            }
+        } else {
+            features
+                .iter()
+                .filter(|f| match crate::implied_closure(features, [f.name]) {
+                    Ok(closure) => closure.contains(name),
+                    Err(QueryError::UnknownImplications(_)) => {
+                        implications_unknown = true;
+                        f.name == name
+                    }
+                    Err(_) => f.name == name,
+                })
+                .map(|f| f.name)
+                .collect()
+        };
+        for feature in features.iter_mut() {
+            if affected.contains(feature.name) {
+                feature.globally_enabled = toggle.enable;
+            }
+        }
+        if implications_unknown {
+            warnings.push(Warning::UnknownImplications(toggle));
+        }
+    }
+    warnings
+}
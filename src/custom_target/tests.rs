@@ -0,0 +1,176 @@
+use super::*;
+
+fn enabled(custom: &CustomTarget) -> BTreeSet<&'static str> {
+    custom
+        .features
+        .iter()
+        .filter(|f| f.globally_enabled)
+        .map(|f| f.name)
+        .collect()
+}
+
+fn spec(llvm_target: &str, arch: &str, extra: &str) -> String {
+    format!(
+        r#"{{"llvm-target": "{}", "arch": "{}"{}}}"#,
+        llvm_target, arch, extra
+    )
+}
+
+#[test]
+fn load_fixture() {
+    let custom = CustomTarget::load(
+        "1.86.0",
+        std::path::Path::new("fixtures/custom_target/thumbv7em-acme-none-eabihf.json"),
+    )
+    .unwrap();
+    assert_eq!(custom.llvm_target, "thumbv7em-none-eabihf");
+    assert_eq!(custom.arch, "arm");
+    assert_eq!(custom.base_target, "thumbv7em-none-eabihf");
+
+    // +vfp4 enabled vfp4, vfp3, vfp2, and d32, then -d32 disabled d32 and what implies it
+    assert_eq!(
+        enabled(&custom),
+        BTreeSet::from([
+            "dsp",
+            "fpregs",
+            "mclass",
+            "thumb-mode",
+            "thumb2",
+            "v5te",
+            "v6",
+            "v6k",
+            "v6t2",
+            "v7",
+            "vfp2",
+        ])
+    );
+    assert_eq!(
+        custom.warnings,
+        [
+            Warning::Malformed("neon".into()),
+            Warning::UnknownFeature(Toggle {
+                enable: true,
+                feature: "fpv5".into(),
+            }),
+        ]
+    );
+    assert_eq!(
+        custom.warnings[1].to_string(),
+        "+fpv5: unknown target feature"
+    );
+
+    assert_eq!(
+        crate::safe_call::check_features(custom.features.clone(), &[], &["vfp2"]),
+        Ok(crate::safe_call::CallSafety::Safe)
+    );
+    let detection =
+        crate::detect::Detection::with_features("1.86.0", &custom.arch, &custom.features).unwrap();
+    assert!(detection.globally_enabled.contains("vfp2"));
+}
+
+#[test]
+fn toggle_order() {
+    let custom = CustomTarget::parse(
+        "1.86.0",
+        &spec(
+            "thumbv7em-none-eabihf",
+            "arm",
+            r#", "features": "-d32,+neon""#,
+        ),
+    )
+    .unwrap();
+    let enabled = enabled(&custom);
+    for feature in ["neon", "vfp3", "vfp2", "d32"] {
+        assert!(enabled.contains(feature), "{}", feature);
+    }
+    assert!(!enabled.contains("vfp4"));
+    assert!(custom.warnings.is_empty());
+}
+
+#[test]
+fn closest_base_target() {
+    let base = |llvm_target, arch, extra| {
+        CustomTarget::parse("1.86.0", &spec(llvm_target, arch, extra))
+            .unwrap()
+            .base_target
+    };
+    assert_eq!(
+        base("thumbv7em-unknown-none-eabi", "arm", ""),
+        "thumbv7em-none-eabi"
+    );
+    assert_eq!(
+        base("thumbv7em-none-elf", "arm", r#", "abi": "eabihf""#),
+        "thumbv7em-none-eabihf"
+    );
+    assert_eq!(
+        base("armv7-unknown-linux-gnueabihf", "arm", r#", "os": "linux""#),
+        "armv7-unknown-linux-gnueabihf"
+    );
+    // No target has this sub-architecture, so the one sharing the longest prefix is chosen
+    assert_eq!(
+        base("riscv32imafdc-unknown-none-elf", "riscv32", ""),
+        "riscv32imafc-unknown-none-elf"
+    );
+}
+
+#[test]
+fn unknown_implications() {
+    let custom = CustomTarget::parse(
+        "1.81.0",
+        &spec("thumbv7em-none-eabihf", "arm", r#", "features": "+vfp4""#),
+    )
+    .unwrap();
+    assert!(enabled(&custom).contains("vfp4"));
+    assert!(!enabled(&custom).contains("vfp3"));
+    assert_eq!(
+        custom.warnings,
+        [Warning::UnknownImplications(Toggle {
+            enable: true,
+            feature: "vfp4".into(),
+        })]
+    );
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        CustomTarget::parse("1.86.0", r#"{"arch": "arm"}"#),
+        Err(CustomTargetError::MissingField("llvm-target"))
+    );
+    assert_eq!(
+        CustomTarget::parse("1.86.0", "{\n\"arch\": arm}"),
+        Err(CustomTargetError::Json {
+            line: 2,
+            message: "expected a value".into(),
+        })
+    );
+    assert_eq!(
+        CustomTarget::parse("1.86.0", &spec("z80-none-elf", "z80", "")),
+        Err(CustomTargetError::NoBaseTarget("z80".into()))
+    );
+    assert_eq!(
+        CustomTarget::parse("1.0.0", &spec("thumbv7em-none-eabihf", "arm", "")),
+        Err(CustomTargetError::NotFound(
+            NotFoundError::CompilerNotFound("1.0.0".into())
+        ))
+    );
+}
+
+#[test]
+fn json() {
+    use crate::json::{Value, parse};
+
+    let value = parse(r#"{"a": ["é😀\n", -1.5e3, null, true]}"#).unwrap();
+    assert_eq!(
+        value.get("a"),
+        Some(&Value::Array(vec![
+            Value::String("é😀\n".into()),
+            Value::Number("-1.5e3".into()),
+            Value::Null,
+            Value::Bool(true),
+        ]))
+    );
+    assert!(parse(r#""\ud83d""#).is_err());
+    assert!(parse(r#""\u+041""#).is_err());
+    assert!(parse("[1] 2").is_err());
+}
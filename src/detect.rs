@@ -0,0 +1,201 @@
+//! Runtime detection of target features with `is_*_feature_detected!`.
+//!
+//! The standard library can detect some target features at runtime, through one macro per
+//! architecture, like `is_x86_feature_detected!`. These macros don't accept every target feature,
+//! they accept some names which aren't target features, and both differ between Rust versions.
+//!
+//! ```
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use rust_target_feature_data::detect::Detection;
+//!
+//! let detection = Detection::new("1.86.0", "x86_64-unknown-linux-gnu")?;
+//! assert!(detection.detectable.contains_key("avx2"));
+//! assert!(detection.undetectable.contains("prfchw"));
+//! assert!(detection.not_target_features.contains("tsc"));
+//!
+//! let check = detection.runtime_check(&["avx2", "fma"])?;
+//! assert_eq!(
+//!     check.expression,
+//!     r#"std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")"#,
+//! );
+//! assert!(check.feature_gates.is_empty());
+//! # Ok(()) }
+//! ```
+//!
+//! These tables were read from the `std_detect` sources shipped with each Rust version.
+
+use crate::{NotFoundError, TargetFeature};
+use std::collections::{BTreeMap, BTreeSet};
+
+#[cfg(test)]
+mod tests;
+
+/// An `is_*_feature_detected!` macro.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DetectMacro {
+    /// The macro name, e.g. `"is_x86_feature_detected"`.
+    pub name: &'static str,
+    /// If the macro is unstable, the language feature gate required to use it.
+    pub unstable_feature_gate: Option<&'static str>,
+    /// The architectures this macro is available on, as in `cfg(target_arch)`.
+    pub target_arches: Vec<&'static str>,
+    /// The names this macro accepts.
+    pub features: Vec<DetectableFeature>,
+    /// Additional names this macro accepts, and the name each is equivalent to.
+    pub aliases: Vec<(&'static str, &'static str)>,
+    /// Names which this macro rejects, since they cannot be detected at runtime.
+    pub no_runtime_detection: Vec<&'static str>,
+}
+
+/// A name accepted by an `is_*_feature_detected!` macro.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DetectableFeature {
+    /// The name, e.g. `"avx2"`.
+    pub name: &'static str,
+    /// If detecting this name is unstable, the language feature gate required.
+    pub unstable_feature_gate: Option<&'static str>,
+}
+
+/// How the features of one target can be detected at runtime, in one Rust version.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Detection {
+    /// The macro for this target's architecture, if there is one.
+    pub detect_macro: Option<DetectMacro>,
+    /// Target features which the macro can detect, and the language feature gates required to
+    /// detect each, including any required by the macro itself.
+    pub detectable: BTreeMap<&'static str, BTreeSet<&'static str>>,
+    /// Target features which cannot be detected at runtime.
+    pub undetectable: BTreeSet<&'static str>,
+    /// Names which the macro can detect, but which are not target features of this target.
+    pub not_target_features: BTreeSet<&'static str>,
+    /// Target features which the target enables by default, which are always available.
+    pub globally_enabled: BTreeSet<&'static str>,
+}
+
+/// A runtime check for a set of target features.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RuntimeCheck {
+    /// A Rust expression which is `true` when all features are available, e.g.
+    /// `std::arch::is_x86_feature_detected!("avx2")`.
+    pub expression: String,
+    /// Language feature gates required by the expression.
+    pub feature_gates: BTreeSet<&'static str>,
+}
+
+/// An error generating a runtime check.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum DetectError {
+    /// The target feature does not exist for this target
+    UnknownFeature(String),
+    /// The target feature cannot be detected at runtime
+    Undetectable(String),
+}
+
+impl std::error::Error for DetectError {}
+
+impl std::fmt::Display for DetectError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            DetectError::UnknownFeature(name) => write!(f, "unknown target feature {}", name),
+            DetectError::Undetectable(name) => {
+                write!(f, "target feature {} cannot be detected at runtime", name)
+            }
+        }
+    }
+}
+
+/// Find the `is_*_feature_detected!` macros available in a Rust version.
+pub fn macros(rust_version: &str) -> Result<Vec<DetectMacro>, NotFoundError> {
+    crate::generated::detect()
+        .find(|(version, _)| *version == rust_version)
+        .map(|(_, macros)| macros.collect())
+        .ok_or_else(|| NotFoundError::CompilerNotFound(rust_version.into()))
+}
+
+impl Detection {
+    /// Determine which of a target's features can be detected at runtime.
+    pub fn new(rust_version: &str, target: &str) -> Result<Self, NotFoundError> {
+        let features: Vec<_> = crate::find(rust_version, target)?.collect();
+        Self::with_features(rust_version, crate::target_arch(target), &features)
+    }
+
+    /// Determine which of a target's features can be detected at runtime, given the target's
+    /// architecture, as in `cfg(target_arch)`, and features.
+    pub fn with_features(
+        rust_version: &str,
+        arch: &str,
+        features: &[TargetFeature],
+    ) -> Result<Self, NotFoundError> {
+        let detect_macro = macros(rust_version)?
+            .into_iter()
+            .find(|detect_macro| detect_macro.target_arches.contains(&arch));
+
+        let mut detection = Detection {
+            detect_macro: None,
+            detectable: BTreeMap::new(),
+            undetectable: features.iter().map(|f| f.name).collect(),
+            not_target_features: BTreeSet::new(),
+            globally_enabled: features
+                .iter()
+                .filter(|f| f.globally_enabled)
+                .map(|f| f.name)
+                .collect(),
+        };
+        let Some(detect_macro) = detect_macro else {
+            return Ok(detection);
+        };
+
+        let accepted = detect_macro
+            .features
+            .iter()
+            .map(|f| (f.name, f.unstable_feature_gate))
+            .chain(detect_macro.aliases.iter().map(|(alias, _)| (*alias, None)));
+        for (name, gate) in accepted {
+            if detection.undetectable.remove(name) {
+                let gates = detect_macro.unstable_feature_gate.into_iter().chain(gate);
+                detection.detectable.insert(name, gates.collect());
+            } else if !detection.detectable.contains_key(name) {
+                detection.not_target_features.insert(name);
+            }
+        }
+        detection.detect_macro = Some(detect_macro);
+        Ok(detection)
+    }
+
+    /// Generate an expression which checks at runtime that all of `features` are available.
+    ///
+    /// Features which the target enables by default are always available, so they are not
+    /// checked. If nothing needs to be checked, the expression is `true`.
+    pub fn runtime_check(&self, features: &[&str]) -> Result<RuntimeCheck, DetectError> {
+        let mut features: Vec<&str> = features.to_vec();
+        features.sort();
+        features.dedup();
+
+        let mut checks = Vec::new();
+        let mut feature_gates = BTreeSet::new();
+        for feature in features {
+            if self.globally_enabled.contains(feature) {
+                continue;
+            }
+            match (&self.detect_macro, self.detectable.get(feature)) {
+                (Some(detect_macro), Some(gates)) => {
+                    checks.push(format!("std::arch::{}!({:?})", detect_macro.name, feature));
+                    feature_gates.extend(gates);
+                }
+                _ if self.undetectable.contains(feature) => {
+                    return Err(DetectError::Undetectable(feature.into()));
+                }
+                _ => return Err(DetectError::UnknownFeature(feature.into())),
+            }
+        }
+
+        Ok(RuntimeCheck {
+            expression: if checks.is_empty() {
+                "true".into()
+            } else {
+                checks.join(" && ")
+            },
+            feature_gates,
+        })
+    }
+}
@@ -0,0 +1,87 @@
+use super::*;
+
+#[test]
+fn every_version_has_macros() {
+    for version in crate::versions() {
+        let macros = macros(version).unwrap();
+        for arch in ["x86_64", "aarch64", "arm", "riscv64", "powerpc64"] {
+            assert!(
+                macros.iter().any(|m| m.target_arches.contains(&arch)),
+                "{} {}",
+                version,
+                arch
+            );
+        }
+    }
+    assert!(macros("1.0.0").is_err());
+}
+
+#[test]
+fn aarch64() {
+    let detection = Detection::new("1.86.0", "aarch64-unknown-linux-gnu").unwrap();
+    let detect_macro = detection.detect_macro.as_ref().unwrap();
+    assert_eq!(detect_macro.name, "is_aarch64_feature_detected");
+    assert_eq!(detect_macro.aliases, [("asimd", "neon")]);
+
+    // Architecture versions are target features, but cannot be detected
+    assert!(detection.undetectable.contains("v8.1a"));
+    assert!(detection.detectable["sve2"].is_empty());
+    assert!(detection.not_target_features.contains("asimd"));
+
+    let check = detection.runtime_check(&["sve2", "neon", "sve2"]).unwrap();
+    assert_eq!(
+        check.expression,
+        r#"std::arch::is_aarch64_feature_detected!("sve2")"#
+    );
+    assert_eq!(
+        detection.runtime_check(&["v8.2a"]),
+        Err(DetectError::Undetectable("v8.2a".into()))
+    );
+    assert_eq!(
+        detection.runtime_check(&["avx2"]),
+        Err(DetectError::UnknownFeature("avx2".into()))
+    );
+    assert_eq!(detection.runtime_check(&[]).unwrap().expression, "true");
+}
+
+#[test]
+fn feature_gates() {
+    let detection = Detection::new("1.86.0", "riscv64gc-unknown-linux-gnu").unwrap();
+    let check = detection.runtime_check(&["zba", "v"]).unwrap();
+    assert_eq!(
+        check.expression,
+        r#"std::arch::is_riscv_feature_detected!("v") && std::arch::is_riscv_feature_detected!("zba")"#
+    );
+    assert_eq!(
+        check.feature_gates,
+        BTreeSet::from([
+            "is_riscv_feature_detected",
+            "stdarch_riscv_feature_detection"
+        ])
+    );
+
+    let detection = Detection::new("1.86.0", "x86_64-unknown-linux-gnu").unwrap();
+    let check = detection.runtime_check(&["sha512"]).unwrap();
+    assert_eq!(check.feature_gates, BTreeSet::from(["sha512_sm_x86"]));
+}
+
+#[test]
+fn no_macro() {
+    let detection = Detection::new("1.86.0", "wasm32-unknown-unknown").unwrap();
+    assert_eq!(detection.detect_macro, None);
+    assert!(detection.detectable.is_empty());
+    assert!(detection.undetectable.contains("simd128"));
+    assert_eq!(
+        detection.runtime_check(&["simd128"]),
+        Err(DetectError::Undetectable("simd128".into()))
+    );
+}
+
+#[test]
+fn versions_differ() {
+    // `is_s390x_feature_detected!` was first exported in 1.87.0
+    let before = Detection::new("1.86.0", "s390x-unknown-linux-gnu").unwrap();
+    assert_eq!(before.detect_macro, None);
+    let after = Detection::new("1.87.0", "s390x-unknown-linux-gnu").unwrap();
+    assert!(after.detectable.contains_key("vector"));
+}
@@ -0,0 +1,52 @@
+//! Finding targets with identical target feature data.
+//!
+//! Many targets have exactly the same target features, with the same implications, gates, and
+//! globally enabled features. Code which depends only on target features behaves the same on all
+//! of them, so testing one target from each equivalence class is enough.
+//!
+//! ```
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use rust_target_feature_data::equivalence;
+//!
+//! let equivalent = equivalence::equivalent_targets("1.86.0", "x86_64-unknown-linux-gnu")?;
+//! assert!(equivalent.contains(&"x86_64-unknown-linux-musl"));
+//! assert!(!equivalent.contains(&"x86_64-apple-darwin"));
+//! # Ok(()) }
+//! ```
+
+use crate::NotFoundError;
+use std::collections::BTreeMap;
+
+#[cfg(test)]
+mod tests;
+
+/// Group the targets of a Rust version into classes with identical target feature data.
+///
+/// Targets within each class are sorted, and classes are sorted by their first target.
+pub fn equivalence_classes(rust_version: &str) -> Result<Vec<Vec<&'static str>>, NotFoundError> {
+    let (_, targets) = crate::generated::feature_list_ids()
+        .find(|(version, _)| *version == rust_version)
+        .ok_or_else(|| NotFoundError::CompilerNotFound(rust_version.into()))?;
+
+    let mut classes: BTreeMap<usize, Vec<&'static str>> = BTreeMap::new();
+    for (target, feature_list) in targets {
+        classes.entry(feature_list).or_default().push(target);
+    }
+    let mut classes: Vec<Vec<&'static str>> = classes.into_values().collect();
+    for class in &mut classes {
+        class.sort();
+    }
+    classes.sort();
+    Ok(classes)
+}
+
+/// Find the targets with target feature data identical to `target`, including `target` itself.
+pub fn equivalent_targets(
+    rust_version: &str,
+    target: &str,
+) -> Result<Vec<&'static str>, NotFoundError> {
+    equivalence_classes(rust_version)?
+        .into_iter()
+        .find(|class| class.contains(&target))
+        .ok_or_else(|| NotFoundError::TargetNotFound(target.into()))
+}
@@ -0,0 +1,41 @@
+use super::*;
+
+#[test]
+fn classes_have_identical_features() {
+    for version in crate::versions() {
+        let classes = equivalence_classes(version).unwrap();
+        let mut all: Vec<&str> = classes.iter().flatten().copied().collect();
+        all.sort();
+        all.dedup();
+        assert_eq!(all.len(), classes.iter().map(Vec::len).sum::<usize>());
+
+        for class in &classes {
+            let first: Vec<_> = crate::find(version, class[0]).unwrap().collect();
+            for target in &class[1..] {
+                let features: Vec<_> = crate::find(version, target).unwrap().collect();
+                assert_eq!(features, first, "{} {} {}", version, class[0], target);
+            }
+        }
+        for pair in classes.windows(2) {
+            let a: Vec<_> = crate::find(version, pair[0][0]).unwrap().collect();
+            let b: Vec<_> = crate::find(version, pair[1][0]).unwrap().collect();
+            assert_ne!(a, b, "{} {} {}", version, pair[0][0], pair[1][0]);
+        }
+    }
+}
+
+#[test]
+fn equivalent() {
+    let equivalent = equivalent_targets("1.86.0", "thumbv7em-none-eabi").unwrap();
+    assert!(equivalent.contains(&"thumbv7em-none-eabi"));
+    assert!(equivalent.contains(&"thumbv7em-nuttx-eabi"));
+
+    assert_eq!(
+        equivalent_targets("1.86.0", "nonexistent"),
+        Err(NotFoundError::TargetNotFound("nonexistent".into()))
+    );
+    assert_eq!(
+        equivalent_targets("1.0.0", "x86_64-unknown-linux-gnu"),
+        Err(NotFoundError::CompilerNotFound("1.0.0".into()))
+    );
+}
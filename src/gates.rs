@@ -0,0 +1,105 @@
+//! Language feature gates required by unstable target features.
+//!
+//! Using an unstable target feature, whether through `#[target_feature]` or
+//! `#[cfg(target_feature)]`, requires a nightly compiler and a `#![feature(…)]` attribute enabling
+//! the target feature's language feature gate. Several target features often share one gate.
+//!
+//! ```
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use rust_target_feature_data::gates;
+//!
+//! let gates = gates::feature_gates(
+//!     "1.86.0",
+//!     "x86_64-unknown-linux-gnu",
+//!     &["avx2", "avx512f", "avx512bw", "sha512"],
+//! )?;
+//! assert_eq!(
+//!     gates.attribute().as_deref(),
+//!     Some("#![feature(avx512_target_feature, sha512_sm_x86)]"),
+//! );
+//! assert!(gates.stable.contains("avx2"));
+//! # Ok(()) }
+//! ```
+
+use crate::QueryError;
+use std::collections::{BTreeMap, BTreeSet};
+
+#[cfg(test)]
+mod tests;
+
+/// The language feature gates needed to use a set of target features.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FeatureGates {
+    /// Language feature gates which must be enabled, and the target features which require each.
+    pub required: BTreeMap<&'static str, BTreeSet<&'static str>>,
+    /// Target features which are stable in this Rust version.
+    pub stable: BTreeSet<&'static str>,
+    /// For each required gate, the first later embedded Rust version in which none of these target
+    /// features need it anymore, or `None` if every embedded version still needs it.
+    pub unneeded_since: BTreeMap<&'static str, Option<&'static str>>,
+}
+
+impl FeatureGates {
+    /// Generate a `#![feature(…)]` attribute enabling every required gate.
+    ///
+    /// Returns `None` if no gates are required.
+    pub fn attribute(&self) -> Option<String> {
+        if self.required.is_empty() {
+            return None;
+        }
+        let gates: Vec<&str> = self.required.keys().copied().collect();
+        Some(format!("#![feature({})]", gates.join(", ")))
+    }
+}
+
+/// Determine the language feature gates needed to use `features` on a Rust version and target.
+pub fn feature_gates(
+    rust_version: &str,
+    target: &str,
+    features: &[&str],
+) -> Result<FeatureGates, QueryError> {
+    let target_features: Vec<_> = crate::find(rust_version, target)?.collect();
+
+    let mut required: BTreeMap<&'static str, BTreeSet<&'static str>> = BTreeMap::new();
+    let mut stable = BTreeSet::new();
+    for name in features {
+        let feature = target_features
+            .iter()
+            .find(|f| f.name == *name)
+            .ok_or_else(|| QueryError::UnknownFeature(name.to_string()))?;
+        match feature.unstable_feature_gate {
+            Some(gate) => {
+                required.entry(gate).or_default().insert(feature.name);
+            }
+            None => {
+                stable.insert(feature.name);
+            }
+        }
+    }
+
+    let later_versions: Vec<&'static str> = crate::versions()
+        .into_iter()
+        .skip_while(|version| *version != rust_version)
+        .skip(1)
+        .collect();
+    let unneeded_since = required
+        .iter()
+        .map(|(gate, names)| {
+            let since = later_versions.iter().copied().find(|version| {
+                let Ok(later) = crate::find(version, target) else {
+                    return false;
+                };
+                let later: Vec<_> = later.filter(|f| names.contains(f.name)).collect();
+                later.len() == names.len()
+                    && later.iter().all(|f| f.unstable_feature_gate != Some(*gate))
+            });
+            (*gate, since)
+        })
+        .collect();
+
+    Ok(FeatureGates {
+        required,
+        stable,
+        unneeded_since,
+    })
+}
@@ -0,0 +1,86 @@
+use super::*;
+
+#[test]
+fn required_and_stable() {
+    let gates = feature_gates(
+        "1.86.0",
+        "x86_64-unknown-linux-gnu",
+        &["avx2", "avx512f", "avx512bw", "sha512"],
+    )
+    .unwrap();
+    assert_eq!(
+        gates.required,
+        BTreeMap::from([
+            (
+                "avx512_target_feature",
+                BTreeSet::from(["avx512bw", "avx512f"])
+            ),
+            ("sha512_sm_x86", BTreeSet::from(["sha512"])),
+        ])
+    );
+    assert_eq!(gates.stable, BTreeSet::from(["avx2"]));
+    assert_eq!(
+        gates.unneeded_since,
+        BTreeMap::from([("avx512_target_feature", None), ("sha512_sm_x86", None)])
+    );
+}
+
+#[test]
+fn stabilized_later() {
+    // wasm32's multivalue and relaxed-simd were stabilized in 1.82.0, and the next version in the
+    // data is 1.85.0
+    let gates = feature_gates(
+        "1.81.0",
+        "wasm32-unknown-unknown",
+        &["multivalue", "relaxed-simd", "simd128"],
+    )
+    .unwrap();
+    assert_eq!(
+        gates.required,
+        BTreeMap::from([(
+            "wasm_target_feature",
+            BTreeSet::from(["multivalue", "relaxed-simd"])
+        )])
+    );
+    assert_eq!(
+        gates.unneeded_since,
+        BTreeMap::from([("wasm_target_feature", Some("1.85.0"))])
+    );
+
+    // The gate is still needed while any of its features are unstable
+    let gates = feature_gates(
+        "1.81.0",
+        "wasm32-unknown-unknown",
+        &["multivalue", "atomics"],
+    )
+    .unwrap();
+    assert_eq!(
+        gates.unneeded_since,
+        BTreeMap::from([("wasm_target_feature", None)])
+    );
+}
+
+#[test]
+fn stable_only() {
+    let gates = feature_gates("1.85.0", "aarch64-unknown-linux-gnu", &["neon", "sve2"]).unwrap();
+    assert!(gates.required.is_empty());
+    assert_eq!(gates.attribute(), None);
+    assert_eq!(gates.stable, BTreeSet::from(["neon", "sve2"]));
+}
+
+#[test]
+fn attribute() {
+    let gates = feature_gates("1.87.0", "aarch64-unknown-linux-gnu", &["v8.1a", "sme"]).unwrap();
+    assert_eq!(
+        gates.attribute().as_deref(),
+        Some("#![feature(aarch64_unstable_target_feature, aarch64_ver_target_feature)]")
+    );
+}
+
+#[test]
+fn unknown_feature() {
+    assert_eq!(
+        feature_gates("1.86.0", "x86_64-unknown-linux-gnu", &["neon"]).unwrap_err(),
+        QueryError::UnknownFeature("neon".into())
+    );
+}
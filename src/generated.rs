@@ -10,6 +10,7 @@ static NAMES: &[&str] = &[
     "3e7",
     "7e10",
     "a",
+    "aarch64",
     "aarch64-apple-darwin",
     "aarch64-apple-ios",
     "aarch64-apple-ios-macabi",
@@ -20,6 +21,7 @@ static NAMES: &[&str] = &[
     "aarch64-apple-visionos-sim",
     "aarch64-apple-watchos",
     "aarch64-apple-watchos-sim",
+    "aarch64-fuchsia",
     "aarch64-kmc-solid_asp3",
     "aarch64-linux-android",
     "aarch64-nintendo-switch-freestanding",
@@ -53,10 +55,12 @@ static NAMES: &[&str] = &[
     "aarch64_be-unknown-netbsd",
     "aarch64_unstable_target_feature",
     "aarch64_ver_target_feature",
+    "abm",
     "aclass",
     "adx",
     "aes",
     "altivec",
+    "altnzcv",
     "alu32",
     "amdgcn-amd-amdhsa",
     "amx-bf16",
@@ -64,6 +68,7 @@ static NAMES: &[&str] = &[
     "amx-fp16",
     "amx-int8",
     "amx-tile",
+    "arm",
     "arm-linux-androideabi",
     "arm-unknown-linux-gnueabi",
     "arm-unknown-linux-gnueabihf",
@@ -73,6 +78,7 @@ static NAMES: &[&str] = &[
     "arm64e-apple-darwin",
     "arm64e-apple-ios",
     "arm64e-apple-tvos",
+    "arm64ec",
     "arm64ec-pc-windows-msvc",
     "arm_target_feature",
     "armeb-unknown-linux-gnueabi",
@@ -112,6 +118,7 @@ static NAMES: &[&str] = &[
     "armv7r-none-eabihf",
     "armv7s-apple-ios",
     "armv8r-none-eabihf",
+    "asimd",
     "atomics",
     "avr-none",
     "avr-unknown-gnu-atmega328",
@@ -123,22 +130,29 @@ static NAMES: &[&str] = &[
     "avx512bw",
     "avx512cd",
     "avx512dq",
+    "avx512er",
     "avx512f",
     "avx512fp16",
+    "avx512gfni",
     "avx512ifma",
+    "avx512pf",
+    "avx512vaes",
     "avx512vbmi",
     "avx512vbmi2",
     "avx512vl",
     "avx512vnni",
     "avx512vp2intersect",
+    "avx512vpclmulqdq",
     "avx512vpopcntdq",
     "avxifma",
     "avxneconvert",
     "avxvnni",
     "avxvnniint16",
     "avxvnniint8",
+    "b16b16",
     "backchain",
     "bf16",
+    "bmi",
     "bmi1",
     "bmi2",
     "bpf_target_feature",
@@ -148,12 +162,18 @@ static NAMES: &[&str] = &[
     "bulk-memory",
     "c",
     "cache",
+    "ccdp",
+    "ccpp",
     "cmpxchg16b",
+    "complxnum",
     "crc",
+    "crc32",
+    "crypto",
     "csky-unknown-linux-gnuabiv2",
     "csky-unknown-linux-gnuabiv2hf",
     "csky_target_feature",
     "cssc",
+    "cx16",
     "d",
     "d32",
     "deflate-conversion",
@@ -175,6 +195,7 @@ static NAMES: &[&str] = &[
     "enhanced-sort",
     "ermsb",
     "ermsb_target_feature",
+    "evex512",
     "exception-handling",
     "extended-const",
     "f",
@@ -182,6 +203,7 @@ static NAMES: &[&str] = &[
     "f32mm",
     "f64mm",
     "faminmax",
+    "fast-unaligned-access",
     "fcma",
     "fdivdu",
     "fhm",
@@ -193,14 +215,18 @@ static NAMES: &[&str] = &[
     "float7e60",
     "floate1",
     "fma",
+    "fp",
     "fp-armv8",
     "fp16",
+    "fp16fml",
     "fp64",
     "fp8",
     "fp8dot2",
     "fp8dot4",
     "fp8fma",
+    "fpmr",
     "fpregs",
+    "fptoint",
     "fpuv2_df",
     "fpuv2_sf",
     "fpuv3_df",
@@ -209,12 +235,15 @@ static NAMES: &[&str] = &[
     "fpuv3_sf",
     "frecipe",
     "frintts",
+    "fullfp16",
     "fxsr",
     "gfni",
     "guarded-storage",
+    "h",
     "hard-float",
     "hard-float-abi",
     "hard-tp",
+    "hasleoncasa",
     "hbc",
     "hexagon-unknown-linux-musl",
     "hexagon-unknown-none-elf",
@@ -252,6 +281,16 @@ static NAMES: &[&str] = &[
     "i686-win7-windows-msvc",
     "i686-wrs-vxworks",
     "i8mm",
+    "is_aarch64_feature_detected",
+    "is_arm_feature_detected",
+    "is_loongarch_feature_detected",
+    "is_mips64_feature_detected",
+    "is_mips_feature_detected",
+    "is_powerpc64_feature_detected",
+    "is_powerpc_feature_detected",
+    "is_riscv_feature_detected",
+    "is_s390x_feature_detected",
+    "is_x86_feature_detected",
     "isa-68000",
     "isa-68010",
     "isa-68020",
@@ -260,6 +299,7 @@ static NAMES: &[&str] = &[
     "isa-68060",
     "isa-68881",
     "isa-68882",
+    "j",
     "jsconv",
     "keylocker_x86",
     "kl",
@@ -271,6 +311,7 @@ static NAMES: &[&str] = &[
     "lbt",
     "ld-seq-sa",
     "leoncasa",
+    "loongarch64",
     "loongarch64-unknown-linux-gnu",
     "loongarch64-unknown-linux-musl",
     "loongarch64-unknown-linux-ohos",
@@ -290,10 +331,12 @@ static NAMES: &[&str] = &[
     "m68k-unknown-none-elf",
     "m68k_target_feature",
     "mclass",
+    "mips",
     "mips-mti-none-elf",
     "mips-unknown-linux-gnu",
     "mips-unknown-linux-musl",
     "mips-unknown-linux-uclibc",
+    "mips64",
     "mips64-openwrt-linux-musl",
     "mips64-unknown-linux-gnuabi64",
     "mips64-unknown-linux-muslabi64",
@@ -312,6 +355,7 @@ static NAMES: &[&str] = &[
     "mipsisa32r6el-unknown-linux-gnu",
     "mipsisa64r6-unknown-linux-gnuabi64",
     "mipsisa64r6el-unknown-linux-gnuabi64",
+    "mmx",
     "mops",
     "movbe",
     "mp",
@@ -327,20 +371,27 @@ static NAMES: &[&str] = &[
     "nontrapping-fptoint",
     "nvic",
     "nvptx64-nvidia-cuda",
+    "p",
     "paca",
     "pacg",
     "pan",
     "partword-atomics",
+    "pauth",
     "pauth-lr",
+    "pclmul",
     "pclmulqdq",
+    "perfmon",
+    "pmull",
     "pmuv3",
     "popcnt",
     "power10-vector",
+    "power8",
     "power8-altivec",
     "power8-crypto",
     "power8-vector",
     "power9-altivec",
     "power9-vector",
+    "powerpc",
     "powerpc-unknown-freebsd",
     "powerpc-unknown-linux-gnu",
     "powerpc-unknown-linux-gnuspe",
@@ -350,6 +401,7 @@ static NAMES: &[&str] = &[
     "powerpc-unknown-openbsd",
     "powerpc-wrs-vxworks",
     "powerpc-wrs-vxworks-spe",
+    "powerpc64",
     "powerpc64-ibm-aix",
     "powerpc64-unknown-freebsd",
     "powerpc64-unknown-linux-gnu",
@@ -362,20 +414,24 @@ static NAMES: &[&str] = &[
     "powerpc_target_feature",
     "prfchw",
     "prfchw_target_feature",
+    "q",
     "quadword-atomics",
     "rand",
     "ras",
     "rclass",
     "rcpc",
+    "rcpc-immo",
     "rcpc2",
     "rcpc3",
     "rdm",
     "rdrand",
+    "rdrnd",
     "rdseed",
     "reference-types",
     "relax",
     "relaxed-simd",
     "reserve-x18",
+    "riscv32",
     "riscv32-wrs-vxworks",
     "riscv32e-unknown-none-elf",
     "riscv32em-unknown-none-elf",
@@ -396,6 +452,7 @@ static NAMES: &[&str] = &[
     "riscv32imc-esp-espidf",
     "riscv32imc-unknown-none-elf",
     "riscv32imc-unknown-nuttx-elf",
+    "riscv64",
     "riscv64-linux-android",
     "riscv64-wrs-vxworks",
     "riscv64gc-unknown-freebsd",
@@ -412,9 +469,16 @@ static NAMES: &[&str] = &[
     "riscv_target_feature",
     "rtm",
     "rtm_target_feature",
+    "rv128i",
+    "rv32e",
+    "rv32i",
+    "rv64i",
+    "s",
+    "s390x",
     "s390x-unknown-linux-gnu",
     "s390x-unknown-linux-musl",
     "s390x_target_feature",
+    "sahf",
     "sb",
     "scq",
     "sha",
@@ -458,6 +522,13 @@ static NAMES: &[&str] = &[
     "ssve-fp8dot2",
     "ssve-fp8dot4",
     "ssve-fp8fma",
+    "stdarch_aarch64_feature_detection",
+    "stdarch_arm_feature_detection",
+    "stdarch_loongarch_feature_detection",
+    "stdarch_mips_feature_detection",
+    "stdarch_powerpc_feature_detection",
+    "stdarch_riscv_feature_detection",
+    "stdarch_s390x_feature_detection",
     "sve",
     "sve-b16b16",
     "sve2",
@@ -466,6 +537,9 @@ static NAMES: &[&str] = &[
     "sve2-sha3",
     "sve2-sm4",
     "sve2p1",
+    "svinval",
+    "svnapot",
+    "svpbmt",
     "tail-call",
     "tbm",
     "tbm_target_feature",
@@ -498,6 +572,7 @@ static NAMES: &[&str] = &[
     "transactional-execution",
     "trust",
     "trustzone",
+    "tsc",
     "ual",
     "unaligned-scalar-mem",
     "v",
@@ -545,6 +620,7 @@ static NAMES: &[&str] = &[
     "wasm32-unknown-emscripten",
     "wasm32-unknown-unknown",
     "wasm32-wali-linux-musl",
+    "wasm32-wasi",
     "wasm32-wasip1",
     "wasm32-wasip1-threads",
     "wasm32-wasip2",
@@ -554,12 +630,15 @@ static NAMES: &[&str] = &[
     "wfxt",
     "wide-arithmetic",
     "widekl",
+    "x86",
+    "x86_64",
     "x86_64-apple-darwin",
     "x86_64-apple-ios",
     "x86_64-apple-ios-macabi",
     "x86_64-apple-tvos",
     "x86_64-apple-watchos-sim",
     "x86_64-fortanix-unknown-sgx",
+    "x86_64-fuchsia",
     "x86_64-linux-android",
     "x86_64-pc-cygwin",
     "x86_64-pc-nto-qnx710",
@@ -616,6 +695,7 @@ static NAMES: &[&str] = &[
     "zabha",
     "zacas",
     "zalrsc",
+    "zam",
     "zama16b",
     "zawrs",
     "zba",
@@ -631,6 +711,11 @@ static NAMES: &[&str] = &[
     "zfinx",
     "zhinx",
     "zhinxmin",
+    "zicntr",
+    "zicsr",
+    "zifencei",
+    "zihintpause",
+    "zihpm",
     "zk",
     "zkn",
     "zknd",
@@ -641,34 +726,41 @@ static NAMES: &[&str] = &[
     "zksed",
     "zksh",
     "zkt",
+    "ztso",
 ];
-static IMPLIES_FEATURES_NAMES: &[u16] = &[
-    0u16, 2u16, 0u16, 3u16, 158u16, 0u16, 6u16, 0u16, 7u16, 0u16, 54u16, 115u16, 0u16,
-    54u16, 459u16, 0u16, 55u16, 0u16, 62u16, 0u16, 114u16, 0u16, 114u16, 331u16, 0u16,
-    114u16, 451u16, 0u16, 115u16, 0u16, 115u16, 177u16, 191u16, 0u16, 119u16, 0u16,
-    119u16, 121u16, 127u16, 0u16, 122u16, 0u16, 137u16, 0u16, 137u16, 180u16, 281u16,
-    0u16, 137u16, 250u16, 510u16, 0u16, 137u16, 434u16, 458u16, 0u16, 143u16, 161u16,
-    414u16, 445u16, 509u16, 0u16, 148u16, 276u16, 277u16, 328u16, 368u16, 536u16, 0u16,
-    152u16, 513u16, 0u16, 153u16, 0u16, 154u16, 533u16, 0u16, 156u16, 159u16, 184u16,
-    508u16, 0u16, 158u16, 0u16, 160u16, 0u16, 160u16, 363u16, 506u16, 0u16, 167u16, 0u16,
-    170u16, 0u16, 176u16, 0u16, 193u16, 0u16, 195u16, 0u16, 195u16, 434u16, 0u16, 197u16,
-    0u16, 198u16, 0u16, 214u16, 311u16, 512u16, 0u16, 220u16, 0u16, 251u16, 0u16, 252u16,
-    0u16, 253u16, 0u16, 254u16, 258u16, 0u16, 255u16, 0u16, 257u16, 0u16, 259u16, 326u16,
-    327u16, 365u16, 507u16, 0u16, 261u16, 0u16, 277u16, 0u16, 280u16, 0u16, 321u16, 0u16,
-    335u16, 0u16, 335u16, 540u16, 0u16, 337u16, 338u16, 0u16, 339u16, 0u16, 365u16, 0u16,
-    366u16, 0u16, 417u16, 0u16, 418u16, 459u16, 0u16, 422u16, 0u16, 424u16, 459u16, 0u16,
-    425u16, 0u16, 425u16, 459u16, 0u16, 430u16, 0u16, 434u16, 0u16, 446u16, 0u16, 447u16,
-    0u16, 448u16, 0u16, 449u16, 0u16, 450u16, 0u16, 453u16, 0u16, 455u16, 0u16, 456u16,
-    0u16, 457u16, 0u16, 459u16, 0u16, 459u16, 510u16, 0u16, 469u16, 502u16, 0u16, 500u16,
-    0u16, 501u16, 0u16, 503u16, 0u16, 504u16, 0u16, 511u16, 522u16, 0u16, 511u16, 550u16,
-    0u16, 512u16, 517u16, 0u16, 513u16, 518u16, 0u16, 514u16, 519u16, 0u16, 520u16, 0u16,
-    527u16, 0u16, 528u16, 0u16, 530u16, 0u16, 531u16, 0u16, 534u16, 0u16, 535u16, 0u16,
-    599u16, 0u16, 611u16, 0u16, 611u16, 614u16, 0u16, 620u16, 621u16, 622u16, 632u16,
-    633u16, 634u16, 0u16, 620u16, 621u16, 622u16, 637u16, 638u16, 0u16, 626u16, 0u16,
-    627u16, 0u16, 629u16, 0u16, 631u16, 635u16, 639u16, 0u16,
+static IMPLIES_FEATURES_NAMES: &[u32] = &[
+    0u32, 2u32, 0u32, 3u32, 178u32, 0u32, 6u32, 0u32, 7u32, 0u32, 57u32, 122u32, 0u32,
+    57u32, 530u32, 0u32, 58u32, 0u32, 66u32, 0u32, 121u32, 0u32, 121u32, 378u32, 0u32,
+    121u32, 515u32, 0u32, 122u32, 0u32, 122u32, 198u32, 213u32, 0u32, 126u32, 0u32,
+    126u32, 128u32, 138u32, 0u32, 130u32, 0u32, 150u32, 0u32, 150u32, 201u32, 322u32,
+    0u32, 150u32, 279u32, 585u32, 0u32, 150u32, 498u32, 529u32, 0u32, 157u32, 181u32,
+    478u32, 509u32, 584u32, 0u32, 165u32, 317u32, 318u32, 373u32, 422u32, 611u32, 0u32,
+    171u32, 588u32, 0u32, 173u32, 0u32, 174u32, 608u32, 0u32, 176u32, 179u32, 206u32,
+    583u32, 0u32, 178u32, 0u32, 180u32, 0u32, 180u32, 416u32, 581u32, 0u32, 187u32, 0u32,
+    190u32, 0u32, 197u32, 0u32, 216u32, 0u32, 219u32, 0u32, 219u32, 498u32, 0u32, 221u32,
+    0u32, 222u32, 0u32, 243u32, 355u32, 587u32, 0u32, 249u32, 0u32, 290u32, 0u32, 291u32,
+    0u32, 292u32, 0u32, 293u32, 297u32, 0u32, 294u32, 0u32, 296u32, 0u32, 299u32, 371u32,
+    372u32, 418u32, 582u32, 0u32, 301u32, 0u32, 318u32, 0u32, 321u32, 0u32, 365u32, 0u32,
+    385u32, 0u32, 385u32, 615u32, 0u32, 387u32, 388u32, 0u32, 389u32, 0u32, 418u32, 0u32,
+    420u32, 0u32, 481u32, 0u32, 482u32, 530u32, 0u32, 486u32, 0u32, 488u32, 530u32, 0u32,
+    489u32, 0u32, 489u32, 530u32, 0u32, 494u32, 0u32, 498u32, 0u32, 510u32, 0u32, 511u32,
+    0u32, 512u32, 0u32, 513u32, 0u32, 514u32, 0u32, 517u32, 0u32, 519u32, 0u32, 520u32,
+    0u32, 528u32, 0u32, 530u32, 0u32, 530u32, 585u32, 0u32, 543u32, 577u32, 0u32, 575u32,
+    0u32, 576u32, 0u32, 578u32, 0u32, 579u32, 0u32, 586u32, 597u32, 0u32, 586u32, 626u32,
+    0u32, 587u32, 592u32, 0u32, 588u32, 593u32, 0u32, 589u32, 594u32, 0u32, 595u32, 0u32,
+    602u32, 0u32, 603u32, 0u32, 605u32, 0u32, 606u32, 0u32, 609u32, 0u32, 610u32, 0u32,
+    678u32, 0u32, 690u32, 0u32, 690u32, 693u32, 0u32, 700u32, 701u32, 702u32, 717u32,
+    718u32, 719u32, 0u32, 700u32, 701u32, 702u32, 722u32, 723u32, 0u32, 706u32, 0u32,
+    707u32, 0u32, 709u32, 0u32, 716u32, 720u32, 724u32, 0u32,
 ];
-fn get_implies_features(mut n: usize) -> impl Iterator<Item = &'static str> {
-    let mut values = IMPLIES_FEATURES_NAMES
+fn get_implies_features(n: usize) -> impl Iterator<Item = &'static str> {
+    get_name_list(IMPLIES_FEATURES_NAMES, n)
+}
+fn get_name_list(
+    lists: &'static [u32],
+    mut n: usize,
+) -> impl Iterator<Item = &'static str> {
+    let mut values = lists
         .iter()
         .skip_while(move |v| {
             if n == 0 {
@@ -686,20 +778,43 @@ fn get_implies_features(mut n: usize) -> impl Iterator<Item = &'static str> {
         })
         .fuse()
 }
+/// Decode a sequence of unsigned LEB128 values.
+///
+/// The sequence ends early at a truncated value, or at one which doesn't fit in `usize`.
+pub(crate) fn read_values(blob: &[u8]) -> impl Iterator<Item = usize> {
+    let mut bytes = blob.iter();
+    std::iter::from_fn(move || read_value(&mut bytes)).fuse()
+}
+/// Decode one unsigned LEB128 value.
+fn read_value(bytes: &mut std::slice::Iter<u8>) -> Option<usize> {
+    let mut value = 0;
+    let mut shift = 0;
+    loop {
+        let byte = *bytes.next()?;
+        let bits = usize::from(byte & 0x7f);
+        let shifted = bits.checked_shl(shift)?;
+        if shifted >> shift != bits {
+            return None;
+        }
+        value |= shifted;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+        shift += 7;
+    }
+}
 static FEATURES_BLOB: &[u8] = include_bytes!("generated_features.blob");
-fn get_feature(n: usize) -> TargetFeature {
-    let offset = n * 6;
-    let blob = &FEATURES_BLOB[offset..];
-    let mut list = blob
-        .chunks(2)
-        .map(|bytes| u16::from_le_bytes(bytes.try_into().unwrap()));
-    let name = NAMES[list.next().unwrap() as usize];
-    let packed = list.next().unwrap();
-    let unstable_feature_gate = (packed & 0x7fff)
+fn get_feature(offset: usize) -> TargetFeature {
+    let mut values = read_values(&FEATURES_BLOB[offset..]);
+    let name = NAMES[values.next().unwrap()];
+    let packed = values.next().unwrap();
+    let unstable_feature_gate = (packed >> 1).checked_sub(1).map(|idx| NAMES[idx]);
+    let globally_enabled = (packed & 1) != 0;
+    let implies_features = values
+        .next()
+        .unwrap()
         .checked_sub(1)
-        .map(|idx| NAMES[idx as usize]);
-    let globally_enabled = (packed & 0x8000) != 0;
-    let implies_features = get_implies_features(list.next().unwrap() as usize).collect();
+        .map(|idx| get_implies_features(idx).collect());
     TargetFeature {
         name,
         unstable_feature_gate,
@@ -709,64 +824,782 @@ fn get_feature(n: usize) -> TargetFeature {
 }
 static FEATURE_LISTS_BLOB: &[u8] = include_bytes!("generated_feature_lists.blob");
 static FEATURE_LISTS_OFFSETS: &[usize] = &[
-    0usize, 186usize, 372usize, 558usize, 744usize, 930usize, 1116usize, 1168usize,
-    1220usize, 1272usize, 1324usize, 1376usize, 1428usize, 1482usize, 1536usize,
-    1588usize, 1640usize, 1692usize, 1744usize, 1796usize, 1848usize, 1902usize,
-    1954usize, 2006usize, 2058usize, 2060usize, 2064usize, 2144usize, 2224usize,
-    2230usize, 2360usize, 2490usize, 2620usize, 2750usize, 2882usize, 2902usize,
-    2922usize, 2942usize, 2960usize, 2968usize, 2976usize, 2998usize, 3020usize,
-    3042usize, 3064usize, 3134usize, 3204usize, 3274usize, 3344usize, 3414usize,
-    3484usize, 3554usize, 3624usize, 3694usize, 3764usize, 3834usize, 3840usize,
-    3848usize, 3856usize, 3910usize, 3964usize, 4016usize, 4068usize, 4120usize,
-    4172usize, 4200usize, 4228usize, 4256usize, 4284usize, 4414usize, 4544usize,
-    4674usize, 4804usize, 4934usize, 4992usize, 5050usize, 5108usize, 5166usize,
-    5224usize, 5282usize, 5340usize, 5398usize, 5456usize, 5514usize, 5572usize,
-    5630usize, 5688usize, 5746usize, 5804usize, 5862usize, 5920usize, 5978usize,
-    6116usize, 6254usize, 6392usize, 6530usize, 6668usize, 6686usize, 6758usize,
-    6830usize, 6902usize, 6974usize, 7046usize, 7118usize, 7190usize, 7262usize,
-    7334usize, 7406usize, 7478usize, 7536usize, 7594usize, 7652usize, 7710usize,
-    7768usize, 7826usize, 7884usize, 7942usize, 8000usize, 8138usize, 8276usize,
-    8414usize, 8552usize, 8690usize, 8876usize, 8906usize, 8936usize, 8960usize,
-    8984usize, 9008usize, 9032usize, 9056usize, 9138usize, 9220usize, 9302usize,
-    9384usize, 9466usize, 9548usize, 9630usize, 9712usize, 9794usize, 9876usize,
-    9958usize, 9986usize, 9994usize, 10022usize,
+    0usize, 102usize, 204usize, 306usize, 408usize, 510usize, 560usize, 610usize,
+    660usize, 710usize, 760usize, 810usize, 860usize, 910usize, 960usize, 1010usize,
+    1060usize, 1110usize, 1160usize, 1210usize, 1260usize, 1310usize, 1311usize,
+    1314usize, 1386usize, 1458usize, 1463usize, 1584usize, 1705usize, 1826usize,
+    1947usize, 2068usize, 2087usize, 2106usize, 2113usize, 2120usize, 2135usize,
+    2150usize, 2165usize, 2180usize, 2244usize, 2308usize, 2372usize, 2436usize,
+    2500usize, 2564usize, 2628usize, 2692usize, 2742usize, 2792usize, 2842usize,
+    2892usize, 2942usize, 2992usize, 3015usize, 3038usize, 3061usize, 3182usize,
+    3303usize, 3424usize, 3545usize, 3730usize, 3915usize, 4100usize, 4285usize,
+    4470usize, 4655usize, 4705usize, 4755usize, 4805usize, 4855usize, 4905usize,
+    4955usize, 5007usize, 5059usize, 5109usize, 5159usize, 5209usize, 5259usize,
+    5309usize, 5359usize, 5411usize, 5461usize, 5511usize, 5561usize, 5564usize,
+    5636usize, 5708usize, 5713usize, 5841usize, 5969usize, 6097usize, 6225usize,
+    6355usize, 6374usize, 6393usize, 6412usize, 6429usize, 6436usize, 6443usize,
+    6464usize, 6485usize, 6506usize, 6527usize, 6595usize, 6663usize, 6731usize,
+    6799usize, 6867usize, 6935usize, 7003usize, 7071usize, 7139usize, 7207usize,
+    7275usize, 7280usize, 7287usize, 7294usize, 7346usize, 7398usize, 7448usize,
+    7498usize, 7548usize, 7598usize, 7625usize, 7652usize, 7679usize, 7706usize,
+    7834usize, 7962usize, 8090usize, 8218usize, 8346usize, 8402usize, 8458usize,
+    8514usize, 8570usize, 8626usize, 8682usize, 8738usize, 8794usize, 8850usize,
+    8906usize, 8962usize, 9018usize, 9074usize, 9130usize, 9186usize, 9242usize,
+    9298usize, 9354usize, 9490usize, 9626usize, 9762usize, 9898usize, 10034usize,
+    10051usize, 10121usize, 10191usize, 10261usize, 10331usize, 10401usize, 10471usize,
+    10541usize, 10611usize, 10681usize, 10751usize, 10821usize, 10877usize, 10933usize,
+    10989usize, 11045usize, 11101usize, 11157usize, 11213usize, 11269usize, 11325usize,
+    11461usize, 11597usize, 11733usize, 11869usize, 12005usize, 12190usize, 12219usize,
+    12248usize, 12271usize, 12294usize, 12317usize, 12340usize, 12363usize, 12443usize,
+    12523usize, 12603usize, 12683usize, 12763usize, 12843usize, 12923usize, 13003usize,
+    13083usize, 13163usize, 13243usize, 13270usize, 13277usize, 13304usize,
 ];
 fn get_feature_list(n: usize) -> impl Iterator<Item = TargetFeature> {
     let offset = FEATURE_LISTS_OFFSETS[n];
-    let blob = &FEATURE_LISTS_BLOB[offset..];
-    let mut list = blob
-        .chunks(2)
-        .map(|bytes| u16::from_le_bytes(bytes.try_into().unwrap()));
+    let mut values = read_values(&FEATURE_LISTS_BLOB[offset..]);
     std::iter::from_fn(move || {
-            list.next().unwrap().checked_sub(1).map(|id| get_feature(id as usize))
+            values.next().unwrap().checked_sub(1).map(get_feature)
         })
         .fuse()
 }
 static TARGET_MAPS_BLOB: &[u8] = include_bytes!("generated_target_maps.blob");
 static TARGET_MAPS_OFFSETS: &[(&str, usize)] = &[
-    ("1.85.0", 0usize),
-    ("1.86.0", 1084usize),
-    ("1.87.0", 2232usize),
-    ("", 3380usize),
+    ("1.81.0", 0usize),
+    ("1.85.0", 661usize),
+    ("1.86.0", 1398usize),
+    ("1.87.0", 2334usize),
+    ("", 3301usize),
 ];
 pub(crate) fn all() -> impl Iterator<
     Item = (
         &'static str,
         impl Iterator<Item = (&'static str, impl Iterator<Item = TargetFeature>)>,
     ),
+> {
+    feature_list_ids()
+        .map(|(version, targets)| {
+            let targets = targets
+                .map(|(target, feature_list)| (target, get_feature_list(feature_list)));
+            (version, targets)
+        })
+}
+pub(crate) fn feature_list_ids() -> impl Iterator<
+    Item = (&'static str, impl Iterator<Item = (&'static str, usize)>),
 > {
     TARGET_MAPS_OFFSETS
         .windows(2)
         .map(|window| {
             let &[(version, start), (_, end)] = window else { unreachable!() };
-            let slice = &TARGET_MAPS_BLOB[start..end];
-            let targets = slice
-                .chunks(4)
-                .map(|bytes| {
-                    let target_name = u16::from_le_bytes([bytes[0], bytes[1]]);
-                    let feature_list = u16::from_le_bytes([bytes[2], bytes[3]]);
-                    (NAMES[target_name as usize], get_feature_list(feature_list.into()))
-                });
+            let mut values = read_values(&TARGET_MAPS_BLOB[start..end]);
+            let targets = std::iter::from_fn(move || {
+                let target_name = values.next()?;
+                let feature_list = values.next().unwrap();
+                Some((NAMES[target_name], feature_list))
+            });
             (version, targets)
         })
 }
+static LLVM_FEATURES_NAMES: &[u32] = &[
+    1u32, 0u32, 2u32, 0u32, 3u32, 0u32, 4u32, 0u32, 5u32, 0u32, 6u32, 0u32, 7u32, 0u32,
+    8u32, 0u32, 55u32, 0u32, 56u32, 0u32, 57u32, 0u32, 58u32, 0u32, 60u32, 0u32, 62u32,
+    0u32, 63u32, 0u32, 64u32, 0u32, 65u32, 0u32, 66u32, 0u32, 118u32, 0u32, 121u32, 0u32,
+    122u32, 0u32, 124u32, 194u32, 0u32, 125u32, 194u32, 0u32, 126u32, 194u32, 0u32,
+    127u32, 194u32, 0u32, 128u32, 194u32, 0u32, 130u32, 194u32, 0u32, 131u32, 194u32,
+    0u32, 133u32, 194u32, 0u32, 136u32, 194u32, 0u32, 137u32, 194u32, 0u32, 138u32,
+    194u32, 0u32, 139u32, 194u32, 0u32, 140u32, 194u32, 0u32, 142u32, 194u32, 0u32,
+    143u32, 0u32, 144u32, 0u32, 145u32, 0u32, 146u32, 0u32, 147u32, 0u32, 150u32, 0u32,
+    151u32, 0u32, 153u32, 0u32, 157u32, 0u32, 158u32, 0u32, 159u32, 0u32, 160u32, 0u32,
+    172u32, 0u32, 165u32, 0u32, 173u32, 0u32, 174u32, 0u32, 176u32, 0u32, 178u32, 0u32,
+    179u32, 0u32, 162u32, 0u32, 161u32, 0u32, 182u32, 0u32, 183u32, 0u32, 184u32, 0u32,
+    185u32, 0u32, 186u32, 0u32, 187u32, 0u32, 189u32, 0u32, 190u32, 0u32, 192u32, 0u32,
+    195u32, 0u32, 196u32, 0u32, 197u32, 0u32, 198u32, 0u32, 199u32, 365u32, 0u32, 200u32,
+    365u32, 0u32, 164u32, 0u32, 204u32, 0u32, 217u32, 365u32, 0u32, 206u32, 0u32, 208u32,
+    0u32, 209u32, 0u32, 210u32, 0u32, 211u32, 0u32, 212u32, 0u32, 213u32, 0u32, 215u32,
+    0u32, 234u32, 365u32, 0u32, 218u32, 0u32, 226u32, 0u32, 227u32, 0u32, 228u32, 0u32,
+    229u32, 0u32, 230u32, 0u32, 231u32, 0u32, 232u32, 0u32, 225u32, 0u32, 235u32, 0u32,
+    236u32, 0u32, 239u32, 0u32, 240u32, 0u32, 241u32, 0u32, 247u32, 0u32, 249u32, 0u32,
+    250u32, 0u32, 251u32, 0u32, 279u32, 0u32, 299u32, 365u32, 0u32, 477u32, 0u32, 306u32,
+    0u32, 307u32, 0u32, 317u32, 0u32, 318u32, 0u32, 321u32, 0u32, 323u32, 0u32, 324u32,
+    0u32, 325u32, 0u32, 329u32, 0u32, 356u32, 0u32, 357u32, 0u32, 358u32, 0u32, 359u32,
+    0u32, 362u32, 0u32, 363u32, 0u32, 364u32, 0u32, 365u32, 215u32, 0u32, 365u32, 0u32,
+    367u32, 0u32, 368u32, 0u32, 375u32, 0u32, 373u32, 0u32, 377u32, 0u32, 379u32, 0u32,
+    382u32, 0u32, 383u32, 0u32, 385u32, 0u32, 387u32, 0u32, 388u32, 0u32, 389u32, 0u32,
+    411u32, 0u32, 415u32, 0u32, 416u32, 0u32, 417u32, 0u32, 418u32, 0u32, 419u32, 0u32,
+    422u32, 0u32, 424u32, 0u32, 425u32, 0u32, 426u32, 0u32, 427u32, 0u32, 428u32, 0u32,
+    466u32, 0u32, 478u32, 0u32, 480u32, 0u32, 481u32, 0u32, 482u32, 0u32, 485u32, 0u32,
+    486u32, 0u32, 488u32, 0u32, 508u32, 0u32, 509u32, 0u32, 510u32, 0u32, 511u32, 0u32,
+    512u32, 0u32, 513u32, 0u32, 514u32, 166u32, 0u32, 515u32, 0u32, 517u32, 0u32, 528u32,
+    365u32, 0u32, 530u32, 365u32, 0u32, 531u32, 365u32, 0u32, 532u32, 365u32, 0u32,
+    533u32, 365u32, 0u32, 534u32, 365u32, 0u32, 540u32, 0u32, 542u32, 0u32, 543u32, 0u32,
+    567u32, 0u32, 569u32, 0u32, 570u32, 0u32, 572u32, 0u32, 202u32, 0u32, 574u32, 0u32,
+    575u32, 0u32, 576u32, 0u32, 577u32, 0u32, 578u32, 0u32, 579u32, 0u32, 580u32, 0u32,
+    581u32, 0u32, 582u32, 0u32, 583u32, 0u32, 584u32, 0u32, 585u32, 0u32, 586u32, 0u32,
+    587u32, 0u32, 598u32, 0u32, 599u32, 0u32, 600u32, 0u32, 601u32, 0u32, 608u32, 0u32,
+    609u32, 0u32, 610u32, 0u32, 611u32, 0u32, 612u32, 0u32, 613u32, 0u32, 614u32, 0u32,
+    615u32, 0u32, 676u32, 0u32, 678u32, 0u32, 679u32, 0u32, 680u32, 0u32, 681u32, 0u32,
+    697u32, 0u32, 698u32, 0u32, 699u32, 0u32, 700u32, 0u32, 701u32, 0u32, 702u32, 0u32,
+    703u32, 0u32, 704u32, 0u32, 705u32, 0u32, 706u32, 0u32, 707u32, 0u32, 708u32, 0u32,
+    709u32, 0u32, 715u32, 0u32, 716u32, 0u32, 717u32, 0u32, 718u32, 0u32, 719u32, 0u32,
+    720u32, 0u32, 721u32, 0u32, 722u32, 0u32, 723u32, 0u32, 724u32, 0u32, 149u32, 0u32,
+    171u32, 0u32, 188u32, 0u32, 199u32, 0u32, 200u32, 0u32, 201u32, 0u32, 217u32, 0u32,
+    59u32, 0u32, 234u32, 0u32, 219u32, 0u32, 220u32, 0u32, 221u32, 0u32, 222u32, 0u32,
+    224u32, 0u32, 243u32, 0u32, 290u32, 0u32, 291u32, 0u32, 292u32, 0u32, 293u32, 0u32,
+    294u32, 0u32, 295u32, 0u32, 296u32, 0u32, 297u32, 0u32, 299u32, 0u32, 242u32, 0u32,
+    319u32, 0u32, 320u32, 0u32, 322u32, 0u32, 355u32, 0u32, 374u32, 0u32, 376u32, 0u32,
+    167u32, 0u32, 414u32, 0u32, 421u32, 0u32, 429u32, 0u32, 483u32, 0u32, 487u32, 0u32,
+    489u32, 0u32, 148u32, 0u32, 491u32, 0u32, 492u32, 0u32, 493u32, 0u32, 494u32, 0u32,
+    495u32, 0u32, 496u32, 0u32, 497u32, 0u32, 498u32, 0u32, 499u32, 0u32, 518u32, 0u32,
+    519u32, 0u32, 520u32, 0u32, 528u32, 0u32, 530u32, 0u32, 531u32, 0u32, 532u32, 0u32,
+    533u32, 0u32, 534u32, 0u32, 535u32, 0u32, 539u32, 0u32, 573u32, 0u32, 588u32, 0u32,
+    589u32, 0u32, 591u32, 0u32, 592u32, 0u32, 593u32, 0u32, 594u32, 0u32, 595u32, 0u32,
+    596u32, 0u32, 597u32, 0u32, 602u32, 0u32, 626u32, 0u32, 0u32, 674u32, 0u32, 690u32,
+    0u32, 691u32, 0u32, 693u32, 0u32, 301u32, 0u32, 500u32, 0u32, 628u32, 0u32, 175u32,
+    0u32, 177u32, 0u32, 191u32, 0u32, 237u32, 0u32, 248u32, 0u32, 304u32, 0u32, 305u32,
+    0u32, 308u32, 0u32, 361u32, 0u32, 366u32, 0u32, 479u32, 0u32, 490u32, 0u32, 529u32,
+    0u32, 568u32, 0u32, 590u32, 0u32, 603u32, 0u32, 604u32, 0u32, 605u32, 0u32, 606u32,
+    0u32, 607u32, 0u32, 627u32, 0u32, 688u32, 0u32, 689u32, 0u32, 692u32, 0u32, 695u32,
+    0u32, 696u32, 0u32,
+];
+static LLVM_BLOB: &[u8] = include_bytes!("generated_llvm.blob");
+static LLVM_OFFSETS: &[(&str, &str, usize)] = &[
+    ("1.81.0", "18.1.7", 0usize),
+    ("1.85.0", "19.1.7", 1009usize),
+    ("1.86.0", "19.1.7", 2353usize),
+    ("1.87.0", "20.1.1", 3712usize),
+    ("", "", 5181usize),
+];
+pub(crate) fn llvm() -> impl Iterator<
+    Item = (
+        &'static str,
+        &'static str,
+        impl Iterator<
+            Item = (
+                &'static str,
+                Option<&'static str>,
+                impl Iterator<Item = &'static str>,
+            ),
+        >,
+    ),
+> {
+    LLVM_OFFSETS
+        .windows(2)
+        .map(|window| {
+            let &[(version, llvm_version, start), (_, _, end)] = window else {
+                unreachable!()
+            };
+            let mut values = read_values(&LLVM_BLOB[start..end]);
+            let entries = std::iter::from_fn(move || {
+                let name = values.next()?;
+                let arch = values.next().unwrap();
+                let list = values.next().unwrap();
+                Some((
+                    NAMES[name],
+                    arch.checked_sub(1).map(|idx| NAMES[idx]),
+                    get_name_list(LLVM_FEATURES_NAMES, list),
+                ))
+            });
+            (version, llvm_version, entries)
+        })
+}
+static FEATURE_INDEX_BLOB: &[u8] = include_bytes!("generated_feature_index.blob");
+static FEATURE_INDEX_OFFSETS: &[(&str, usize)] = &[
+    ("1.81.0", 0usize),
+    ("1.85.0", 1129usize),
+    ("1.86.0", 2588usize),
+    ("1.87.0", 4062usize),
+    ("", 5688usize),
+];
+static TARGET_LISTS_BLOB: &[u8] = include_bytes!("generated_target_lists.blob");
+static TARGET_LISTS_OFFSETS: &[usize] = &[
+    0usize, 5usize, 8usize, 55usize, 92usize, 93usize, 160usize, 189usize, 310usize,
+    538usize, 552usize, 587usize, 606usize, 611usize, 626usize, 629usize, 632usize,
+    674usize, 679usize, 718usize, 745usize, 853usize, 865usize, 920usize, 949usize,
+    969usize, 972usize, 982usize, 1022usize, 1055usize, 1094usize, 1113usize, 1122usize,
+    1229usize, 1234usize, 1241usize, 1286usize, 1301usize, 1355usize, 1358usize,
+    1363usize, 1370usize, 1376usize, 1381usize, 1484usize, 1515usize, 1534usize,
+    1555usize, 1586usize, 1631usize, 1634usize, 1637usize, 1700usize, 1756usize,
+    1802usize, 1805usize, 1841usize, 1844usize, 1846usize, 1855usize, 1922usize,
+    1971usize, 2054usize, 2085usize, 2208usize, 2456usize, 2471usize, 2508usize,
+    2523usize, 2528usize, 2572usize, 2627usize, 2753usize, 2766usize, 2837usize,
+    2874usize, 2896usize, 2907usize, 2953usize, 2960usize, 3037usize, 3080usize,
+    3098usize, 3106usize, 3117usize, 3224usize, 3227usize, 3240usize, 3247usize,
+    3310usize, 3339usize, 3352usize, 3409usize, 3411usize, 3577usize, 3682usize,
+    3727usize, 3784usize, 3863usize, 3935usize, 3993usize, 4004usize, 4042usize,
+    4049usize, 4138usize, 4175usize, 4308usize, 4575usize, 4622usize, 4757usize,
+    4785usize, 4837usize, 4880usize, 4933usize, 5054usize, 5059usize, 5122usize,
+    5125usize, 5304usize, 5525usize, 5552usize, 5669usize, 5718usize, 5781usize,
+    5866usize, 5944usize, 6008usize, 6052usize, 6183usize, 6448usize, 6464usize,
+    6481usize, 6486usize, 6501usize, 6530usize, 6544usize, 6665usize, 6674usize,
+    6681usize, 6688usize, 6865usize, 7084usize, 7201usize, 7234usize, 7255usize,
+    7278usize,
+];
+pub(crate) fn feature_index() -> impl Iterator<
+    Item = (&'static str, impl Iterator<Item = (&'static str, [usize; 3])>),
+> {
+    FEATURE_INDEX_OFFSETS
+        .windows(2)
+        .map(|window| {
+            let &[(version, start), (_, end)] = window else { unreachable!() };
+            let mut values = read_values(&FEATURE_INDEX_BLOB[start..end]);
+            let features = std::iter::from_fn(move || {
+                let name = NAMES[values.next()?];
+                let lists = [(); 3].map(|_| values.next().unwrap());
+                Some((name, lists))
+            });
+            (version, features)
+        })
+}
+pub(crate) fn get_target_list(n: usize) -> impl Iterator<Item = &'static str> {
+    let offset = TARGET_LISTS_OFFSETS[n];
+    let mut values = read_values(&TARGET_LISTS_BLOB[offset..]);
+    std::iter::from_fn(move || {
+            values.next().unwrap().checked_sub(1).map(|id| NAMES[id])
+        })
+        .fuse()
+}
+static DETECT_NAMES: &[u32] = &[
+    9u32, 77u32, 0u32, 117u32, 365u32, 0u32, 416u32, 581u32, 582u32, 583u32, 584u32,
+    585u32, 586u32, 587u32, 0u32, 67u32, 0u32, 0u32, 579u32, 608u32, 609u32, 610u32,
+    0u32, 330u32, 0u32, 335u32, 0u32, 390u32, 0u32, 400u32, 0u32, 430u32, 451u32, 0u32,
+    629u32, 630u32, 0u32, 54u32, 324u32, 132u32, 236u32, 135u32, 598u32, 141u32, 614u32,
+    0u32, 416u32, 581u32, 582u32, 583u32, 584u32, 585u32, 586u32, 587u32, 588u32, 589u32,
+    592u32, 593u32, 594u32, 595u32, 596u32, 597u32, 0u32, 310u32, 0u32, 473u32, 0u32,
+];
+/// Name, gate, architectures list, (feature, gate) pairs, aliases list, no detection list
+type DetectMacroEntry = (u32, u32, u32, &'static [(u32, u32)], u32, u32);
+static DETECT_MACROS: &[DetectMacroEntry] = &[
+    (
+        279u32,
+        0u32,
+        0u32,
+        &[
+            (364u32, 0u32),
+            (379u32, 0u32),
+            (213u32, 0u32),
+            (215u32, 0u32),
+            (527u32, 0u32),
+            (164u32, 0u32),
+            (317u32, 0u32),
+            (319u32, 0u32),
+            (421u32, 0u32),
+            (417u32, 0u32),
+            (419u32, 0u32),
+            (178u32, 0u32),
+            (566u32, 0u32),
+            (204u32, 0u32),
+            (175u32, 0u32),
+            (205u32, 0u32),
+            (508u32, 0u32),
+            (477u32, 0u32),
+            (370u32, 0u32),
+            (371u32, 0u32),
+            (179u32, 0u32),
+            (180u32, 0u32),
+            (529u32, 0u32),
+            (530u32, 0u32),
+            (533u32, 0u32),
+            (532u32, 0u32),
+            (531u32, 0u32),
+            (232u32, 0u32),
+            (278u32, 0u32),
+            (198u32, 0u32),
+            (199u32, 0u32),
+            (149u32, 0u32),
+            (414u32, 0u32),
+            (156u32, 0u32),
+            (361u32, 0u32),
+            (298u32, 0u32),
+            (202u32, 0u32),
+            (56u32, 0u32),
+            (480u32, 0u32),
+            (481u32, 0u32),
+            (487u32, 0u32),
+        ],
+        1u32,
+        2u32,
+    ),
+    (
+        280u32,
+        522u32,
+        3u32,
+        &[
+            (364u32, 522u32),
+            (379u32, 522u32),
+            (164u32, 522u32),
+            (56u32, 522u32),
+            (480u32, 522u32),
+            (278u32, 522u32),
+            (178u32, 522u32),
+        ],
+        4u32,
+        5u32,
+    ),
+    (283u32, 524u32, 6u32, &[(358u32, 524u32)], 4u32, 4u32),
+    (282u32, 524u32, 7u32, &[(358u32, 524u32)], 4u32, 4u32),
+    (
+        285u32,
+        525u32,
+        8u32,
+        &[(57u32, 525u32), (614u32, 525u32), (383u32, 525u32)],
+        4u32,
+        4u32,
+    ),
+    (
+        284u32,
+        525u32,
+        9u32,
+        &[(57u32, 525u32), (614u32, 525u32), (383u32, 525u32)],
+        4u32,
+        4u32,
+    ),
+    (
+        286u32,
+        287u32,
+        10u32,
+        &[
+            (469u32, 526u32),
+            (711u32, 526u32),
+            (712u32, 526u32),
+            (470u32, 526u32),
+            (324u32, 0u32),
+            (7u32, 0u32),
+            (710u32, 526u32),
+            (709u32, 526u32),
+            (713u32, 526u32),
+            (196u32, 526u32),
+            (172u32, 526u32),
+            (412u32, 526u32),
+            (158u32, 0u32),
+            (706u32, 526u32),
+            (703u32, 526u32),
+            (707u32, 526u32),
+            (708u32, 526u32),
+            (724u32, 526u32),
+            (468u32, 526u32),
+            (467u32, 526u32),
+            (704u32, 526u32),
+            (705u32, 526u32),
+            (297u32, 526u32),
+            (369u32, 526u32),
+            (573u32, 526u32),
+            (693u32, 526u32),
+            (471u32, 526u32),
+            (536u32, 526u32),
+            (537u32, 526u32),
+            (535u32, 526u32),
+            (237u32, 526u32),
+            (696u32, 0u32),
+            (697u32, 0u32),
+            (698u32, 0u32),
+            (702u32, 0u32),
+            (699u32, 0u32),
+            (700u32, 0u32),
+            (701u32, 0u32),
+            (716u32, 0u32),
+            (717u32, 0u32),
+            (718u32, 0u32),
+            (721u32, 0u32),
+            (722u32, 0u32),
+            (719u32, 0u32),
+            (715u32, 0u32),
+            (720u32, 0u32),
+            (714u32, 0u32),
+            (723u32, 0u32),
+        ],
+        4u32,
+        4u32,
+    ),
+    (
+        288u32,
+        0u32,
+        11u32,
+        &[
+            (56u32, 0u32),
+            (377u32, 0u32),
+            (422u32, 0u32),
+            (424u32, 0u32),
+            (570u32, 0u32),
+            (353u32, 0u32),
+            (509u32, 0u32),
+            (510u32, 0u32),
+            (511u32, 0u32),
+            (516u32, 0u32),
+            (512u32, 0u32),
+            (513u32, 0u32),
+            (514u32, 0u32),
+            (479u32, 0u32),
+            (120u32, 0u32),
+            (121u32, 0u32),
+            (129u32, 0u32),
+            (126u32, 0u32),
+            (128u32, 0u32),
+            (133u32, 0u32),
+            (125u32, 0u32),
+            (127u32, 0u32),
+            (137u32, 0u32),
+            (132u32, 0u32),
+            (135u32, 0u32),
+            (141u32, 0u32),
+            (136u32, 0u32),
+            (235u32, 0u32),
+            (597u32, 0u32),
+            (613u32, 0u32),
+            (138u32, 0u32),
+            (124u32, 0u32),
+            (123u32, 0u32),
+            (139u32, 0u32),
+            (130u32, 0u32),
+            (197u32, 0u32),
+            (212u32, 0u32),
+            (151u32, 0u32),
+            (152u32, 0u32),
+            (323u32, 0u32),
+            (539u32, 0u32),
+            (381u32, 0u32),
+            (234u32, 0u32),
+            (677u32, 0u32),
+            (679u32, 0u32),
+            (680u32, 0u32),
+            (678u32, 0u32),
+            (162u32, 0u32),
+            (55u32, 0u32),
+            (465u32, 0u32),
+            (355u32, 0u32),
+            (191u32, 0u32),
+        ],
+        12u32,
+        4u32,
+    ),
+    (
+        279u32,
+        0u32,
+        0u32,
+        &[
+            (364u32, 0u32),
+            (379u32, 0u32),
+            (213u32, 0u32),
+            (56u32, 0u32),
+            (149u32, 0u32),
+            (156u32, 0u32),
+            (164u32, 0u32),
+            (170u32, 521u32),
+            (175u32, 0u32),
+            (179u32, 0u32),
+            (180u32, 0u32),
+            (178u32, 0u32),
+            (187u32, 521u32),
+            (198u32, 0u32),
+            (199u32, 0u32),
+            (200u32, 521u32),
+            (202u32, 0u32),
+            (204u32, 0u32),
+            (205u32, 0u32),
+            (206u32, 521u32),
+            (215u32, 0u32),
+            (218u32, 521u32),
+            (219u32, 521u32),
+            (220u32, 521u32),
+            (221u32, 521u32),
+            (222u32, 521u32),
+            (232u32, 0u32),
+            (242u32, 521u32),
+            (278u32, 0u32),
+            (298u32, 0u32),
+            (317u32, 0u32),
+            (318u32, 521u32),
+            (319u32, 0u32),
+            (321u32, 521u32),
+            (354u32, 521u32),
+            (361u32, 0u32),
+            (370u32, 0u32),
+            (371u32, 0u32),
+            (375u32, 521u32),
+            (414u32, 0u32),
+            (417u32, 0u32),
+            (419u32, 0u32),
+            (420u32, 521u32),
+            (421u32, 0u32),
+            (477u32, 0u32),
+            (480u32, 0u32),
+            (481u32, 0u32),
+            (487u32, 0u32),
+            (488u32, 521u32),
+            (497u32, 521u32),
+            (498u32, 521u32),
+            (489u32, 521u32),
+            (490u32, 521u32),
+            (491u32, 521u32),
+            (492u32, 521u32),
+            (493u32, 521u32),
+            (494u32, 521u32),
+            (495u32, 521u32),
+            (496u32, 521u32),
+            (508u32, 0u32),
+            (517u32, 521u32),
+            (518u32, 521u32),
+            (519u32, 521u32),
+            (527u32, 0u32),
+            (529u32, 0u32),
+            (534u32, 521u32),
+            (530u32, 0u32),
+            (528u32, 521u32),
+            (531u32, 0u32),
+            (532u32, 0u32),
+            (533u32, 0u32),
+            (566u32, 0u32),
+            (625u32, 521u32),
+        ],
+        1u32,
+        13u32,
+    ),
+    (
+        281u32,
+        282u32,
+        14u32,
+        &[
+            (196u32, 523u32),
+            (172u32, 523u32),
+            (231u32, 523u32),
+            (320u32, 523u32),
+            (305u32, 523u32),
+            (306u32, 523u32),
+            (322u32, 523u32),
+            (571u32, 523u32),
+        ],
+        4u32,
+        4u32,
+    ),
+    (
+        288u32,
+        0u32,
+        11u32,
+        &[
+            (56u32, 0u32),
+            (377u32, 0u32),
+            (422u32, 0u32),
+            (424u32, 0u32),
+            (570u32, 0u32),
+            (353u32, 0u32),
+            (509u32, 0u32),
+            (510u32, 0u32),
+            (511u32, 0u32),
+            (516u32, 0u32),
+            (512u32, 0u32),
+            (513u32, 0u32),
+            (514u32, 0u32),
+            (479u32, 0u32),
+            (120u32, 0u32),
+            (121u32, 0u32),
+            (482u32, 484u32),
+            (486u32, 484u32),
+            (487u32, 484u32),
+            (129u32, 0u32),
+            (126u32, 0u32),
+            (128u32, 0u32),
+            (133u32, 0u32),
+            (125u32, 0u32),
+            (127u32, 0u32),
+            (137u32, 0u32),
+            (132u32, 0u32),
+            (135u32, 0u32),
+            (141u32, 0u32),
+            (136u32, 0u32),
+            (235u32, 0u32),
+            (597u32, 0u32),
+            (613u32, 0u32),
+            (138u32, 0u32),
+            (124u32, 0u32),
+            (123u32, 0u32),
+            (139u32, 0u32),
+            (130u32, 0u32),
+            (142u32, 123u32),
+            (143u32, 123u32),
+            (144u32, 123u32),
+            (145u32, 123u32),
+            (146u32, 123u32),
+            (65u32, 673u32),
+            (64u32, 673u32),
+            (61u32, 673u32),
+            (63u32, 673u32),
+            (62u32, 673u32),
+            (197u32, 0u32),
+            (212u32, 0u32),
+            (151u32, 0u32),
+            (152u32, 0u32),
+            (323u32, 0u32),
+            (539u32, 0u32),
+            (381u32, 0u32),
+            (234u32, 0u32),
+            (677u32, 0u32),
+            (679u32, 0u32),
+            (680u32, 0u32),
+            (678u32, 0u32),
+            (162u32, 0u32),
+            (55u32, 0u32),
+            (465u32, 0u32),
+            (355u32, 0u32),
+            (191u32, 0u32),
+            (675u32, 677u32),
+        ],
+        12u32,
+        4u32,
+    ),
+    (
+        287u32,
+        527u32,
+        15u32,
+        &[
+            (174u32, 527u32),
+            (190u32, 527u32),
+            (236u32, 527u32),
+            (247u32, 527u32),
+            (365u32, 527u32),
+            (567u32, 527u32),
+            (601u32, 527u32),
+            (602u32, 527u32),
+            (603u32, 527u32),
+            (604u32, 527u32),
+            (605u32, 527u32),
+            (606u32, 527u32),
+        ],
+        4u32,
+        4u32,
+    ),
+    (
+        288u32,
+        0u32,
+        11u32,
+        &[
+            (56u32, 0u32),
+            (377u32, 0u32),
+            (422u32, 0u32),
+            (424u32, 0u32),
+            (570u32, 0u32),
+            (353u32, 0u32),
+            (509u32, 0u32),
+            (510u32, 0u32),
+            (511u32, 0u32),
+            (516u32, 0u32),
+            (512u32, 0u32),
+            (513u32, 0u32),
+            (514u32, 0u32),
+            (479u32, 0u32),
+            (120u32, 0u32),
+            (121u32, 0u32),
+            (482u32, 484u32),
+            (486u32, 484u32),
+            (487u32, 484u32),
+            (129u32, 0u32),
+            (126u32, 0u32),
+            (128u32, 0u32),
+            (133u32, 0u32),
+            (125u32, 0u32),
+            (127u32, 0u32),
+            (137u32, 0u32),
+            (132u32, 0u32),
+            (135u32, 0u32),
+            (141u32, 0u32),
+            (136u32, 0u32),
+            (235u32, 0u32),
+            (597u32, 0u32),
+            (613u32, 0u32),
+            (138u32, 0u32),
+            (124u32, 0u32),
+            (123u32, 0u32),
+            (139u32, 0u32),
+            (130u32, 0u32),
+            (142u32, 123u32),
+            (143u32, 123u32),
+            (144u32, 123u32),
+            (145u32, 123u32),
+            (146u32, 123u32),
+            (65u32, 673u32),
+            (64u32, 673u32),
+            (61u32, 673u32),
+            (63u32, 673u32),
+            (62u32, 673u32),
+            (197u32, 0u32),
+            (212u32, 0u32),
+            (151u32, 0u32),
+            (152u32, 0u32),
+            (323u32, 0u32),
+            (539u32, 0u32),
+            (381u32, 0u32),
+            (234u32, 0u32),
+            (677u32, 0u32),
+            (679u32, 0u32),
+            (680u32, 0u32),
+            (678u32, 0u32),
+            (162u32, 0u32),
+            (300u32, 300u32),
+            (627u32, 300u32),
+            (55u32, 0u32),
+            (465u32, 0u32),
+            (355u32, 0u32),
+            (191u32, 0u32),
+            (675u32, 677u32),
+        ],
+        12u32,
+        4u32,
+    ),
+];
+static DETECT_MAPS: &[(&str, &[u32])] = &[
+    ("1.81.0", &[0u32, 1u32, 2u32, 3u32, 4u32, 5u32, 6u32, 7u32]),
+    ("1.85.0", &[8u32, 1u32, 9u32, 2u32, 3u32, 4u32, 5u32, 6u32, 10u32]),
+    ("1.86.0", &[8u32, 1u32, 9u32, 2u32, 3u32, 4u32, 5u32, 6u32, 10u32]),
+    ("1.87.0", &[8u32, 1u32, 9u32, 2u32, 3u32, 4u32, 5u32, 6u32, 11u32, 12u32]),
+];
+pub(crate) fn detect() -> impl Iterator<
+    Item = (&'static str, impl Iterator<Item = detect::DetectMacro>),
+> {
+    DETECT_MAPS
+        .iter()
+        .map(|(version, ids)| {
+            let macros = ids
+                .iter()
+                .map(|id| {
+                    let (
+                        name,
+                        macro_gate,
+                        target_arches,
+                        features,
+                        aliases,
+                        no_runtime_detection,
+                    ) = DETECT_MACROS[*id as usize];
+                    let gate = |gate: u32| {
+                        gate.checked_sub(1).map(|idx| NAMES[idx as usize])
+                    };
+                    let aliases: Vec<&'static str> = get_name_list(
+                            DETECT_NAMES,
+                            aliases as usize,
+                        )
+                        .collect();
+                    detect::DetectMacro {
+                        name: NAMES[name as usize],
+                        unstable_feature_gate: gate(macro_gate),
+                        target_arches: get_name_list(
+                                DETECT_NAMES,
+                                target_arches as usize,
+                            )
+                            .collect(),
+                        features: features
+                            .iter()
+                            .map(|&(name, feature_gate)| detect::DetectableFeature {
+                                name: NAMES[name as usize],
+                                unstable_feature_gate: gate(feature_gate),
+                            })
+                            .collect(),
+                        aliases: aliases
+                            .chunks(2)
+                            .map(|pair| (pair[0], pair[1]))
+                            .collect(),
+                        no_runtime_detection: get_name_list(
+                                DETECT_NAMES,
+                                no_runtime_detection as usize,
+                            )
+                            .collect(),
+                    }
+                });
+            (*version, macros)
+        })
+}
@@ -0,0 +1,3964 @@
+// WARNING: This file was generated automatically by rust-target-feature-data-gen.
+
+use super::*;
+static NAMES: [&str; 725usize] = [
+    "10e60",
+    "2e3",
+    "3e3r1",
+    "3e3r2",
+    "3e3r3",
+    "3e7",
+    "7e10",
+    "a",
+    "aarch64",
+    "aarch64-apple-darwin",
+    "aarch64-apple-ios",
+    "aarch64-apple-ios-macabi",
+    "aarch64-apple-ios-sim",
+    "aarch64-apple-tvos",
+    "aarch64-apple-tvos-sim",
+    "aarch64-apple-visionos",
+    "aarch64-apple-visionos-sim",
+    "aarch64-apple-watchos",
+    "aarch64-apple-watchos-sim",
+    "aarch64-fuchsia",
+    "aarch64-kmc-solid_asp3",
+    "aarch64-linux-android",
+    "aarch64-nintendo-switch-freestanding",
+    "aarch64-pc-windows-gnullvm",
+    "aarch64-pc-windows-msvc",
+    "aarch64-unknown-freebsd",
+    "aarch64-unknown-fuchsia",
+    "aarch64-unknown-hermit",
+    "aarch64-unknown-illumos",
+    "aarch64-unknown-linux-gnu",
+    "aarch64-unknown-linux-gnu_ilp32",
+    "aarch64-unknown-linux-musl",
+    "aarch64-unknown-linux-ohos",
+    "aarch64-unknown-netbsd",
+    "aarch64-unknown-none",
+    "aarch64-unknown-none-softfloat",
+    "aarch64-unknown-nto-qnx700",
+    "aarch64-unknown-nto-qnx710",
+    "aarch64-unknown-nto-qnx710_iosock",
+    "aarch64-unknown-nto-qnx800",
+    "aarch64-unknown-nuttx",
+    "aarch64-unknown-openbsd",
+    "aarch64-unknown-redox",
+    "aarch64-unknown-teeos",
+    "aarch64-unknown-trusty",
+    "aarch64-unknown-uefi",
+    "aarch64-uwp-windows-msvc",
+    "aarch64-wrs-vxworks",
+    "aarch64_be-unknown-linux-gnu",
+    "aarch64_be-unknown-linux-gnu_ilp32",
+    "aarch64_be-unknown-netbsd",
+    "aarch64_unstable_target_feature",
+    "aarch64_ver_target_feature",
+    "abm",
+    "aclass",
+    "adx",
+    "aes",
+    "altivec",
+    "altnzcv",
+    "alu32",
+    "amdgcn-amd-amdhsa",
+    "amx-bf16",
+    "amx-complex",
+    "amx-fp16",
+    "amx-int8",
+    "amx-tile",
+    "arm",
+    "arm-linux-androideabi",
+    "arm-unknown-linux-gnueabi",
+    "arm-unknown-linux-gnueabihf",
+    "arm-unknown-linux-musleabi",
+    "arm-unknown-linux-musleabihf",
+    "arm64_32-apple-watchos",
+    "arm64e-apple-darwin",
+    "arm64e-apple-ios",
+    "arm64e-apple-tvos",
+    "arm64ec",
+    "arm64ec-pc-windows-msvc",
+    "arm_target_feature",
+    "armeb-unknown-linux-gnueabi",
+    "armebv7r-none-eabi",
+    "armebv7r-none-eabihf",
+    "armv4t-none-eabi",
+    "armv4t-unknown-linux-gnueabi",
+    "armv5te-none-eabi",
+    "armv5te-unknown-linux-gnueabi",
+    "armv5te-unknown-linux-musleabi",
+    "armv5te-unknown-linux-uclibceabi",
+    "armv6-unknown-freebsd",
+    "armv6-unknown-netbsd-eabihf",
+    "armv6k-nintendo-3ds",
+    "armv7-linux-androideabi",
+    "armv7-rtems-eabihf",
+    "armv7-sony-vita-newlibeabihf",
+    "armv7-unknown-freebsd",
+    "armv7-unknown-linux-gnueabi",
+    "armv7-unknown-linux-gnueabihf",
+    "armv7-unknown-linux-musleabi",
+    "armv7-unknown-linux-musleabihf",
+    "armv7-unknown-linux-ohos",
+    "armv7-unknown-linux-uclibceabi",
+    "armv7-unknown-linux-uclibceabihf",
+    "armv7-unknown-netbsd-eabihf",
+    "armv7-unknown-trusty",
+    "armv7-wrs-vxworks-eabihf",
+    "armv7a-kmc-solid_asp3-eabi",
+    "armv7a-kmc-solid_asp3-eabihf",
+    "armv7a-none-eabi",
+    "armv7a-none-eabihf",
+    "armv7a-nuttx-eabi",
+    "armv7a-nuttx-eabihf",
+    "armv7k-apple-watchos",
+    "armv7r-none-eabi",
+    "armv7r-none-eabihf",
+    "armv7s-apple-ios",
+    "armv8r-none-eabihf",
+    "asimd",
+    "atomics",
+    "avr-none",
+    "avr-unknown-gnu-atmega328",
+    "avx",
+    "avx2",
+    "avx512_target_feature",
+    "avx512bf16",
+    "avx512bitalg",
+    "avx512bw",
+    "avx512cd",
+    "avx512dq",
+    "avx512er",
+    "avx512f",
+    "avx512fp16",
+    "avx512gfni",
+    "avx512ifma",
+    "avx512pf",
+    "avx512vaes",
+    "avx512vbmi",
+    "avx512vbmi2",
+    "avx512vl",
+    "avx512vnni",
+    "avx512vp2intersect",
+    "avx512vpclmulqdq",
+    "avx512vpopcntdq",
+    "avxifma",
+    "avxneconvert",
+    "avxvnni",
+    "avxvnniint16",
+    "avxvnniint8",
+    "b16b16",
+    "backchain",
+    "bf16",
+    "bmi",
+    "bmi1",
+    "bmi2",
+    "bpf_target_feature",
+    "bpfeb-unknown-none",
+    "bpfel-unknown-none",
+    "bti",
+    "bulk-memory",
+    "c",
+    "cache",
+    "ccdp",
+    "ccpp",
+    "cmpxchg16b",
+    "complxnum",
+    "crc",
+    "crc32",
+    "crypto",
+    "csky-unknown-linux-gnuabiv2",
+    "csky-unknown-linux-gnuabiv2hf",
+    "csky_target_feature",
+    "cssc",
+    "cx16",
+    "d",
+    "d32",
+    "deflate-conversion",
+    "dit",
+    "div32",
+    "doloop",
+    "dotprod",
+    "dpb",
+    "dpb2",
+    "dsp",
+    "dsp1e2",
+    "dspe60",
+    "e",
+    "e1",
+    "e2",
+    "ecv",
+    "edsp",
+    "elrw",
+    "enhanced-sort",
+    "ermsb",
+    "ermsb_target_feature",
+    "evex512",
+    "exception-handling",
+    "extended-const",
+    "f",
+    "f16c",
+    "f32mm",
+    "f64mm",
+    "faminmax",
+    "fast-unaligned-access",
+    "fcma",
+    "fdivdu",
+    "fhm",
+    "flagm",
+    "flagm2",
+    "float1e2",
+    "float1e3",
+    "float3e4",
+    "float7e60",
+    "floate1",
+    "fma",
+    "fp",
+    "fp-armv8",
+    "fp16",
+    "fp16fml",
+    "fp64",
+    "fp8",
+    "fp8dot2",
+    "fp8dot4",
+    "fp8fma",
+    "fpmr",
+    "fpregs",
+    "fptoint",
+    "fpuv2_df",
+    "fpuv2_sf",
+    "fpuv3_df",
+    "fpuv3_hf",
+    "fpuv3_hi",
+    "fpuv3_sf",
+    "frecipe",
+    "frintts",
+    "fullfp16",
+    "fxsr",
+    "gfni",
+    "guarded-storage",
+    "h",
+    "hard-float",
+    "hard-float-abi",
+    "hard-tp",
+    "hasleoncasa",
+    "hbc",
+    "hexagon-unknown-linux-musl",
+    "hexagon-unknown-none-elf",
+    "hexagon_target_feature",
+    "high-registers",
+    "high-word",
+    "hvx",
+    "hvx-length128b",
+    "hwdiv",
+    "i386-apple-ios",
+    "i586-pc-nto-qnx700",
+    "i586-pc-windows-msvc",
+    "i586-unknown-linux-gnu",
+    "i586-unknown-linux-musl",
+    "i586-unknown-netbsd",
+    "i586-unknown-redox",
+    "i686-apple-darwin",
+    "i686-linux-android",
+    "i686-pc-nto-qnx700",
+    "i686-pc-windows-gnu",
+    "i686-pc-windows-gnullvm",
+    "i686-pc-windows-msvc",
+    "i686-unknown-freebsd",
+    "i686-unknown-haiku",
+    "i686-unknown-hurd-gnu",
+    "i686-unknown-linux-gnu",
+    "i686-unknown-linux-musl",
+    "i686-unknown-netbsd",
+    "i686-unknown-openbsd",
+    "i686-unknown-redox",
+    "i686-unknown-uefi",
+    "i686-uwp-windows-gnu",
+    "i686-uwp-windows-msvc",
+    "i686-win7-windows-gnu",
+    "i686-win7-windows-msvc",
+    "i686-wrs-vxworks",
+    "i8mm",
+    "is_aarch64_feature_detected",
+    "is_arm_feature_detected",
+    "is_loongarch_feature_detected",
+    "is_mips64_feature_detected",
+    "is_mips_feature_detected",
+    "is_powerpc64_feature_detected",
+    "is_powerpc_feature_detected",
+    "is_riscv_feature_detected",
+    "is_s390x_feature_detected",
+    "is_x86_feature_detected",
+    "isa-68000",
+    "isa-68010",
+    "isa-68020",
+    "isa-68030",
+    "isa-68040",
+    "isa-68060",
+    "isa-68881",
+    "isa-68882",
+    "j",
+    "jsconv",
+    "keylocker_x86",
+    "kl",
+    "lahfsahf",
+    "lahfsahf_target_feature",
+    "lam-bh",
+    "lamcas",
+    "lasx",
+    "lbt",
+    "ld-seq-sa",
+    "leoncasa",
+    "loongarch64",
+    "loongarch64-unknown-linux-gnu",
+    "loongarch64-unknown-linux-musl",
+    "loongarch64-unknown-linux-ohos",
+    "loongarch64-unknown-none",
+    "loongarch64-unknown-none-softfloat",
+    "loongarch_target_feature",
+    "lor",
+    "lse",
+    "lse128",
+    "lse2",
+    "lsx",
+    "lut",
+    "lvz",
+    "lzcnt",
+    "m",
+    "m68k-unknown-linux-gnu",
+    "m68k-unknown-none-elf",
+    "m68k_target_feature",
+    "mclass",
+    "mips",
+    "mips-mti-none-elf",
+    "mips-unknown-linux-gnu",
+    "mips-unknown-linux-musl",
+    "mips-unknown-linux-uclibc",
+    "mips64",
+    "mips64-openwrt-linux-musl",
+    "mips64-unknown-linux-gnuabi64",
+    "mips64-unknown-linux-muslabi64",
+    "mips64el-unknown-linux-gnuabi64",
+    "mips64el-unknown-linux-muslabi64",
+    "mips_target_feature",
+    "mipsel-mti-none-elf",
+    "mipsel-sony-psp",
+    "mipsel-sony-psx",
+    "mipsel-unknown-linux-gnu",
+    "mipsel-unknown-linux-musl",
+    "mipsel-unknown-linux-uclibc",
+    "mipsel-unknown-netbsd",
+    "mipsel-unknown-none",
+    "mipsisa32r6-unknown-linux-gnu",
+    "mipsisa32r6el-unknown-linux-gnu",
+    "mipsisa64r6-unknown-linux-gnuabi64",
+    "mipsisa64r6el-unknown-linux-gnuabi64",
+    "mmx",
+    "mops",
+    "movbe",
+    "mp",
+    "mp1e2",
+    "msa",
+    "msp430-none-elf",
+    "msync",
+    "mte",
+    "multivalue",
+    "mutable-globals",
+    "neon",
+    "nnp-assist",
+    "nontrapping-fptoint",
+    "nvic",
+    "nvptx64-nvidia-cuda",
+    "p",
+    "paca",
+    "pacg",
+    "pan",
+    "partword-atomics",
+    "pauth",
+    "pauth-lr",
+    "pclmul",
+    "pclmulqdq",
+    "perfmon",
+    "pmull",
+    "pmuv3",
+    "popcnt",
+    "power10-vector",
+    "power8",
+    "power8-altivec",
+    "power8-crypto",
+    "power8-vector",
+    "power9-altivec",
+    "power9-vector",
+    "powerpc",
+    "powerpc-unknown-freebsd",
+    "powerpc-unknown-linux-gnu",
+    "powerpc-unknown-linux-gnuspe",
+    "powerpc-unknown-linux-musl",
+    "powerpc-unknown-linux-muslspe",
+    "powerpc-unknown-netbsd",
+    "powerpc-unknown-openbsd",
+    "powerpc-wrs-vxworks",
+    "powerpc-wrs-vxworks-spe",
+    "powerpc64",
+    "powerpc64-ibm-aix",
+    "powerpc64-unknown-freebsd",
+    "powerpc64-unknown-linux-gnu",
+    "powerpc64-unknown-linux-musl",
+    "powerpc64-unknown-openbsd",
+    "powerpc64-wrs-vxworks",
+    "powerpc64le-unknown-freebsd",
+    "powerpc64le-unknown-linux-gnu",
+    "powerpc64le-unknown-linux-musl",
+    "powerpc_target_feature",
+    "prfchw",
+    "prfchw_target_feature",
+    "q",
+    "quadword-atomics",
+    "rand",
+    "ras",
+    "rclass",
+    "rcpc",
+    "rcpc-immo",
+    "rcpc2",
+    "rcpc3",
+    "rdm",
+    "rdrand",
+    "rdrnd",
+    "rdseed",
+    "reference-types",
+    "relax",
+    "relaxed-simd",
+    "reserve-x18",
+    "riscv32",
+    "riscv32-wrs-vxworks",
+    "riscv32e-unknown-none-elf",
+    "riscv32em-unknown-none-elf",
+    "riscv32emc-unknown-none-elf",
+    "riscv32gc-unknown-linux-gnu",
+    "riscv32gc-unknown-linux-musl",
+    "riscv32i-unknown-none-elf",
+    "riscv32im-risc0-zkvm-elf",
+    "riscv32im-unknown-none-elf",
+    "riscv32ima-unknown-none-elf",
+    "riscv32imac-esp-espidf",
+    "riscv32imac-unknown-none-elf",
+    "riscv32imac-unknown-nuttx-elf",
+    "riscv32imac-unknown-xous-elf",
+    "riscv32imafc-esp-espidf",
+    "riscv32imafc-unknown-none-elf",
+    "riscv32imafc-unknown-nuttx-elf",
+    "riscv32imc-esp-espidf",
+    "riscv32imc-unknown-none-elf",
+    "riscv32imc-unknown-nuttx-elf",
+    "riscv64",
+    "riscv64-linux-android",
+    "riscv64-wrs-vxworks",
+    "riscv64gc-unknown-freebsd",
+    "riscv64gc-unknown-fuchsia",
+    "riscv64gc-unknown-hermit",
+    "riscv64gc-unknown-linux-gnu",
+    "riscv64gc-unknown-linux-musl",
+    "riscv64gc-unknown-netbsd",
+    "riscv64gc-unknown-none-elf",
+    "riscv64gc-unknown-nuttx-elf",
+    "riscv64gc-unknown-openbsd",
+    "riscv64imac-unknown-none-elf",
+    "riscv64imac-unknown-nuttx-elf",
+    "riscv_target_feature",
+    "rtm",
+    "rtm_target_feature",
+    "rv128i",
+    "rv32e",
+    "rv32i",
+    "rv64i",
+    "s",
+    "s390x",
+    "s390x-unknown-linux-gnu",
+    "s390x-unknown-linux-musl",
+    "s390x_target_feature",
+    "sahf",
+    "sb",
+    "scq",
+    "sha",
+    "sha2",
+    "sha3",
+    "sha512",
+    "sha512_sm_x86",
+    "sign-ext",
+    "simd128",
+    "sm3",
+    "sm4",
+    "sme",
+    "sme-b16b16",
+    "sme-f16f16",
+    "sme-f64f64",
+    "sme-f8f16",
+    "sme-f8f32",
+    "sme-fa64",
+    "sme-i16i64",
+    "sme-lutv2",
+    "sme2",
+    "sme2p1",
+    "soft-float",
+    "sparc-unknown-linux-gnu",
+    "sparc-unknown-none-elf",
+    "sparc64-unknown-linux-gnu",
+    "sparc64-unknown-netbsd",
+    "sparc64-unknown-openbsd",
+    "sparc_target_feature",
+    "sparcv9-sun-solaris",
+    "spe",
+    "ssbs",
+    "sse",
+    "sse2",
+    "sse3",
+    "sse4.1",
+    "sse4.2",
+    "sse4a",
+    "sse4a_target_feature",
+    "ssse3",
+    "ssve-fp8dot2",
+    "ssve-fp8dot4",
+    "ssve-fp8fma",
+    "stdarch_aarch64_feature_detection",
+    "stdarch_arm_feature_detection",
+    "stdarch_loongarch_feature_detection",
+    "stdarch_mips_feature_detection",
+    "stdarch_powerpc_feature_detection",
+    "stdarch_riscv_feature_detection",
+    "stdarch_s390x_feature_detection",
+    "sve",
+    "sve-b16b16",
+    "sve2",
+    "sve2-aes",
+    "sve2-bitperm",
+    "sve2-sha3",
+    "sve2-sm4",
+    "sve2p1",
+    "svinval",
+    "svnapot",
+    "svpbmt",
+    "tail-call",
+    "tbm",
+    "tbm_target_feature",
+    "thumb-mode",
+    "thumb2",
+    "thumbv4t-none-eabi",
+    "thumbv5te-none-eabi",
+    "thumbv6m-none-eabi",
+    "thumbv6m-nuttx-eabi",
+    "thumbv7a-nuttx-eabi",
+    "thumbv7a-nuttx-eabihf",
+    "thumbv7a-pc-windows-msvc",
+    "thumbv7a-uwp-windows-msvc",
+    "thumbv7em-none-eabi",
+    "thumbv7em-none-eabihf",
+    "thumbv7em-nuttx-eabi",
+    "thumbv7em-nuttx-eabihf",
+    "thumbv7m-none-eabi",
+    "thumbv7m-nuttx-eabi",
+    "thumbv7neon-linux-androideabi",
+    "thumbv7neon-unknown-linux-gnueabihf",
+    "thumbv7neon-unknown-linux-musleabihf",
+    "thumbv8m.base-none-eabi",
+    "thumbv8m.base-nuttx-eabi",
+    "thumbv8m.main-none-eabi",
+    "thumbv8m.main-none-eabihf",
+    "thumbv8m.main-nuttx-eabi",
+    "thumbv8m.main-nuttx-eabihf",
+    "tme",
+    "transactional-execution",
+    "trust",
+    "trustzone",
+    "tsc",
+    "ual",
+    "unaligned-scalar-mem",
+    "v",
+    "v5te",
+    "v6",
+    "v6k",
+    "v6t2",
+    "v7",
+    "v8",
+    "v8.1a",
+    "v8.2a",
+    "v8.3a",
+    "v8.4a",
+    "v8.5a",
+    "v8.6a",
+    "v8.7a",
+    "v8.8a",
+    "v8.9a",
+    "v8plus",
+    "v9",
+    "v9.1a",
+    "v9.2a",
+    "v9.3a",
+    "v9.4a",
+    "v9.5a",
+    "v9a",
+    "vaes",
+    "vdsp2e60f",
+    "vdspv1",
+    "vdspv2",
+    "vector",
+    "vector-enhancements-1",
+    "vector-enhancements-2",
+    "vector-packed-decimal",
+    "vector-packed-decimal-enhancement",
+    "vector-packed-decimal-enhancement-2",
+    "vfp2",
+    "vfp3",
+    "vfp4",
+    "vh",
+    "virt",
+    "virtualization",
+    "vpclmulqdq",
+    "vsx",
+    "wasm32-unknown-emscripten",
+    "wasm32-unknown-unknown",
+    "wasm32-wali-linux-musl",
+    "wasm32-wasi",
+    "wasm32-wasip1",
+    "wasm32-wasip1-threads",
+    "wasm32-wasip2",
+    "wasm32v1-none",
+    "wasm64-unknown-unknown",
+    "wasm_target_feature",
+    "wfxt",
+    "wide-arithmetic",
+    "widekl",
+    "x86",
+    "x86_64",
+    "x86_64-apple-darwin",
+    "x86_64-apple-ios",
+    "x86_64-apple-ios-macabi",
+    "x86_64-apple-tvos",
+    "x86_64-apple-watchos-sim",
+    "x86_64-fortanix-unknown-sgx",
+    "x86_64-fuchsia",
+    "x86_64-linux-android",
+    "x86_64-pc-cygwin",
+    "x86_64-pc-nto-qnx710",
+    "x86_64-pc-nto-qnx710_iosock",
+    "x86_64-pc-nto-qnx800",
+    "x86_64-pc-solaris",
+    "x86_64-pc-windows-gnu",
+    "x86_64-pc-windows-gnullvm",
+    "x86_64-pc-windows-msvc",
+    "x86_64-unikraft-linux-musl",
+    "x86_64-unknown-dragonfly",
+    "x86_64-unknown-freebsd",
+    "x86_64-unknown-fuchsia",
+    "x86_64-unknown-haiku",
+    "x86_64-unknown-hermit",
+    "x86_64-unknown-hurd-gnu",
+    "x86_64-unknown-illumos",
+    "x86_64-unknown-l4re-uclibc",
+    "x86_64-unknown-linux-gnu",
+    "x86_64-unknown-linux-gnux32",
+    "x86_64-unknown-linux-musl",
+    "x86_64-unknown-linux-none",
+    "x86_64-unknown-linux-ohos",
+    "x86_64-unknown-netbsd",
+    "x86_64-unknown-none",
+    "x86_64-unknown-openbsd",
+    "x86_64-unknown-redox",
+    "x86_64-unknown-trusty",
+    "x86_64-unknown-uefi",
+    "x86_64-uwp-windows-gnu",
+    "x86_64-uwp-windows-msvc",
+    "x86_64-win7-windows-gnu",
+    "x86_64-win7-windows-msvc",
+    "x86_64-wrs-vxworks",
+    "x86_64h-apple-darwin",
+    "x86_amx_intrinsics",
+    "x87",
+    "x87_target_feature",
+    "xop",
+    "xop_target_feature",
+    "xsave",
+    "xsavec",
+    "xsaveopt",
+    "xsaves",
+    "xtensa-esp32-espidf",
+    "xtensa-esp32-none-elf",
+    "xtensa-esp32s2-espidf",
+    "xtensa-esp32s2-none-elf",
+    "xtensa-esp32s3-espidf",
+    "xtensa-esp32s3-none-elf",
+    "za128rs",
+    "za64rs",
+    "zaamo",
+    "zabha",
+    "zacas",
+    "zalrsc",
+    "zam",
+    "zama16b",
+    "zawrs",
+    "zba",
+    "zbb",
+    "zbc",
+    "zbkb",
+    "zbkc",
+    "zbkx",
+    "zbs",
+    "zdinx",
+    "zfh",
+    "zfhmin",
+    "zfinx",
+    "zhinx",
+    "zhinxmin",
+    "zicntr",
+    "zicsr",
+    "zifencei",
+    "zihintpause",
+    "zihpm",
+    "zk",
+    "zkn",
+    "zknd",
+    "zkne",
+    "zknh",
+    "zkr",
+    "zks",
+    "zksed",
+    "zksh",
+    "zkt",
+    "ztso",
+];
+static IMPLIES_FEATURES: [&[&str]; 102usize] = [
+    &[],
+    &[NAMES[1u32 as usize]],
+    &[NAMES[2u32 as usize], NAMES[177u32 as usize]],
+    &[NAMES[5u32 as usize]],
+    &[NAMES[6u32 as usize]],
+    &[NAMES[56u32 as usize], NAMES[121u32 as usize]],
+    &[NAMES[56u32 as usize], NAMES[529u32 as usize]],
+    &[NAMES[57u32 as usize]],
+    &[NAMES[65u32 as usize]],
+    &[NAMES[120u32 as usize]],
+    &[NAMES[120u32 as usize], NAMES[377u32 as usize]],
+    &[NAMES[120u32 as usize], NAMES[514u32 as usize]],
+    &[NAMES[121u32 as usize]],
+    &[NAMES[121u32 as usize], NAMES[197u32 as usize], NAMES[212u32 as usize]],
+    &[NAMES[125u32 as usize]],
+    &[NAMES[125u32 as usize], NAMES[127u32 as usize], NAMES[137u32 as usize]],
+    &[NAMES[129u32 as usize]],
+    &[NAMES[149u32 as usize]],
+    &[NAMES[149u32 as usize], NAMES[200u32 as usize], NAMES[321u32 as usize]],
+    &[NAMES[149u32 as usize], NAMES[278u32 as usize], NAMES[584u32 as usize]],
+    &[NAMES[149u32 as usize], NAMES[497u32 as usize], NAMES[528u32 as usize]],
+    &[
+        NAMES[156u32 as usize],
+        NAMES[180u32 as usize],
+        NAMES[477u32 as usize],
+        NAMES[508u32 as usize],
+        NAMES[583u32 as usize],
+    ],
+    &[
+        NAMES[164u32 as usize],
+        NAMES[316u32 as usize],
+        NAMES[317u32 as usize],
+        NAMES[372u32 as usize],
+        NAMES[421u32 as usize],
+        NAMES[610u32 as usize],
+    ],
+    &[NAMES[170u32 as usize], NAMES[587u32 as usize]],
+    &[NAMES[172u32 as usize]],
+    &[NAMES[173u32 as usize], NAMES[607u32 as usize]],
+    &[
+        NAMES[175u32 as usize],
+        NAMES[178u32 as usize],
+        NAMES[205u32 as usize],
+        NAMES[582u32 as usize],
+    ],
+    &[NAMES[177u32 as usize]],
+    &[NAMES[179u32 as usize]],
+    &[NAMES[179u32 as usize], NAMES[415u32 as usize], NAMES[580u32 as usize]],
+    &[NAMES[186u32 as usize]],
+    &[NAMES[189u32 as usize]],
+    &[NAMES[196u32 as usize]],
+    &[NAMES[215u32 as usize]],
+    &[NAMES[218u32 as usize]],
+    &[NAMES[218u32 as usize], NAMES[497u32 as usize]],
+    &[NAMES[220u32 as usize]],
+    &[NAMES[221u32 as usize]],
+    &[NAMES[242u32 as usize], NAMES[354u32 as usize], NAMES[586u32 as usize]],
+    &[NAMES[248u32 as usize]],
+    &[NAMES[289u32 as usize]],
+    &[NAMES[290u32 as usize]],
+    &[NAMES[291u32 as usize]],
+    &[NAMES[292u32 as usize], NAMES[296u32 as usize]],
+    &[NAMES[293u32 as usize]],
+    &[NAMES[295u32 as usize]],
+    &[
+        NAMES[298u32 as usize],
+        NAMES[370u32 as usize],
+        NAMES[371u32 as usize],
+        NAMES[417u32 as usize],
+        NAMES[581u32 as usize],
+    ],
+    &[NAMES[300u32 as usize]],
+    &[NAMES[317u32 as usize]],
+    &[NAMES[320u32 as usize]],
+    &[NAMES[364u32 as usize]],
+    &[NAMES[384u32 as usize]],
+    &[NAMES[384u32 as usize], NAMES[614u32 as usize]],
+    &[NAMES[386u32 as usize], NAMES[387u32 as usize]],
+    &[NAMES[388u32 as usize]],
+    &[NAMES[417u32 as usize]],
+    &[NAMES[419u32 as usize]],
+    &[NAMES[480u32 as usize]],
+    &[NAMES[481u32 as usize], NAMES[529u32 as usize]],
+    &[NAMES[485u32 as usize]],
+    &[NAMES[487u32 as usize], NAMES[529u32 as usize]],
+    &[NAMES[488u32 as usize]],
+    &[NAMES[488u32 as usize], NAMES[529u32 as usize]],
+    &[NAMES[493u32 as usize]],
+    &[NAMES[497u32 as usize]],
+    &[NAMES[509u32 as usize]],
+    &[NAMES[510u32 as usize]],
+    &[NAMES[511u32 as usize]],
+    &[NAMES[512u32 as usize]],
+    &[NAMES[513u32 as usize]],
+    &[NAMES[516u32 as usize]],
+    &[NAMES[518u32 as usize]],
+    &[NAMES[519u32 as usize]],
+    &[NAMES[527u32 as usize]],
+    &[NAMES[529u32 as usize]],
+    &[NAMES[529u32 as usize], NAMES[584u32 as usize]],
+    &[NAMES[542u32 as usize], NAMES[576u32 as usize]],
+    &[NAMES[574u32 as usize]],
+    &[NAMES[575u32 as usize]],
+    &[NAMES[577u32 as usize]],
+    &[NAMES[578u32 as usize]],
+    &[NAMES[585u32 as usize], NAMES[596u32 as usize]],
+    &[NAMES[585u32 as usize], NAMES[625u32 as usize]],
+    &[NAMES[586u32 as usize], NAMES[591u32 as usize]],
+    &[NAMES[587u32 as usize], NAMES[592u32 as usize]],
+    &[NAMES[588u32 as usize], NAMES[593u32 as usize]],
+    &[NAMES[594u32 as usize]],
+    &[NAMES[601u32 as usize]],
+    &[NAMES[602u32 as usize]],
+    &[NAMES[604u32 as usize]],
+    &[NAMES[605u32 as usize]],
+    &[NAMES[608u32 as usize]],
+    &[NAMES[609u32 as usize]],
+    &[NAMES[677u32 as usize]],
+    &[NAMES[689u32 as usize]],
+    &[NAMES[689u32 as usize], NAMES[692u32 as usize]],
+    &[
+        NAMES[699u32 as usize],
+        NAMES[700u32 as usize],
+        NAMES[701u32 as usize],
+        NAMES[716u32 as usize],
+        NAMES[717u32 as usize],
+        NAMES[718u32 as usize],
+    ],
+    &[
+        NAMES[699u32 as usize],
+        NAMES[700u32 as usize],
+        NAMES[701u32 as usize],
+        NAMES[721u32 as usize],
+        NAMES[722u32 as usize],
+    ],
+    &[NAMES[705u32 as usize]],
+    &[NAMES[706u32 as usize]],
+    &[NAMES[708u32 as usize]],
+    &[NAMES[715u32 as usize], NAMES[719u32 as usize], NAMES[723u32 as usize]],
+];
+/// Name, gate + 1, globally enabled, implied features + 1
+static FEATURES: [consts::Feature; 813usize] = [
+    feature(0u32, 170u32, false, 0u32),
+    feature(0u32, 170u32, true, 0u32),
+    feature(0u32, 170u32, false, 5u32),
+    feature(0u32, 170u32, true, 5u32),
+    feature(1u32, 170u32, true, 0u32),
+    feature(1u32, 170u32, true, 31u32),
+    feature(2u32, 170u32, false, 0u32),
+    feature(2u32, 170u32, true, 0u32),
+    feature(2u32, 170u32, false, 1u32),
+    feature(2u32, 170u32, true, 1u32),
+    feature(3u32, 170u32, false, 0u32),
+    feature(3u32, 170u32, true, 0u32),
+    feature(3u32, 170u32, false, 3u32),
+    feature(3u32, 170u32, true, 3u32),
+    feature(4u32, 170u32, false, 0u32),
+    feature(4u32, 170u32, true, 0u32),
+    feature(4u32, 170u32, false, 28u32),
+    feature(4u32, 170u32, true, 28u32),
+    feature(5u32, 170u32, true, 0u32),
+    feature(5u32, 170u32, true, 2u32),
+    feature(6u32, 170u32, true, 0u32),
+    feature(6u32, 170u32, true, 4u32),
+    feature(7u32, 0u32, false, 0u32),
+    feature(7u32, 0u32, true, 0u32),
+    feature(7u32, 0u32, false, 96u32),
+    feature(7u32, 0u32, true, 96u32),
+    feature(54u32, 79u32, false, 0u32),
+    feature(54u32, 79u32, true, 0u32),
+    feature(54u32, 79u32, false, 1u32),
+    feature(54u32, 79u32, true, 1u32),
+    feature(55u32, 0u32, false, 0u32),
+    feature(55u32, 0u32, false, 1u32),
+    feature(56u32, 0u32, false, 0u32),
+    feature(56u32, 0u32, true, 0u32),
+    feature(56u32, 0u32, false, 51u32),
+    feature(56u32, 0u32, true, 51u32),
+    feature(56u32, 0u32, false, 67u32),
+    feature(56u32, 79u32, false, 0u32),
+    feature(56u32, 79u32, false, 51u32),
+    feature(57u32, 410u32, false, 0u32),
+    feature(57u32, 410u32, true, 0u32),
+    feature(57u32, 410u32, false, 1u32),
+    feature(57u32, 410u32, true, 1u32),
+    feature(59u32, 154u32, false, 0u32),
+    feature(59u32, 154u32, false, 1u32),
+    feature(61u32, 673u32, false, 0u32),
+    feature(61u32, 673u32, false, 9u32),
+    feature(62u32, 673u32, false, 0u32),
+    feature(62u32, 673u32, false, 9u32),
+    feature(63u32, 673u32, false, 0u32),
+    feature(63u32, 673u32, false, 9u32),
+    feature(64u32, 673u32, false, 0u32),
+    feature(64u32, 673u32, false, 9u32),
+    feature(65u32, 673u32, false, 0u32),
+    feature(65u32, 673u32, false, 1u32),
+    feature(117u32, 625u32, false, 0u32),
+    feature(117u32, 625u32, true, 0u32),
+    feature(117u32, 625u32, false, 1u32),
+    feature(117u32, 625u32, true, 1u32),
+    feature(120u32, 0u32, false, 0u32),
+    feature(120u32, 0u32, true, 0u32),
+    feature(120u32, 0u32, false, 70u32),
+    feature(120u32, 0u32, true, 70u32),
+    feature(121u32, 0u32, false, 0u32),
+    feature(121u32, 0u32, true, 0u32),
+    feature(121u32, 0u32, false, 10u32),
+    feature(121u32, 0u32, true, 10u32),
+    feature(123u32, 123u32, false, 0u32),
+    feature(123u32, 123u32, false, 15u32),
+    feature(124u32, 123u32, false, 0u32),
+    feature(124u32, 123u32, false, 15u32),
+    feature(125u32, 123u32, false, 0u32),
+    feature(125u32, 123u32, false, 17u32),
+    feature(126u32, 123u32, false, 0u32),
+    feature(126u32, 123u32, false, 17u32),
+    feature(127u32, 123u32, false, 0u32),
+    feature(127u32, 123u32, false, 17u32),
+    feature(129u32, 123u32, false, 0u32),
+    feature(129u32, 123u32, false, 14u32),
+    feature(130u32, 123u32, false, 0u32),
+    feature(130u32, 123u32, false, 16u32),
+    feature(132u32, 123u32, false, 0u32),
+    feature(132u32, 123u32, false, 17u32),
+    feature(135u32, 123u32, false, 0u32),
+    feature(135u32, 123u32, false, 15u32),
+    feature(136u32, 123u32, false, 0u32),
+    feature(136u32, 123u32, false, 15u32),
+    feature(137u32, 123u32, false, 0u32),
+    feature(137u32, 123u32, false, 17u32),
+    feature(138u32, 123u32, false, 0u32),
+    feature(138u32, 123u32, false, 17u32),
+    feature(139u32, 123u32, false, 0u32),
+    feature(139u32, 123u32, false, 17u32),
+    feature(141u32, 123u32, false, 0u32),
+    feature(141u32, 123u32, false, 17u32),
+    feature(142u32, 123u32, false, 0u32),
+    feature(142u32, 123u32, false, 13u32),
+    feature(143u32, 123u32, false, 0u32),
+    feature(143u32, 123u32, false, 13u32),
+    feature(144u32, 123u32, false, 0u32),
+    feature(144u32, 123u32, false, 13u32),
+    feature(145u32, 123u32, false, 0u32),
+    feature(145u32, 123u32, false, 13u32),
+    feature(146u32, 123u32, false, 0u32),
+    feature(146u32, 123u32, false, 13u32),
+    feature(148u32, 476u32, true, 1u32),
+    feature(149u32, 0u32, false, 0u32),
+    feature(149u32, 0u32, false, 1u32),
+    feature(151u32, 0u32, false, 0u32),
+    feature(151u32, 0u32, true, 0u32),
+    feature(151u32, 0u32, false, 1u32),
+    feature(151u32, 0u32, true, 1u32),
+    feature(152u32, 0u32, false, 0u32),
+    feature(152u32, 0u32, true, 0u32),
+    feature(152u32, 0u32, false, 1u32),
+    feature(152u32, 0u32, true, 1u32),
+    feature(156u32, 0u32, false, 0u32),
+    feature(156u32, 0u32, false, 1u32),
+    feature(157u32, 0u32, false, 0u32),
+    feature(157u32, 0u32, true, 0u32),
+    feature(157u32, 0u32, false, 1u32),
+    feature(157u32, 0u32, true, 1u32),
+    feature(158u32, 0u32, false, 0u32),
+    feature(158u32, 0u32, true, 0u32),
+    feature(158u32, 0u32, false, 1u32),
+    feature(158u32, 0u32, true, 1u32),
+    feature(159u32, 170u32, true, 0u32),
+    feature(159u32, 170u32, true, 1u32),
+    feature(162u32, 0u32, false, 0u32),
+    feature(162u32, 0u32, true, 0u32),
+    feature(162u32, 0u32, false, 1u32),
+    feature(162u32, 0u32, true, 1u32),
+    feature(164u32, 0u32, false, 0u32),
+    feature(164u32, 0u32, true, 0u32),
+    feature(164u32, 0u32, false, 1u32),
+    feature(164u32, 0u32, true, 1u32),
+    feature(164u32, 79u32, false, 0u32),
+    feature(164u32, 79u32, true, 0u32),
+    feature(164u32, 79u32, false, 1u32),
+    feature(164u32, 79u32, true, 1u32),
+    feature(170u32, 52u32, false, 1u32),
+    feature(172u32, 316u32, false, 0u32),
+    feature(172u32, 316u32, true, 0u32),
+    feature(172u32, 316u32, false, 33u32),
+    feature(172u32, 316u32, true, 33u32),
+    feature(172u32, 465u32, false, 0u32),
+    feature(172u32, 465u32, true, 0u32),
+    feature(172u32, 465u32, false, 33u32),
+    feature(172u32, 465u32, true, 33u32),
+    feature(173u32, 79u32, false, 0u32),
+    feature(173u32, 79u32, true, 0u32),
+    feature(173u32, 79u32, false, 1u32),
+    feature(173u32, 79u32, true, 1u32),
+    feature(174u32, 476u32, false, 1u32),
+    feature(175u32, 0u32, false, 0u32),
+    feature(175u32, 0u32, true, 0u32),
+    feature(175u32, 0u32, false, 1u32),
+    feature(175u32, 0u32, true, 1u32),
+    feature(176u32, 316u32, false, 1u32),
+    feature(177u32, 170u32, false, 0u32),
+    feature(177u32, 170u32, true, 0u32),
+    feature(177u32, 170u32, false, 1u32),
+    feature(177u32, 170u32, true, 1u32),
+    feature(178u32, 0u32, false, 0u32),
+    feature(178u32, 0u32, true, 0u32),
+    feature(178u32, 0u32, false, 51u32),
+    feature(178u32, 0u32, true, 51u32),
+    feature(178u32, 79u32, false, 0u32),
+    feature(178u32, 79u32, false, 51u32),
+    feature(179u32, 0u32, false, 0u32),
+    feature(179u32, 0u32, true, 0u32),
+    feature(179u32, 0u32, false, 1u32),
+    feature(179u32, 0u32, true, 1u32),
+    feature(180u32, 0u32, false, 0u32),
+    feature(180u32, 0u32, true, 0u32),
+    feature(180u32, 0u32, false, 29u32),
+    feature(180u32, 0u32, true, 29u32),
+    feature(181u32, 79u32, false, 0u32),
+    feature(181u32, 79u32, true, 0u32),
+    feature(181u32, 79u32, false, 1u32),
+    feature(181u32, 79u32, true, 1u32),
+    feature(182u32, 170u32, true, 0u32),
+    feature(182u32, 170u32, true, 1u32),
+    feature(183u32, 170u32, true, 0u32),
+    feature(183u32, 170u32, true, 1u32),
+    feature(184u32, 465u32, false, 0u32),
+    feature(184u32, 465u32, false, 1u32),
+    feature(184u32, 465u32, true, 1u32),
+    feature(185u32, 170u32, true, 0u32),
+    feature(185u32, 170u32, true, 32u32),
+    feature(186u32, 170u32, true, 0u32),
+    feature(186u32, 170u32, true, 31u32),
+    feature(187u32, 52u32, false, 1u32),
+    feature(188u32, 170u32, true, 0u32),
+    feature(188u32, 170u32, true, 1u32),
+    feature(189u32, 170u32, true, 0u32),
+    feature(189u32, 170u32, true, 1u32),
+    feature(190u32, 476u32, false, 1u32),
+    feature(191u32, 193u32, false, 0u32),
+    feature(191u32, 193u32, true, 0u32),
+    feature(191u32, 193u32, false, 1u32),
+    feature(191u32, 193u32, true, 1u32),
+    feature(194u32, 625u32, false, 0u32),
+    feature(194u32, 625u32, false, 1u32),
+    feature(195u32, 0u32, false, 0u32),
+    feature(195u32, 0u32, false, 1u32),
+    feature(196u32, 316u32, false, 0u32),
+    feature(196u32, 316u32, true, 0u32),
+    feature(196u32, 316u32, false, 1u32),
+    feature(196u32, 316u32, true, 1u32),
+    feature(196u32, 465u32, false, 0u32),
+    feature(196u32, 465u32, true, 0u32),
+    feature(196u32, 465u32, false, 1u32),
+    feature(196u32, 465u32, true, 1u32),
+    feature(197u32, 0u32, false, 0u32),
+    feature(197u32, 0u32, true, 0u32),
+    feature(197u32, 0u32, false, 10u32),
+    feature(197u32, 0u32, true, 10u32),
+    feature(198u32, 0u32, false, 0u32),
+    feature(198u32, 0u32, false, 74u32),
+    feature(199u32, 0u32, false, 0u32),
+    feature(199u32, 0u32, false, 74u32),
+    feature(200u32, 52u32, false, 1u32),
+    feature(202u32, 0u32, false, 0u32),
+    feature(202u32, 0u32, true, 0u32),
+    feature(202u32, 0u32, false, 51u32),
+    feature(202u32, 0u32, true, 51u32),
+    feature(203u32, 170u32, false, 0u32),
+    feature(203u32, 170u32, false, 1u32),
+    feature(204u32, 0u32, false, 0u32),
+    feature(204u32, 0u32, true, 0u32),
+    feature(204u32, 0u32, false, 34u32),
+    feature(204u32, 0u32, true, 34u32),
+    feature(205u32, 0u32, false, 0u32),
+    feature(205u32, 0u32, true, 0u32),
+    feature(205u32, 0u32, false, 1u32),
+    feature(205u32, 0u32, true, 1u32),
+    feature(206u32, 52u32, false, 1u32),
+    feature(206u32, 52u32, true, 1u32),
+    feature(207u32, 170u32, false, 0u32),
+    feature(207u32, 170u32, false, 1u32),
+    feature(208u32, 170u32, false, 0u32),
+    feature(208u32, 170u32, false, 1u32),
+    feature(209u32, 170u32, false, 0u32),
+    feature(209u32, 170u32, false, 1u32),
+    feature(210u32, 170u32, false, 0u32),
+    feature(210u32, 170u32, true, 0u32),
+    feature(210u32, 170u32, false, 1u32),
+    feature(210u32, 170u32, true, 1u32),
+    feature(211u32, 170u32, false, 0u32),
+    feature(211u32, 170u32, false, 1u32),
+    feature(212u32, 0u32, false, 0u32),
+    feature(212u32, 0u32, true, 0u32),
+    feature(212u32, 0u32, false, 10u32),
+    feature(212u32, 0u32, true, 10u32),
+    feature(214u32, 79u32, false, 0u32),
+    feature(214u32, 79u32, false, 93u32),
+    feature(215u32, 0u32, false, 0u32),
+    feature(215u32, 0u32, true, 0u32),
+    feature(215u32, 0u32, false, 51u32),
+    feature(215u32, 0u32, true, 51u32),
+    feature(215u32, 79u32, false, 51u32),
+    feature(217u32, 341u32, false, 0u32),
+    feature(217u32, 341u32, true, 0u32),
+    feature(217u32, 341u32, false, 1u32),
+    feature(217u32, 341u32, true, 1u32),
+    feature(218u32, 52u32, false, 19u32),
+    feature(219u32, 52u32, false, 37u32),
+    feature(220u32, 52u32, false, 38u32),
+    feature(221u32, 52u32, false, 35u32),
+    feature(223u32, 79u32, false, 1u32),
+    feature(223u32, 79u32, true, 1u32),
+    feature(225u32, 170u32, false, 0u32),
+    feature(225u32, 170u32, false, 1u32),
+    feature(226u32, 170u32, false, 0u32),
+    feature(226u32, 170u32, false, 1u32),
+    feature(227u32, 170u32, false, 0u32),
+    feature(227u32, 170u32, true, 0u32),
+    feature(227u32, 170u32, false, 1u32),
+    feature(227u32, 170u32, true, 1u32),
+    feature(228u32, 170u32, false, 0u32),
+    feature(228u32, 170u32, true, 0u32),
+    feature(228u32, 170u32, false, 1u32),
+    feature(228u32, 170u32, true, 1u32),
+    feature(229u32, 170u32, false, 0u32),
+    feature(229u32, 170u32, true, 0u32),
+    feature(229u32, 170u32, false, 1u32),
+    feature(229u32, 170u32, true, 1u32),
+    feature(230u32, 170u32, false, 0u32),
+    feature(230u32, 170u32, true, 0u32),
+    feature(230u32, 170u32, false, 1u32),
+    feature(230u32, 170u32, true, 1u32),
+    feature(231u32, 316u32, false, 0u32),
+    feature(231u32, 316u32, false, 1u32),
+    feature(232u32, 0u32, false, 0u32),
+    feature(232u32, 0u32, true, 0u32),
+    feature(232u32, 0u32, false, 1u32),
+    feature(232u32, 0u32, true, 1u32),
+    feature(234u32, 0u32, false, 0u32),
+    feature(234u32, 0u32, true, 0u32),
+    feature(234u32, 0u32, false, 1u32),
+    feature(234u32, 0u32, true, 1u32),
+    feature(235u32, 123u32, false, 0u32),
+    feature(235u32, 123u32, false, 67u32),
+    feature(236u32, 476u32, false, 1u32),
+    feature(238u32, 170u32, false, 0u32),
+    feature(238u32, 170u32, true, 0u32),
+    feature(238u32, 170u32, false, 1u32),
+    feature(238u32, 170u32, true, 1u32),
+    feature(239u32, 170u32, false, 0u32),
+    feature(239u32, 170u32, true, 0u32),
+    feature(239u32, 170u32, false, 1u32),
+    feature(239u32, 170u32, true, 1u32),
+    feature(240u32, 170u32, true, 0u32),
+    feature(240u32, 170u32, true, 1u32),
+    feature(242u32, 52u32, false, 1u32),
+    feature(246u32, 170u32, true, 0u32),
+    feature(246u32, 170u32, true, 1u32),
+    feature(247u32, 476u32, false, 1u32),
+    feature(248u32, 246u32, true, 0u32),
+    feature(248u32, 246u32, true, 1u32),
+    feature(249u32, 246u32, true, 0u32),
+    feature(249u32, 246u32, true, 40u32),
+    feature(250u32, 170u32, true, 0u32),
+    feature(250u32, 170u32, true, 1u32),
+    feature(278u32, 0u32, false, 0u32),
+    feature(278u32, 0u32, false, 1u32),
+    feature(278u32, 79u32, false, 0u32),
+    feature(278u32, 79u32, false, 51u32),
+    feature(289u32, 328u32, true, 1u32),
+    feature(290u32, 328u32, true, 41u32),
+    feature(291u32, 328u32, false, 42u32),
+    feature(291u32, 328u32, true, 42u32),
+    feature(292u32, 328u32, false, 43u32),
+    feature(293u32, 328u32, false, 44u32),
+    feature(294u32, 328u32, false, 45u32),
+    feature(295u32, 328u32, false, 1u32),
+    feature(296u32, 328u32, false, 46u32),
+    feature(298u32, 0u32, false, 0u32),
+    feature(298u32, 0u32, true, 0u32),
+    feature(298u32, 0u32, false, 51u32),
+    feature(298u32, 0u32, true, 51u32),
+    feature(300u32, 300u32, false, 67u32),
+    feature(301u32, 303u32, false, 0u32),
+    feature(301u32, 303u32, true, 0u32),
+    feature(301u32, 303u32, false, 1u32),
+    feature(301u32, 303u32, true, 1u32),
+    feature(303u32, 316u32, false, 1u32),
+    feature(304u32, 316u32, false, 1u32),
+    feature(305u32, 316u32, false, 0u32),
+    feature(305u32, 316u32, true, 0u32),
+    feature(305u32, 316u32, false, 50u32),
+    feature(306u32, 316u32, true, 0u32),
+    feature(306u32, 316u32, false, 1u32),
+    feature(307u32, 316u32, false, 1u32),
+    feature(308u32, 506u32, false, 1u32),
+    feature(316u32, 0u32, false, 0u32),
+    feature(316u32, 0u32, true, 0u32),
+    feature(316u32, 0u32, false, 1u32),
+    feature(316u32, 0u32, true, 1u32),
+    feature(317u32, 0u32, false, 0u32),
+    feature(317u32, 0u32, true, 0u32),
+    feature(317u32, 0u32, false, 1u32),
+    feature(317u32, 0u32, true, 1u32),
+    feature(318u32, 52u32, false, 49u32),
+    feature(319u32, 52u32, false, 1u32),
+    feature(319u32, 52u32, true, 1u32),
+    feature(320u32, 316u32, false, 0u32),
+    feature(320u32, 316u32, true, 0u32),
+    feature(320u32, 316u32, false, 25u32),
+    feature(320u32, 316u32, true, 25u32),
+    feature(321u32, 52u32, false, 1u32),
+    feature(322u32, 316u32, true, 0u32),
+    feature(322u32, 316u32, false, 1u32),
+    feature(323u32, 0u32, false, 0u32),
+    feature(323u32, 0u32, true, 0u32),
+    feature(323u32, 0u32, false, 1u32),
+    feature(323u32, 0u32, true, 1u32),
+    feature(324u32, 0u32, false, 0u32),
+    feature(324u32, 0u32, true, 0u32),
+    feature(324u32, 0u32, false, 1u32),
+    feature(324u32, 0u32, true, 1u32),
+    feature(328u32, 79u32, false, 0u32),
+    feature(328u32, 79u32, true, 0u32),
+    feature(328u32, 79u32, false, 1u32),
+    feature(328u32, 79u32, true, 1u32),
+    feature(354u32, 52u32, false, 1u32),
+    feature(355u32, 0u32, false, 0u32),
+    feature(355u32, 0u32, true, 0u32),
+    feature(355u32, 0u32, false, 1u32),
+    feature(355u32, 0u32, true, 1u32),
+    feature(356u32, 170u32, true, 0u32),
+    feature(356u32, 170u32, true, 2u32),
+    feature(357u32, 170u32, true, 0u32),
+    feature(357u32, 170u32, true, 4u32),
+    feature(358u32, 341u32, false, 0u32),
+    feature(358u32, 341u32, false, 1u32),
+    feature(360u32, 410u32, false, 1u32),
+    feature(360u32, 410u32, true, 1u32),
+    feature(361u32, 0u32, false, 0u32),
+    feature(361u32, 0u32, false, 1u32),
+    feature(362u32, 0u32, false, 1u32),
+    feature(362u32, 0u32, true, 1u32),
+    feature(362u32, 625u32, false, 0u32),
+    feature(363u32, 0u32, true, 0u32),
+    feature(363u32, 0u32, true, 1u32),
+    feature(364u32, 0u32, false, 0u32),
+    feature(364u32, 0u32, true, 0u32),
+    feature(364u32, 0u32, false, 1u32),
+    feature(364u32, 0u32, true, 1u32),
+    feature(364u32, 79u32, false, 0u32),
+    feature(364u32, 79u32, true, 0u32),
+    feature(364u32, 79u32, false, 92u32),
+    feature(364u32, 79u32, true, 92u32),
+    feature(365u32, 476u32, false, 88u32),
+    feature(366u32, 0u32, false, 0u32),
+    feature(366u32, 0u32, true, 0u32),
+    feature(366u32, 0u32, false, 1u32),
+    feature(366u32, 0u32, true, 1u32),
+    feature(367u32, 170u32, true, 0u32),
+    feature(367u32, 170u32, true, 1u32),
+    feature(370u32, 0u32, false, 0u32),
+    feature(370u32, 0u32, true, 0u32),
+    feature(370u32, 0u32, false, 1u32),
+    feature(370u32, 0u32, true, 1u32),
+    feature(371u32, 0u32, false, 0u32),
+    feature(371u32, 0u32, true, 0u32),
+    feature(371u32, 0u32, false, 1u32),
+    feature(371u32, 0u32, true, 1u32),
+    feature(372u32, 0u32, false, 0u32),
+    feature(372u32, 0u32, true, 0u32),
+    feature(372u32, 0u32, false, 1u32),
+    feature(372u32, 0u32, true, 1u32),
+    feature(373u32, 410u32, false, 1u32),
+    feature(373u32, 410u32, true, 1u32),
+    feature(375u32, 52u32, false, 1u32),
+    feature(377u32, 0u32, false, 0u32),
+    feature(377u32, 0u32, false, 67u32),
+    feature(380u32, 0u32, false, 0u32),
+    feature(380u32, 0u32, true, 0u32),
+    feature(380u32, 0u32, false, 1u32),
+    feature(380u32, 0u32, true, 1u32),
+    feature(381u32, 0u32, false, 0u32),
+    feature(381u32, 0u32, true, 0u32),
+    feature(381u32, 0u32, false, 1u32),
+    feature(381u32, 0u32, true, 1u32),
+    feature(382u32, 410u32, false, 0u32),
+    feature(382u32, 410u32, false, 55u32),
+    feature(384u32, 410u32, false, 0u32),
+    feature(384u32, 410u32, true, 0u32),
+    feature(384u32, 410u32, false, 8u32),
+    feature(384u32, 410u32, true, 8u32),
+    feature(385u32, 410u32, false, 52u32),
+    feature(385u32, 410u32, true, 52u32),
+    feature(386u32, 410u32, false, 0u32),
+    feature(386u32, 410u32, true, 0u32),
+    feature(386u32, 410u32, false, 53u32),
+    feature(386u32, 410u32, true, 53u32),
+    feature(387u32, 410u32, false, 0u32),
+    feature(387u32, 410u32, false, 52u32),
+    feature(388u32, 410u32, false, 0u32),
+    feature(388u32, 410u32, false, 54u32),
+    feature(410u32, 412u32, false, 0u32),
+    feature(410u32, 412u32, false, 1u32),
+    feature(413u32, 410u32, false, 1u32),
+    feature(413u32, 410u32, true, 1u32),
+    feature(414u32, 0u32, false, 0u32),
+    feature(414u32, 0u32, false, 1u32),
+    feature(415u32, 0u32, false, 0u32),
+    feature(415u32, 0u32, true, 0u32),
+    feature(415u32, 0u32, false, 1u32),
+    feature(415u32, 0u32, true, 1u32),
+    feature(416u32, 79u32, false, 0u32),
+    feature(416u32, 79u32, true, 0u32),
+    feature(416u32, 79u32, false, 1u32),
+    feature(416u32, 79u32, true, 1u32),
+    feature(417u32, 0u32, false, 0u32),
+    feature(417u32, 0u32, true, 0u32),
+    feature(417u32, 0u32, false, 1u32),
+    feature(417u32, 0u32, true, 1u32),
+    feature(419u32, 0u32, false, 0u32),
+    feature(419u32, 0u32, true, 0u32),
+    feature(419u32, 0u32, false, 56u32),
+    feature(419u32, 0u32, true, 56u32),
+    feature(420u32, 52u32, false, 57u32),
+    feature(421u32, 0u32, false, 0u32),
+    feature(421u32, 0u32, true, 0u32),
+    feature(421u32, 0u32, false, 51u32),
+    feature(421u32, 0u32, true, 51u32),
+    feature(422u32, 0u32, false, 0u32),
+    feature(422u32, 0u32, true, 0u32),
+    feature(422u32, 0u32, false, 1u32),
+    feature(422u32, 0u32, true, 1u32),
+    feature(424u32, 0u32, false, 0u32),
+    feature(424u32, 0u32, true, 0u32),
+    feature(424u32, 0u32, false, 1u32),
+    feature(424u32, 0u32, true, 1u32),
+    feature(425u32, 0u32, false, 1u32),
+    feature(425u32, 0u32, true, 1u32),
+    feature(425u32, 625u32, false, 0u32),
+    feature(426u32, 316u32, false, 0u32),
+    feature(426u32, 316u32, false, 1u32),
+    feature(426u32, 465u32, false, 0u32),
+    feature(426u32, 465u32, false, 1u32),
+    feature(427u32, 0u32, false, 60u32),
+    feature(427u32, 625u32, false, 0u32),
+    feature(428u32, 52u32, false, 1u32),
+    feature(428u32, 52u32, true, 1u32),
+    feature(465u32, 467u32, false, 0u32),
+    feature(465u32, 467u32, false, 1u32),
+    feature(477u32, 0u32, false, 0u32),
+    feature(477u32, 0u32, true, 0u32),
+    feature(477u32, 0u32, false, 1u32),
+    feature(477u32, 0u32, true, 1u32),
+    feature(478u32, 316u32, false, 1u32),
+    feature(479u32, 0u32, false, 0u32),
+    feature(479u32, 0u32, false, 67u32),
+    feature(480u32, 0u32, false, 0u32),
+    feature(480u32, 0u32, true, 0u32),
+    feature(480u32, 0u32, false, 51u32),
+    feature(480u32, 0u32, true, 51u32),
+    feature(480u32, 79u32, false, 0u32),
+    feature(480u32, 79u32, false, 51u32),
+    feature(481u32, 0u32, false, 0u32),
+    feature(481u32, 0u32, true, 0u32),
+    feature(481u32, 0u32, false, 58u32),
+    feature(481u32, 0u32, true, 58u32),
+    feature(482u32, 484u32, false, 13u32),
+    feature(484u32, 0u32, true, 0u32),
+    feature(484u32, 0u32, false, 1u32),
+    feature(484u32, 0u32, true, 1u32),
+    feature(485u32, 0u32, false, 0u32),
+    feature(485u32, 0u32, false, 1u32),
+    feature(486u32, 484u32, false, 10u32),
+    feature(487u32, 0u32, false, 0u32),
+    feature(487u32, 0u32, false, 51u32),
+    feature(487u32, 484u32, false, 13u32),
+    feature(488u32, 52u32, false, 18u32),
+    feature(489u32, 52u32, false, 21u32),
+    feature(490u32, 52u32, false, 65u32),
+    feature(491u32, 52u32, false, 62u32),
+    feature(492u32, 52u32, false, 64u32),
+    feature(493u32, 52u32, false, 36u32),
+    feature(494u32, 52u32, false, 63u32),
+    feature(495u32, 52u32, false, 62u32),
+    feature(496u32, 52u32, false, 1u32),
+    feature(497u32, 52u32, false, 62u32),
+    feature(498u32, 52u32, false, 65u32),
+    feature(499u32, 79u32, false, 1u32),
+    feature(499u32, 79u32, true, 1u32),
+    feature(499u32, 675u32, false, 1u32),
+    feature(499u32, 675u32, true, 1u32),
+    feature(507u32, 0u32, false, 0u32),
+    feature(507u32, 0u32, false, 1u32),
+    feature(508u32, 0u32, false, 0u32),
+    feature(508u32, 0u32, true, 0u32),
+    feature(508u32, 0u32, false, 1u32),
+    feature(508u32, 0u32, true, 1u32),
+    feature(509u32, 0u32, false, 0u32),
+    feature(509u32, 0u32, true, 0u32),
+    feature(509u32, 0u32, false, 1u32),
+    feature(509u32, 0u32, true, 1u32),
+    feature(510u32, 0u32, false, 0u32),
+    feature(510u32, 0u32, true, 0u32),
+    feature(510u32, 0u32, false, 66u32),
+    feature(510u32, 0u32, true, 66u32),
+    feature(511u32, 0u32, false, 0u32),
+    feature(511u32, 0u32, true, 0u32),
+    feature(511u32, 0u32, false, 67u32),
+    feature(511u32, 0u32, true, 67u32),
+    feature(512u32, 0u32, false, 0u32),
+    feature(512u32, 0u32, true, 0u32),
+    feature(512u32, 0u32, false, 71u32),
+    feature(512u32, 0u32, true, 71u32),
+    feature(513u32, 0u32, false, 0u32),
+    feature(513u32, 0u32, true, 0u32),
+    feature(513u32, 0u32, false, 69u32),
+    feature(513u32, 0u32, true, 69u32),
+    feature(514u32, 516u32, false, 0u32),
+    feature(514u32, 516u32, false, 68u32),
+    feature(516u32, 0u32, false, 0u32),
+    feature(516u32, 0u32, true, 0u32),
+    feature(516u32, 0u32, false, 68u32),
+    feature(516u32, 0u32, true, 68u32),
+    feature(517u32, 52u32, false, 72u32),
+    feature(518u32, 52u32, false, 73u32),
+    feature(519u32, 52u32, false, 36u32),
+    feature(527u32, 0u32, false, 0u32),
+    feature(527u32, 0u32, false, 51u32),
+    feature(528u32, 52u32, false, 18u32),
+    feature(529u32, 0u32, false, 0u32),
+    feature(529u32, 0u32, false, 74u32),
+    feature(530u32, 0u32, false, 0u32),
+    feature(530u32, 0u32, false, 7u32),
+    feature(531u32, 0u32, false, 0u32),
+    feature(531u32, 0u32, false, 75u32),
+    feature(532u32, 0u32, false, 0u32),
+    feature(532u32, 0u32, false, 59u32),
+    feature(533u32, 0u32, false, 0u32),
+    feature(533u32, 0u32, false, 61u32),
+    feature(534u32, 52u32, false, 75u32),
+    feature(538u32, 0u32, false, 1u32),
+    feature(539u32, 541u32, false, 0u32),
+    feature(539u32, 541u32, false, 1u32),
+    feature(541u32, 79u32, false, 0u32),
+    feature(541u32, 79u32, true, 0u32),
+    feature(541u32, 79u32, false, 1u32),
+    feature(541u32, 79u32, true, 1u32),
+    feature(542u32, 79u32, false, 0u32),
+    feature(542u32, 79u32, true, 0u32),
+    feature(542u32, 79u32, false, 1u32),
+    feature(542u32, 79u32, true, 1u32),
+    feature(566u32, 0u32, false, 0u32),
+    feature(566u32, 0u32, false, 1u32),
+    feature(567u32, 476u32, false, 1u32),
+    feature(568u32, 170u32, true, 0u32),
+    feature(568u32, 170u32, true, 1u32),
+    feature(569u32, 79u32, false, 0u32),
+    feature(569u32, 79u32, true, 0u32),
+    feature(569u32, 79u32, false, 1u32),
+    feature(569u32, 79u32, true, 1u32),
+    feature(571u32, 316u32, true, 0u32),
+    feature(571u32, 316u32, true, 1u32),
+    feature(572u32, 465u32, false, 0u32),
+    feature(572u32, 465u32, false, 1u32),
+    feature(573u32, 465u32, false, 0u32),
+    feature(573u32, 465u32, true, 0u32),
+    feature(573u32, 465u32, false, 1u32),
+    feature(573u32, 465u32, true, 1u32),
+    feature(574u32, 79u32, false, 0u32),
+    feature(574u32, 79u32, true, 0u32),
+    feature(574u32, 79u32, false, 1u32),
+    feature(574u32, 79u32, true, 1u32),
+    feature(575u32, 79u32, false, 0u32),
+    feature(575u32, 79u32, true, 0u32),
+    feature(575u32, 79u32, false, 78u32),
+    feature(575u32, 79u32, true, 78u32),
+    feature(576u32, 79u32, false, 0u32),
+    feature(576u32, 79u32, true, 0u32),
+    feature(576u32, 79u32, false, 79u32),
+    feature(576u32, 79u32, true, 79u32),
+    feature(577u32, 79u32, false, 0u32),
+    feature(577u32, 79u32, true, 0u32),
+    feature(577u32, 79u32, false, 77u32),
+    feature(577u32, 79u32, true, 77u32),
+    feature(578u32, 79u32, false, 0u32),
+    feature(578u32, 79u32, true, 0u32),
+    feature(578u32, 79u32, false, 80u32),
+    feature(578u32, 79u32, true, 80u32),
+    feature(579u32, 79u32, false, 0u32),
+    feature(579u32, 79u32, true, 0u32),
+    feature(579u32, 79u32, false, 81u32),
+    feature(579u32, 79u32, true, 81u32),
+    feature(580u32, 53u32, false, 0u32),
+    feature(580u32, 53u32, true, 0u32),
+    feature(580u32, 53u32, false, 23u32),
+    feature(580u32, 53u32, true, 23u32),
+    feature(581u32, 53u32, false, 0u32),
+    feature(581u32, 53u32, true, 0u32),
+    feature(581u32, 53u32, false, 30u32),
+    feature(581u32, 53u32, true, 30u32),
+    feature(582u32, 53u32, false, 0u32),
+    feature(582u32, 53u32, true, 0u32),
+    feature(582u32, 53u32, false, 47u32),
+    feature(582u32, 53u32, true, 47u32),
+    feature(583u32, 53u32, false, 0u32),
+    feature(583u32, 53u32, true, 0u32),
+    feature(583u32, 53u32, false, 27u32),
+    feature(583u32, 53u32, true, 27u32),
+    feature(584u32, 53u32, false, 0u32),
+    feature(584u32, 53u32, false, 22u32),
+    feature(585u32, 53u32, false, 0u32),
+    feature(585u32, 53u32, false, 20u32),
+    feature(586u32, 53u32, false, 0u32),
+    feature(586u32, 53u32, false, 83u32),
+    feature(587u32, 53u32, false, 39u32),
+    feature(588u32, 53u32, false, 24u32),
+    feature(589u32, 506u32, false, 1u32),
+    feature(589u32, 506u32, true, 1u32),
+    feature(590u32, 506u32, false, 1u32),
+    feature(590u32, 506u32, true, 1u32),
+    feature(591u32, 53u32, false, 82u32),
+    feature(592u32, 53u32, false, 84u32),
+    feature(593u32, 53u32, false, 85u32),
+    feature(594u32, 53u32, false, 86u32),
+    feature(595u32, 53u32, false, 87u32),
+    feature(596u32, 53u32, false, 76u32),
+    feature(597u32, 123u32, false, 0u32),
+    feature(597u32, 123u32, false, 6u32),
+    feature(598u32, 170u32, false, 0u32),
+    feature(598u32, 170u32, true, 0u32),
+    feature(598u32, 170u32, false, 1u32),
+    feature(598u32, 170u32, true, 1u32),
+    feature(599u32, 170u32, false, 0u32),
+    feature(599u32, 170u32, false, 1u32),
+    feature(600u32, 170u32, false, 0u32),
+    feature(600u32, 170u32, true, 0u32),
+    feature(600u32, 170u32, false, 1u32),
+    feature(600u32, 170u32, true, 1u32),
+    feature(601u32, 476u32, false, 1u32),
+    feature(602u32, 476u32, false, 88u32),
+    feature(603u32, 476u32, false, 89u32),
+    feature(604u32, 476u32, false, 88u32),
+    feature(605u32, 476u32, false, 90u32),
+    feature(606u32, 476u32, false, 91u32),
+    feature(607u32, 79u32, false, 0u32),
+    feature(607u32, 79u32, true, 0u32),
+    feature(607u32, 79u32, false, 1u32),
+    feature(607u32, 79u32, true, 1u32),
+    feature(608u32, 79u32, false, 0u32),
+    feature(608u32, 79u32, true, 0u32),
+    feature(608u32, 79u32, false, 26u32),
+    feature(608u32, 79u32, true, 26u32),
+    feature(609u32, 79u32, false, 0u32),
+    feature(609u32, 79u32, true, 0u32),
+    feature(609u32, 79u32, false, 92u32),
+    feature(609u32, 79u32, true, 92u32),
+    feature(610u32, 0u32, false, 0u32),
+    feature(610u32, 0u32, true, 0u32),
+    feature(610u32, 0u32, false, 1u32),
+    feature(610u32, 0u32, true, 1u32),
+    feature(611u32, 341u32, false, 0u32),
+    feature(611u32, 341u32, false, 1u32),
+    feature(612u32, 79u32, false, 0u32),
+    feature(612u32, 79u32, true, 0u32),
+    feature(612u32, 79u32, false, 1u32),
+    feature(612u32, 79u32, true, 1u32),
+    feature(613u32, 123u32, false, 0u32),
+    feature(613u32, 123u32, false, 11u32),
+    feature(614u32, 410u32, false, 0u32),
+    feature(614u32, 410u32, true, 0u32),
+    feature(614u32, 410u32, false, 8u32),
+    feature(614u32, 410u32, true, 8u32),
+    feature(625u32, 52u32, false, 1u32),
+    feature(626u32, 625u32, false, 1u32),
+    feature(627u32, 300u32, false, 48u32),
+    feature(673u32, 675u32, true, 1u32),
+    feature(675u32, 677u32, false, 0u32),
+    feature(675u32, 677u32, false, 12u32),
+    feature(677u32, 0u32, false, 0u32),
+    feature(677u32, 0u32, true, 0u32),
+    feature(677u32, 0u32, false, 1u32),
+    feature(677u32, 0u32, true, 1u32),
+    feature(678u32, 0u32, false, 0u32),
+    feature(678u32, 0u32, false, 94u32),
+    feature(679u32, 0u32, false, 0u32),
+    feature(679u32, 0u32, true, 0u32),
+    feature(679u32, 0u32, false, 94u32),
+    feature(679u32, 0u32, true, 94u32),
+    feature(680u32, 0u32, false, 0u32),
+    feature(680u32, 0u32, false, 94u32),
+    feature(687u32, 465u32, false, 1u32),
+    feature(688u32, 465u32, false, 1u32),
+    feature(689u32, 465u32, false, 1u32),
+    feature(689u32, 465u32, true, 1u32),
+    feature(690u32, 465u32, false, 95u32),
+    feature(691u32, 465u32, false, 95u32),
+    feature(692u32, 465u32, false, 1u32),
+    feature(692u32, 465u32, true, 1u32),
+    feature(694u32, 465u32, false, 1u32),
+    feature(695u32, 465u32, false, 1u32),
+    feature(696u32, 0u32, false, 0u32),
+    feature(696u32, 0u32, true, 0u32),
+    feature(696u32, 0u32, false, 1u32),
+    feature(696u32, 0u32, true, 1u32),
+    feature(697u32, 0u32, false, 0u32),
+    feature(697u32, 0u32, true, 0u32),
+    feature(697u32, 0u32, false, 1u32),
+    feature(697u32, 0u32, true, 1u32),
+    feature(698u32, 0u32, false, 0u32),
+    feature(698u32, 0u32, false, 1u32),
+    feature(699u32, 0u32, false, 0u32),
+    feature(699u32, 0u32, false, 1u32),
+    feature(700u32, 0u32, false, 0u32),
+    feature(700u32, 0u32, false, 1u32),
+    feature(701u32, 0u32, false, 0u32),
+    feature(701u32, 0u32, false, 1u32),
+    feature(702u32, 0u32, false, 0u32),
+    feature(702u32, 0u32, true, 0u32),
+    feature(702u32, 0u32, false, 1u32),
+    feature(702u32, 0u32, true, 1u32),
+    feature(703u32, 465u32, false, 0u32),
+    feature(703u32, 465u32, false, 100u32),
+    feature(704u32, 465u32, false, 0u32),
+    feature(704u32, 465u32, false, 99u32),
+    feature(705u32, 465u32, false, 0u32),
+    feature(705u32, 465u32, false, 33u32),
+    feature(706u32, 465u32, false, 0u32),
+    feature(706u32, 465u32, false, 1u32),
+    feature(707u32, 465u32, false, 0u32),
+    feature(707u32, 465u32, false, 101u32),
+    feature(708u32, 465u32, false, 0u32),
+    feature(708u32, 465u32, false, 100u32),
+    feature(714u32, 0u32, false, 0u32),
+    feature(714u32, 0u32, false, 102u32),
+    feature(715u32, 0u32, false, 0u32),
+    feature(715u32, 0u32, false, 97u32),
+    feature(716u32, 0u32, false, 0u32),
+    feature(716u32, 0u32, false, 1u32),
+    feature(717u32, 0u32, false, 0u32),
+    feature(717u32, 0u32, false, 1u32),
+    feature(718u32, 0u32, false, 0u32),
+    feature(718u32, 0u32, false, 1u32),
+    feature(719u32, 0u32, false, 0u32),
+    feature(719u32, 0u32, false, 1u32),
+    feature(720u32, 0u32, false, 0u32),
+    feature(720u32, 0u32, false, 98u32),
+    feature(721u32, 0u32, false, 0u32),
+    feature(721u32, 0u32, false, 1u32),
+    feature(722u32, 0u32, false, 0u32),
+    feature(722u32, 0u32, false, 1u32),
+    feature(723u32, 0u32, false, 0u32),
+    feature(723u32, 0u32, false, 1u32),
+];
+static FEATURE_LISTS: [&[u32]; 204usize] = [
+    &[
+        33u32, 106u32, 116u32, 133u32, 155u32, 164u32, 170u32, 174u32, 218u32, 220u32,
+        224u32, 230u32, 234u32, 258u32, 295u32, 325u32, 339u32, 357u32, 361u32, 399u32,
+        407u32, 422u32, 426u32, 430u32, 439u32, 466u32, 469u32, 477u32, 481u32, 486u32,
+        511u32, 518u32, 524u32, 534u32, 552u32, 555u32, 587u32, 590u32, 592u32, 594u32,
+        596u32, 598u32, 612u32, 654u32, 658u32, 662u32, 666u32, 669u32, 671u32, 673u32,
+        718u32,
+    ],
+    &[
+        33u32, 106u32, 116u32, 132u32, 154u32, 163u32, 169u32, 173u32, 218u32, 220u32,
+        223u32, 229u32, 233u32, 257u32, 294u32, 325u32, 338u32, 356u32, 360u32, 399u32,
+        407u32, 421u32, 425u32, 429u32, 439u32, 466u32, 468u32, 476u32, 480u32, 485u32,
+        510u32, 518u32, 523u32, 534u32, 552u32, 554u32, 587u32, 590u32, 592u32, 594u32,
+        596u32, 598u32, 612u32, 653u32, 657u32, 661u32, 665u32, 669u32, 671u32, 673u32,
+        717u32,
+    ],
+    &[
+        33u32, 106u32, 116u32, 133u32, 154u32, 163u32, 170u32, 173u32, 218u32, 220u32,
+        224u32, 229u32, 233u32, 258u32, 294u32, 325u32, 339u32, 357u32, 361u32, 399u32,
+        407u32, 422u32, 426u32, 430u32, 439u32, 466u32, 469u32, 477u32, 480u32, 486u32,
+        510u32, 518u32, 523u32, 534u32, 552u32, 554u32, 587u32, 590u32, 592u32, 594u32,
+        596u32, 598u32, 612u32, 654u32, 658u32, 662u32, 665u32, 669u32, 671u32, 673u32,
+        718u32,
+    ],
+    &[
+        32u32, 106u32, 116u32, 132u32, 154u32, 163u32, 169u32, 173u32, 218u32, 220u32,
+        223u32, 229u32, 233u32, 257u32, 294u32, 325u32, 338u32, 356u32, 360u32, 399u32,
+        407u32, 421u32, 425u32, 429u32, 438u32, 466u32, 468u32, 476u32, 480u32, 485u32,
+        510u32, 517u32, 523u32, 534u32, 552u32, 554u32, 587u32, 590u32, 592u32, 594u32,
+        596u32, 598u32, 612u32, 653u32, 657u32, 661u32, 665u32, 669u32, 671u32, 673u32,
+        717u32,
+    ],
+    &[
+        32u32, 106u32, 116u32, 132u32, 154u32, 163u32, 169u32, 173u32, 218u32, 220u32,
+        223u32, 229u32, 233u32, 257u32, 294u32, 325u32, 338u32, 356u32, 360u32, 399u32,
+        406u32, 421u32, 425u32, 429u32, 438u32, 466u32, 468u32, 476u32, 480u32, 485u32,
+        510u32, 517u32, 523u32, 534u32, 552u32, 554u32, 587u32, 590u32, 592u32, 594u32,
+        596u32, 598u32, 612u32, 653u32, 657u32, 661u32, 665u32, 669u32, 671u32, 673u32,
+        717u32,
+    ],
+    &[
+        26u32, 37u32, 136u32, 149u32, 167u32, 177u32, 255u32, 327u32, 382u32, 410u32,
+        472u32, 521u32, 604u32, 608u32, 617u32, 630u32, 633u32, 637u32, 641u32, 645u32,
+        649u32, 705u32, 709u32, 713u32, 723u32,
+    ],
+    &[
+        26u32, 37u32, 136u32, 149u32, 167u32, 177u32, 255u32, 327u32, 382u32, 410u32,
+        472u32, 521u32, 604u32, 608u32, 617u32, 630u32, 634u32, 637u32, 641u32, 645u32,
+        649u32, 705u32, 709u32, 713u32, 723u32,
+    ],
+    &[
+        26u32, 37u32, 136u32, 149u32, 167u32, 177u32, 255u32, 327u32, 382u32, 410u32,
+        472u32, 521u32, 604u32, 608u32, 617u32, 630u32, 634u32, 637u32, 641u32, 645u32,
+        649u32, 706u32, 709u32, 713u32, 723u32,
+    ],
+    &[
+        26u32, 37u32, 137u32, 149u32, 167u32, 177u32, 255u32, 327u32, 382u32, 410u32,
+        472u32, 521u32, 604u32, 609u32, 617u32, 630u32, 634u32, 638u32, 642u32, 646u32,
+        650u32, 705u32, 709u32, 713u32, 723u32,
+    ],
+    &[
+        26u32, 37u32, 136u32, 149u32, 167u32, 178u32, 255u32, 327u32, 382u32, 410u32,
+        473u32, 521u32, 604u32, 609u32, 617u32, 630u32, 634u32, 638u32, 642u32, 646u32,
+        649u32, 705u32, 709u32, 713u32, 723u32,
+    ],
+    &[
+        26u32, 37u32, 136u32, 149u32, 167u32, 178u32, 255u32, 327u32, 382u32, 410u32,
+        473u32, 521u32, 604u32, 609u32, 617u32, 630u32, 634u32, 638u32, 642u32, 646u32,
+        649u32, 706u32, 709u32, 713u32, 723u32,
+    ],
+    &[
+        26u32, 37u32, 136u32, 149u32, 167u32, 177u32, 255u32, 327u32, 382u32, 410u32,
+        472u32, 521u32, 604u32, 608u32, 617u32, 629u32, 633u32, 637u32, 641u32, 645u32,
+        649u32, 705u32, 709u32, 713u32, 723u32,
+    ],
+    &[
+        26u32, 37u32, 136u32, 149u32, 167u32, 178u32, 255u32, 327u32, 382u32, 410u32,
+        472u32, 521u32, 604u32, 608u32, 617u32, 630u32, 634u32, 637u32, 641u32, 645u32,
+        649u32, 706u32, 709u32, 713u32, 723u32,
+    ],
+    &[
+        26u32, 37u32, 136u32, 149u32, 167u32, 177u32, 255u32, 327u32, 382u32, 410u32,
+        472u32, 521u32, 604u32, 608u32, 617u32, 630u32, 634u32, 638u32, 641u32, 645u32,
+        649u32, 706u32, 709u32, 713u32, 723u32,
+    ],
+    &[
+        27u32, 37u32, 136u32, 149u32, 167u32, 178u32, 255u32, 327u32, 382u32, 410u32,
+        472u32, 521u32, 605u32, 609u32, 617u32, 630u32, 634u32, 638u32, 642u32, 646u32,
+        649u32, 706u32, 709u32, 713u32, 723u32,
+    ],
+    &[
+        27u32, 37u32, 136u32, 150u32, 167u32, 178u32, 255u32, 327u32, 382u32, 411u32,
+        472u32, 521u32, 605u32, 609u32, 618u32, 630u32, 634u32, 638u32, 642u32, 646u32,
+        649u32, 706u32, 710u32, 713u32, 723u32,
+    ],
+    &[
+        27u32, 37u32, 136u32, 149u32, 167u32, 178u32, 255u32, 327u32, 382u32, 410u32,
+        472u32, 521u32, 604u32, 609u32, 617u32, 630u32, 634u32, 638u32, 642u32, 646u32,
+        649u32, 706u32, 709u32, 713u32, 723u32,
+    ],
+    &[
+        27u32, 37u32, 136u32, 150u32, 167u32, 178u32, 255u32, 327u32, 382u32, 410u32,
+        472u32, 521u32, 604u32, 609u32, 617u32, 630u32, 634u32, 638u32, 642u32, 646u32,
+        649u32, 706u32, 710u32, 713u32, 723u32,
+    ],
+    &[
+        27u32, 37u32, 136u32, 150u32, 167u32, 178u32, 255u32, 327u32, 382u32, 411u32,
+        472u32, 521u32, 604u32, 609u32, 618u32, 630u32, 634u32, 638u32, 642u32, 646u32,
+        649u32, 706u32, 710u32, 714u32, 723u32,
+    ],
+    &[
+        27u32, 37u32, 136u32, 150u32, 167u32, 178u32, 255u32, 327u32, 382u32, 411u32,
+        472u32, 521u32, 604u32, 609u32, 617u32, 630u32, 634u32, 638u32, 642u32, 646u32,
+        649u32, 706u32, 710u32, 714u32, 723u32,
+    ],
+    &[
+        26u32, 37u32, 137u32, 149u32, 167u32, 178u32, 255u32, 327u32, 382u32, 410u32,
+        473u32, 521u32, 604u32, 609u32, 617u32, 630u32, 634u32, 638u32, 642u32, 646u32,
+        650u32, 705u32, 709u32, 713u32, 724u32,
+    ],
+    &[],
+    &[43u32],
+    &[
+        0u32, 4u32, 6u32, 10u32, 14u32, 18u32, 20u32, 126u32, 159u32, 181u32, 183u32,
+        188u32, 190u32, 193u32, 195u32, 227u32, 239u32, 241u32, 243u32, 245u32, 249u32,
+        272u32, 274u32, 276u32, 280u32, 284u32, 288u32, 305u32, 309u32, 313u32, 316u32,
+        323u32, 391u32, 393u32, 419u32, 615u32, 689u32, 693u32, 695u32,
+    ],
+    &[
+        1u32, 4u32, 7u32, 11u32, 15u32, 18u32, 20u32, 126u32, 160u32, 181u32, 183u32,
+        188u32, 190u32, 193u32, 195u32, 227u32, 239u32, 241u32, 243u32, 246u32, 249u32,
+        272u32, 274u32, 277u32, 281u32, 285u32, 289u32, 306u32, 310u32, 313u32, 316u32,
+        323u32, 391u32, 393u32, 419u32, 615u32, 690u32, 693u32, 696u32,
+    ],
+    &[319u32, 321u32],
+    &[
+        30u32, 32u32, 45u32, 47u32, 49u32, 51u32, 53u32, 59u32, 63u32, 67u32, 69u32,
+        71u32, 73u32, 75u32, 77u32, 79u32, 81u32, 83u32, 85u32, 87u32, 89u32, 91u32,
+        93u32, 95u32, 97u32, 99u32, 101u32, 103u32, 108u32, 112u32, 129u32, 198u32,
+        214u32, 251u32, 299u32, 302u32, 344u32, 374u32, 387u32, 436u32, 442u32, 462u32,
+        489u32, 493u32, 508u32, 515u32, 559u32, 563u32, 567u32, 571u32, 574u32, 578u32,
+        581u32, 602u32, 687u32, 727u32, 737u32, 739u32, 743u32, 745u32, 749u32,
+    ],
+    &[
+        30u32, 32u32, 45u32, 47u32, 49u32, 51u32, 53u32, 59u32, 63u32, 67u32, 69u32,
+        71u32, 73u32, 75u32, 77u32, 79u32, 81u32, 83u32, 85u32, 87u32, 89u32, 91u32,
+        93u32, 95u32, 97u32, 99u32, 101u32, 103u32, 108u32, 112u32, 128u32, 198u32,
+        214u32, 251u32, 299u32, 302u32, 343u32, 374u32, 387u32, 436u32, 442u32, 462u32,
+        489u32, 493u32, 508u32, 515u32, 559u32, 563u32, 566u32, 570u32, 574u32, 578u32,
+        580u32, 602u32, 687u32, 727u32, 737u32, 739u32, 743u32, 745u32, 749u32,
+    ],
+    &[
+        30u32, 32u32, 45u32, 47u32, 49u32, 51u32, 53u32, 59u32, 63u32, 67u32, 69u32,
+        71u32, 73u32, 75u32, 77u32, 79u32, 81u32, 83u32, 85u32, 87u32, 89u32, 91u32,
+        93u32, 95u32, 97u32, 99u32, 101u32, 103u32, 108u32, 112u32, 128u32, 198u32,
+        214u32, 251u32, 298u32, 302u32, 343u32, 374u32, 387u32, 436u32, 442u32, 462u32,
+        489u32, 493u32, 508u32, 515u32, 558u32, 562u32, 566u32, 570u32, 574u32, 578u32,
+        580u32, 602u32, 687u32, 727u32, 737u32, 739u32, 743u32, 745u32, 749u32,
+    ],
+    &[
+        30u32, 32u32, 45u32, 47u32, 49u32, 51u32, 53u32, 59u32, 63u32, 67u32, 69u32,
+        71u32, 73u32, 75u32, 77u32, 79u32, 81u32, 83u32, 85u32, 87u32, 89u32, 91u32,
+        93u32, 95u32, 97u32, 99u32, 101u32, 103u32, 108u32, 112u32, 128u32, 198u32,
+        214u32, 251u32, 298u32, 302u32, 343u32, 374u32, 387u32, 436u32, 442u32, 462u32,
+        489u32, 493u32, 508u32, 515u32, 559u32, 563u32, 567u32, 570u32, 574u32, 578u32,
+        581u32, 602u32, 687u32, 727u32, 737u32, 739u32, 743u32, 745u32, 749u32,
+    ],
+    &[
+        30u32, 32u32, 45u32, 47u32, 49u32, 51u32, 53u32, 59u32, 63u32, 67u32, 69u32,
+        71u32, 73u32, 75u32, 77u32, 79u32, 81u32, 83u32, 85u32, 87u32, 89u32, 91u32,
+        93u32, 95u32, 97u32, 99u32, 101u32, 103u32, 108u32, 112u32, 128u32, 198u32,
+        214u32, 251u32, 299u32, 302u32, 343u32, 374u32, 387u32, 436u32, 442u32, 462u32,
+        489u32, 493u32, 508u32, 515u32, 558u32, 562u32, 566u32, 570u32, 574u32, 578u32,
+        580u32, 602u32, 687u32, 727u32, 737u32, 739u32, 743u32, 745u32, 749u32,
+    ],
+    &[142u32, 207u32, 292u32, 350u32, 352u32, 368u32, 372u32, 500u32, 621u32],
+    &[141u32, 206u32, 292u32, 349u32, 352u32, 367u32, 372u32, 500u32, 621u32],
+    &[262u32, 395u32, 721u32],
+    &[263u32, 395u32, 721u32],
+    &[39u32, 446u32, 448u32, 454u32, 458u32, 460u32, 729u32],
+    &[40u32, 446u32, 448u32, 454u32, 458u32, 460u32, 730u32],
+    &[40u32, 446u32, 448u32, 454u32, 458u32, 460u32, 729u32],
+    &[40u32, 446u32, 449u32, 455u32, 458u32, 460u32, 730u32],
+    &[
+        23u32, 123u32, 146u32, 185u32, 211u32, 379u32, 502u32, 623u32, 625u32, 761u32,
+        765u32, 769u32, 771u32, 773u32, 775u32, 777u32, 781u32, 783u32, 785u32, 787u32,
+        789u32, 791u32, 793u32, 795u32, 797u32, 799u32, 801u32, 803u32, 805u32, 807u32,
+        809u32, 811u32,
+    ],
+    &[
+        22u32, 122u32, 145u32, 185u32, 210u32, 378u32, 502u32, 623u32, 625u32, 761u32,
+        765u32, 769u32, 771u32, 773u32, 775u32, 777u32, 781u32, 783u32, 785u32, 787u32,
+        789u32, 791u32, 793u32, 795u32, 797u32, 799u32, 801u32, 803u32, 805u32, 807u32,
+        809u32, 811u32,
+    ],
+    &[
+        22u32, 122u32, 145u32, 185u32, 210u32, 379u32, 502u32, 623u32, 625u32, 761u32,
+        765u32, 769u32, 771u32, 773u32, 775u32, 777u32, 781u32, 783u32, 785u32, 787u32,
+        789u32, 791u32, 793u32, 795u32, 797u32, 799u32, 801u32, 803u32, 805u32, 807u32,
+        809u32, 811u32,
+    ],
+    &[
+        23u32, 122u32, 145u32, 185u32, 210u32, 379u32, 502u32, 623u32, 625u32, 761u32,
+        765u32, 769u32, 771u32, 773u32, 775u32, 777u32, 781u32, 783u32, 785u32, 787u32,
+        789u32, 791u32, 793u32, 795u32, 797u32, 799u32, 801u32, 803u32, 805u32, 807u32,
+        809u32, 811u32,
+    ],
+    &[
+        23u32, 123u32, 145u32, 185u32, 210u32, 379u32, 502u32, 623u32, 625u32, 761u32,
+        765u32, 769u32, 771u32, 773u32, 775u32, 777u32, 781u32, 783u32, 785u32, 787u32,
+        789u32, 791u32, 793u32, 795u32, 797u32, 799u32, 801u32, 803u32, 805u32, 807u32,
+        809u32, 811u32,
+    ],
+    &[
+        23u32, 123u32, 145u32, 185u32, 211u32, 379u32, 502u32, 623u32, 625u32, 761u32,
+        765u32, 769u32, 771u32, 773u32, 775u32, 777u32, 781u32, 783u32, 785u32, 787u32,
+        789u32, 791u32, 793u32, 795u32, 797u32, 799u32, 801u32, 803u32, 805u32, 807u32,
+        809u32, 811u32,
+    ],
+    &[
+        22u32, 123u32, 145u32, 185u32, 210u32, 379u32, 502u32, 623u32, 625u32, 761u32,
+        765u32, 769u32, 771u32, 773u32, 775u32, 777u32, 781u32, 783u32, 785u32, 787u32,
+        789u32, 791u32, 793u32, 795u32, 797u32, 799u32, 801u32, 803u32, 805u32, 807u32,
+        809u32, 811u32,
+    ],
+    &[
+        23u32, 123u32, 146u32, 185u32, 211u32, 379u32, 502u32, 623u32, 626u32, 762u32,
+        766u32, 769u32, 771u32, 773u32, 775u32, 778u32, 781u32, 783u32, 785u32, 787u32,
+        789u32, 791u32, 793u32, 795u32, 797u32, 799u32, 801u32, 803u32, 805u32, 807u32,
+        809u32, 811u32,
+    ],
+    &[
+        26u32, 37u32, 136u32, 149u32, 167u32, 177u32, 255u32, 327u32, 382u32, 410u32,
+        472u32, 521u32, 605u32, 608u32, 617u32, 629u32, 633u32, 637u32, 641u32, 645u32,
+        649u32, 705u32, 709u32, 713u32, 723u32,
+    ],
+    &[
+        26u32, 37u32, 136u32, 149u32, 167u32, 177u32, 255u32, 327u32, 382u32, 410u32,
+        472u32, 521u32, 605u32, 608u32, 617u32, 630u32, 633u32, 637u32, 641u32, 645u32,
+        649u32, 705u32, 709u32, 713u32, 723u32,
+    ],
+    &[
+        26u32, 37u32, 136u32, 149u32, 167u32, 177u32, 255u32, 327u32, 383u32, 410u32,
+        472u32, 521u32, 605u32, 608u32, 617u32, 630u32, 634u32, 637u32, 641u32, 645u32,
+        649u32, 705u32, 709u32, 713u32, 723u32,
+    ],
+    &[
+        27u32, 37u32, 136u32, 150u32, 167u32, 178u32, 255u32, 327u32, 382u32, 411u32,
+        472u32, 521u32, 605u32, 609u32, 617u32, 630u32, 634u32, 638u32, 642u32, 646u32,
+        649u32, 706u32, 710u32, 713u32, 723u32,
+    ],
+    &[
+        26u32, 37u32, 136u32, 149u32, 167u32, 178u32, 255u32, 327u32, 383u32, 410u32,
+        472u32, 521u32, 605u32, 609u32, 617u32, 630u32, 634u32, 638u32, 642u32, 646u32,
+        649u32, 705u32, 709u32, 713u32, 723u32,
+    ],
+    &[
+        26u32, 37u32, 136u32, 149u32, 167u32, 177u32, 255u32, 327u32, 383u32, 410u32,
+        472u32, 521u32, 605u32, 609u32, 617u32, 630u32, 634u32, 638u32, 642u32, 646u32,
+        649u32, 705u32, 709u32, 713u32, 723u32,
+    ],
+    &[
+        55u32, 118u32, 202u32, 204u32, 403u32, 404u32, 415u32, 499u32, 505u32, 528u32,
+        531u32,
+    ],
+    &[
+        56u32, 119u32, 202u32, 204u32, 403u32, 404u32, 415u32, 499u32, 505u32, 528u32,
+        531u32,
+    ],
+    &[
+        55u32, 119u32, 202u32, 204u32, 403u32, 404u32, 416u32, 499u32, 505u32, 528u32,
+        531u32,
+    ],
+    &[
+        30u32, 32u32, 45u32, 47u32, 49u32, 51u32, 53u32, 59u32, 63u32, 67u32, 69u32,
+        71u32, 73u32, 75u32, 77u32, 79u32, 81u32, 83u32, 85u32, 87u32, 89u32, 91u32,
+        93u32, 95u32, 97u32, 99u32, 101u32, 103u32, 108u32, 112u32, 128u32, 198u32,
+        214u32, 251u32, 299u32, 302u32, 343u32, 374u32, 387u32, 436u32, 442u32, 462u32,
+        490u32, 494u32, 508u32, 515u32, 559u32, 563u32, 566u32, 570u32, 574u32, 578u32,
+        580u32, 602u32, 687u32, 727u32, 737u32, 739u32, 743u32, 745u32, 749u32,
+    ],
+    &[
+        30u32, 32u32, 45u32, 47u32, 49u32, 51u32, 53u32, 59u32, 63u32, 67u32, 69u32,
+        71u32, 73u32, 75u32, 77u32, 79u32, 81u32, 83u32, 85u32, 87u32, 89u32, 91u32,
+        93u32, 95u32, 97u32, 99u32, 101u32, 103u32, 108u32, 112u32, 128u32, 198u32,
+        214u32, 251u32, 299u32, 302u32, 343u32, 374u32, 387u32, 436u32, 443u32, 462u32,
+        489u32, 493u32, 508u32, 515u32, 559u32, 563u32, 567u32, 571u32, 574u32, 578u32,
+        581u32, 602u32, 687u32, 727u32, 737u32, 739u32, 743u32, 745u32, 749u32,
+    ],
+    &[
+        30u32, 32u32, 45u32, 47u32, 49u32, 51u32, 53u32, 59u32, 63u32, 67u32, 69u32,
+        71u32, 73u32, 75u32, 77u32, 79u32, 81u32, 83u32, 85u32, 87u32, 89u32, 91u32,
+        93u32, 95u32, 97u32, 99u32, 101u32, 103u32, 108u32, 112u32, 129u32, 198u32,
+        214u32, 251u32, 299u32, 302u32, 344u32, 374u32, 387u32, 436u32, 442u32, 462u32,
+        489u32, 493u32, 508u32, 515u32, 559u32, 563u32, 567u32, 570u32, 574u32, 578u32,
+        580u32, 602u32, 687u32, 727u32, 737u32, 739u32, 743u32, 745u32, 749u32,
+    ],
+    &[
+        30u32, 32u32, 45u32, 47u32, 49u32, 51u32, 53u32, 60u32, 64u32, 67u32, 69u32,
+        71u32, 73u32, 75u32, 77u32, 79u32, 81u32, 83u32, 85u32, 87u32, 89u32, 91u32,
+        93u32, 95u32, 97u32, 99u32, 101u32, 103u32, 109u32, 113u32, 129u32, 199u32,
+        215u32, 252u32, 299u32, 302u32, 344u32, 375u32, 388u32, 436u32, 443u32, 462u32,
+        489u32, 493u32, 508u32, 515u32, 559u32, 563u32, 567u32, 571u32, 575u32, 578u32,
+        581u32, 602u32, 687u32, 727u32, 737u32, 740u32, 743u32, 746u32, 749u32,
+    ],
+    &[
+        35u32, 107u32, 117u32, 135u32, 140u32, 157u32, 166u32, 172u32, 176u32, 192u32,
+        219u32, 221u32, 222u32, 226u32, 232u32, 236u32, 238u32, 260u32, 266u32, 267u32,
+        268u32, 269u32, 297u32, 315u32, 326u32, 341u32, 359u32, 363u32, 364u32, 366u32,
+        371u32, 386u32, 400u32, 409u32, 424u32, 428u32, 432u32, 435u32, 441u32, 467u32,
+        471u32, 479u32, 483u32, 484u32, 488u32, 506u32, 513u32, 520u32, 526u32, 535u32,
+        537u32, 538u32, 539u32, 540u32, 541u32, 542u32, 543u32, 544u32, 545u32, 546u32,
+        547u32, 553u32, 557u32, 584u32, 585u32, 586u32, 588u32, 589u32, 591u32, 593u32,
+        595u32, 597u32, 599u32, 600u32, 613u32, 656u32, 660u32, 664u32, 668u32, 670u32,
+        672u32, 674u32, 675u32, 676u32, 681u32, 682u32, 683u32, 684u32, 685u32, 686u32,
+        720u32, 733u32,
+    ],
+    &[
+        35u32, 107u32, 117u32, 134u32, 140u32, 156u32, 165u32, 171u32, 175u32, 192u32,
+        219u32, 221u32, 222u32, 225u32, 231u32, 235u32, 237u32, 259u32, 266u32, 267u32,
+        268u32, 269u32, 296u32, 315u32, 326u32, 340u32, 358u32, 362u32, 364u32, 365u32,
+        371u32, 386u32, 400u32, 409u32, 423u32, 427u32, 431u32, 435u32, 441u32, 467u32,
+        470u32, 478u32, 482u32, 484u32, 487u32, 506u32, 512u32, 520u32, 525u32, 535u32,
+        537u32, 538u32, 539u32, 540u32, 541u32, 542u32, 543u32, 544u32, 545u32, 546u32,
+        547u32, 553u32, 556u32, 584u32, 585u32, 586u32, 588u32, 589u32, 591u32, 593u32,
+        595u32, 597u32, 599u32, 600u32, 613u32, 655u32, 659u32, 663u32, 667u32, 670u32,
+        672u32, 674u32, 675u32, 676u32, 681u32, 682u32, 683u32, 684u32, 685u32, 686u32,
+        719u32, 733u32,
+    ],
+    &[
+        35u32, 107u32, 117u32, 135u32, 140u32, 156u32, 165u32, 172u32, 175u32, 192u32,
+        219u32, 221u32, 222u32, 226u32, 231u32, 235u32, 237u32, 260u32, 266u32, 267u32,
+        268u32, 269u32, 296u32, 315u32, 326u32, 341u32, 359u32, 363u32, 364u32, 365u32,
+        371u32, 386u32, 400u32, 409u32, 424u32, 428u32, 432u32, 435u32, 441u32, 467u32,
+        471u32, 479u32, 482u32, 484u32, 488u32, 506u32, 512u32, 520u32, 525u32, 535u32,
+        537u32, 538u32, 539u32, 540u32, 541u32, 542u32, 543u32, 544u32, 545u32, 546u32,
+        547u32, 553u32, 556u32, 584u32, 585u32, 586u32, 588u32, 589u32, 591u32, 593u32,
+        595u32, 597u32, 599u32, 600u32, 613u32, 656u32, 660u32, 664u32, 667u32, 670u32,
+        672u32, 674u32, 675u32, 676u32, 681u32, 682u32, 683u32, 684u32, 685u32, 686u32,
+        720u32, 733u32,
+    ],
+    &[
+        34u32, 107u32, 117u32, 134u32, 140u32, 156u32, 165u32, 171u32, 175u32, 192u32,
+        219u32, 221u32, 222u32, 225u32, 231u32, 235u32, 237u32, 259u32, 266u32, 267u32,
+        268u32, 269u32, 296u32, 315u32, 326u32, 340u32, 358u32, 362u32, 364u32, 365u32,
+        371u32, 386u32, 400u32, 409u32, 423u32, 427u32, 431u32, 435u32, 440u32, 467u32,
+        470u32, 478u32, 482u32, 484u32, 487u32, 506u32, 512u32, 519u32, 525u32, 535u32,
+        537u32, 538u32, 539u32, 540u32, 541u32, 542u32, 543u32, 544u32, 545u32, 546u32,
+        547u32, 553u32, 556u32, 584u32, 585u32, 586u32, 588u32, 589u32, 591u32, 593u32,
+        595u32, 597u32, 599u32, 600u32, 613u32, 655u32, 659u32, 663u32, 667u32, 670u32,
+        672u32, 674u32, 675u32, 676u32, 681u32, 682u32, 683u32, 684u32, 685u32, 686u32,
+        719u32, 733u32,
+    ],
+    &[
+        34u32, 107u32, 117u32, 134u32, 140u32, 156u32, 165u32, 171u32, 175u32, 192u32,
+        219u32, 221u32, 222u32, 225u32, 231u32, 235u32, 237u32, 259u32, 266u32, 267u32,
+        268u32, 269u32, 296u32, 315u32, 326u32, 340u32, 358u32, 362u32, 364u32, 365u32,
+        371u32, 386u32, 400u32, 408u32, 423u32, 427u32, 431u32, 435u32, 440u32, 467u32,
+        470u32, 478u32, 482u32, 484u32, 487u32, 506u32, 512u32, 519u32, 525u32, 535u32,
+        537u32, 538u32, 539u32, 540u32, 541u32, 542u32, 543u32, 544u32, 545u32, 546u32,
+        547u32, 553u32, 556u32, 584u32, 585u32, 586u32, 588u32, 589u32, 591u32, 593u32,
+        595u32, 597u32, 599u32, 600u32, 613u32, 655u32, 659u32, 663u32, 667u32, 670u32,
+        672u32, 674u32, 675u32, 676u32, 681u32, 682u32, 683u32, 684u32, 685u32, 686u32,
+        719u32, 733u32,
+    ],
+    &[
+        34u32, 107u32, 117u32, 134u32, 140u32, 156u32, 165u32, 171u32, 175u32, 192u32,
+        219u32, 221u32, 222u32, 225u32, 231u32, 235u32, 237u32, 259u32, 266u32, 267u32,
+        268u32, 269u32, 296u32, 315u32, 326u32, 340u32, 358u32, 362u32, 364u32, 365u32,
+        371u32, 386u32, 400u32, 409u32, 423u32, 427u32, 431u32, 435u32, 440u32, 467u32,
+        470u32, 478u32, 482u32, 484u32, 487u32, 507u32, 512u32, 519u32, 525u32, 535u32,
+        537u32, 538u32, 539u32, 540u32, 541u32, 542u32, 543u32, 544u32, 545u32, 546u32,
+        547u32, 553u32, 556u32, 584u32, 585u32, 586u32, 588u32, 589u32, 591u32, 593u32,
+        595u32, 597u32, 599u32, 600u32, 613u32, 655u32, 659u32, 663u32, 667u32, 670u32,
+        672u32, 674u32, 675u32, 676u32, 681u32, 682u32, 683u32, 684u32, 685u32, 686u32,
+        719u32, 733u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 179u32, 256u32, 328u32, 384u32, 412u32,
+        474u32, 522u32, 606u32, 610u32, 619u32, 632u32, 635u32, 639u32, 643u32, 647u32,
+        651u32, 707u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 179u32, 256u32, 328u32, 384u32, 412u32,
+        474u32, 522u32, 606u32, 610u32, 619u32, 632u32, 636u32, 639u32, 643u32, 647u32,
+        651u32, 707u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 179u32, 256u32, 328u32, 384u32, 412u32,
+        474u32, 522u32, 606u32, 610u32, 619u32, 632u32, 636u32, 639u32, 643u32, 647u32,
+        651u32, 708u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 139u32, 151u32, 168u32, 179u32, 256u32, 328u32, 384u32, 412u32,
+        474u32, 522u32, 606u32, 611u32, 619u32, 632u32, 636u32, 640u32, 644u32, 648u32,
+        652u32, 707u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 180u32, 256u32, 328u32, 384u32, 412u32,
+        475u32, 522u32, 606u32, 611u32, 619u32, 632u32, 636u32, 640u32, 644u32, 648u32,
+        651u32, 707u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 180u32, 256u32, 328u32, 384u32, 412u32,
+        475u32, 522u32, 606u32, 611u32, 619u32, 632u32, 636u32, 640u32, 644u32, 648u32,
+        651u32, 708u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 179u32, 256u32, 270u32, 328u32, 384u32,
+        412u32, 474u32, 522u32, 606u32, 610u32, 619u32, 631u32, 635u32, 639u32, 643u32,
+        647u32, 651u32, 707u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 179u32, 256u32, 270u32, 328u32, 384u32,
+        412u32, 474u32, 522u32, 606u32, 610u32, 619u32, 632u32, 635u32, 639u32, 643u32,
+        647u32, 651u32, 707u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 180u32, 256u32, 328u32, 384u32, 412u32,
+        474u32, 522u32, 606u32, 610u32, 619u32, 632u32, 636u32, 639u32, 643u32, 647u32,
+        651u32, 708u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 179u32, 256u32, 328u32, 384u32, 412u32,
+        474u32, 522u32, 606u32, 610u32, 619u32, 632u32, 636u32, 640u32, 643u32, 647u32,
+        651u32, 708u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        29u32, 38u32, 138u32, 151u32, 168u32, 180u32, 256u32, 328u32, 384u32, 412u32,
+        474u32, 522u32, 607u32, 611u32, 619u32, 632u32, 636u32, 640u32, 644u32, 648u32,
+        651u32, 708u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        29u32, 38u32, 138u32, 152u32, 168u32, 180u32, 256u32, 328u32, 384u32, 413u32,
+        474u32, 522u32, 606u32, 611u32, 619u32, 632u32, 636u32, 640u32, 644u32, 648u32,
+        651u32, 708u32, 712u32, 715u32, 725u32,
+    ],
+    &[
+        29u32, 38u32, 138u32, 152u32, 168u32, 180u32, 256u32, 328u32, 384u32, 413u32,
+        474u32, 522u32, 607u32, 611u32, 620u32, 632u32, 636u32, 640u32, 644u32, 648u32,
+        651u32, 708u32, 712u32, 715u32, 725u32,
+    ],
+    &[
+        29u32, 38u32, 138u32, 151u32, 168u32, 180u32, 256u32, 328u32, 384u32, 412u32,
+        474u32, 522u32, 606u32, 611u32, 619u32, 632u32, 636u32, 640u32, 644u32, 648u32,
+        651u32, 708u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        29u32, 38u32, 138u32, 152u32, 168u32, 180u32, 256u32, 271u32, 328u32, 384u32,
+        412u32, 474u32, 522u32, 606u32, 611u32, 619u32, 632u32, 636u32, 640u32, 644u32,
+        648u32, 651u32, 708u32, 712u32, 715u32, 725u32,
+    ],
+    &[
+        29u32, 38u32, 138u32, 152u32, 168u32, 180u32, 256u32, 328u32, 384u32, 413u32,
+        474u32, 522u32, 606u32, 611u32, 620u32, 632u32, 636u32, 640u32, 644u32, 648u32,
+        651u32, 708u32, 712u32, 716u32, 725u32,
+    ],
+    &[
+        29u32, 38u32, 138u32, 152u32, 168u32, 180u32, 256u32, 328u32, 384u32, 413u32,
+        474u32, 522u32, 606u32, 611u32, 619u32, 632u32, 636u32, 640u32, 644u32, 648u32,
+        651u32, 708u32, 712u32, 716u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 139u32, 151u32, 168u32, 180u32, 256u32, 328u32, 384u32, 412u32,
+        475u32, 522u32, 606u32, 611u32, 619u32, 632u32, 636u32, 640u32, 644u32, 648u32,
+        652u32, 707u32, 711u32, 715u32, 726u32,
+    ],
+    &[44u32],
+    &[
+        2u32, 5u32, 8u32, 12u32, 16u32, 19u32, 21u32, 127u32, 161u32, 182u32, 184u32,
+        189u32, 191u32, 194u32, 196u32, 228u32, 240u32, 242u32, 244u32, 247u32, 250u32,
+        273u32, 275u32, 278u32, 282u32, 286u32, 290u32, 307u32, 311u32, 314u32, 317u32,
+        324u32, 392u32, 394u32, 420u32, 616u32, 691u32, 694u32, 697u32,
+    ],
+    &[
+        3u32, 5u32, 9u32, 13u32, 17u32, 19u32, 21u32, 127u32, 162u32, 182u32, 184u32,
+        189u32, 191u32, 194u32, 196u32, 228u32, 240u32, 242u32, 244u32, 248u32, 250u32,
+        273u32, 275u32, 279u32, 283u32, 287u32, 291u32, 308u32, 312u32, 314u32, 317u32,
+        324u32, 392u32, 394u32, 420u32, 616u32, 692u32, 694u32, 698u32,
+    ],
+    &[320u32, 322u32],
+    &[
+        31u32, 36u32, 46u32, 48u32, 50u32, 52u32, 54u32, 61u32, 65u32, 68u32, 70u32,
+        72u32, 74u32, 76u32, 78u32, 80u32, 82u32, 84u32, 86u32, 88u32, 90u32, 92u32,
+        94u32, 96u32, 98u32, 100u32, 102u32, 104u32, 110u32, 114u32, 131u32, 200u32,
+        216u32, 253u32, 301u32, 303u32, 346u32, 376u32, 389u32, 437u32, 444u32, 463u32,
+        491u32, 495u32, 509u32, 516u32, 527u32, 533u32, 536u32, 561u32, 565u32, 569u32,
+        573u32, 576u32, 579u32, 583u32, 603u32, 688u32, 728u32, 738u32, 741u32, 744u32,
+        747u32, 750u32,
+    ],
+    &[
+        31u32, 36u32, 46u32, 48u32, 50u32, 52u32, 54u32, 61u32, 65u32, 68u32, 70u32,
+        72u32, 74u32, 76u32, 78u32, 80u32, 82u32, 84u32, 86u32, 88u32, 90u32, 92u32,
+        94u32, 96u32, 98u32, 100u32, 102u32, 104u32, 110u32, 114u32, 130u32, 200u32,
+        216u32, 253u32, 301u32, 303u32, 345u32, 376u32, 389u32, 437u32, 444u32, 463u32,
+        491u32, 495u32, 509u32, 516u32, 527u32, 533u32, 536u32, 561u32, 565u32, 568u32,
+        572u32, 576u32, 579u32, 582u32, 603u32, 688u32, 728u32, 738u32, 741u32, 744u32,
+        747u32, 750u32,
+    ],
+    &[
+        31u32, 36u32, 46u32, 48u32, 50u32, 52u32, 54u32, 61u32, 65u32, 68u32, 70u32,
+        72u32, 74u32, 76u32, 78u32, 80u32, 82u32, 84u32, 86u32, 88u32, 90u32, 92u32,
+        94u32, 96u32, 98u32, 100u32, 102u32, 104u32, 110u32, 114u32, 130u32, 200u32,
+        216u32, 253u32, 300u32, 303u32, 345u32, 376u32, 389u32, 437u32, 444u32, 463u32,
+        491u32, 495u32, 509u32, 516u32, 527u32, 533u32, 536u32, 560u32, 564u32, 568u32,
+        572u32, 576u32, 579u32, 582u32, 603u32, 688u32, 728u32, 738u32, 741u32, 744u32,
+        747u32, 750u32,
+    ],
+    &[
+        31u32, 36u32, 46u32, 48u32, 50u32, 52u32, 54u32, 61u32, 65u32, 68u32, 70u32,
+        72u32, 74u32, 76u32, 78u32, 80u32, 82u32, 84u32, 86u32, 88u32, 90u32, 92u32,
+        94u32, 96u32, 98u32, 100u32, 102u32, 104u32, 110u32, 114u32, 130u32, 200u32,
+        216u32, 253u32, 300u32, 303u32, 345u32, 376u32, 389u32, 437u32, 444u32, 463u32,
+        491u32, 495u32, 509u32, 516u32, 527u32, 533u32, 536u32, 561u32, 565u32, 569u32,
+        572u32, 576u32, 579u32, 583u32, 603u32, 688u32, 728u32, 738u32, 741u32, 744u32,
+        747u32, 750u32,
+    ],
+    &[
+        31u32, 36u32, 46u32, 48u32, 50u32, 52u32, 54u32, 61u32, 65u32, 68u32, 70u32,
+        72u32, 74u32, 76u32, 78u32, 80u32, 82u32, 84u32, 86u32, 88u32, 90u32, 92u32,
+        94u32, 96u32, 98u32, 100u32, 102u32, 104u32, 110u32, 114u32, 130u32, 200u32,
+        216u32, 253u32, 301u32, 303u32, 345u32, 376u32, 389u32, 437u32, 444u32, 463u32,
+        491u32, 495u32, 509u32, 516u32, 527u32, 533u32, 536u32, 560u32, 564u32, 568u32,
+        572u32, 576u32, 579u32, 582u32, 603u32, 688u32, 728u32, 736u32, 738u32, 741u32,
+        744u32, 747u32, 750u32,
+    ],
+    &[144u32, 209u32, 293u32, 351u32, 353u32, 370u32, 373u32, 501u32, 622u32],
+    &[144u32, 209u32, 293u32, 351u32, 353u32, 369u32, 373u32, 501u32, 622u32],
+    &[143u32, 208u32, 293u32, 351u32, 353u32, 369u32, 373u32, 501u32, 622u32],
+    &[329u32, 330u32, 332u32, 333u32, 334u32, 335u32, 336u32, 337u32],
+    &[264u32, 396u32, 722u32],
+    &[265u32, 396u32, 722u32],
+    &[41u32, 433u32, 447u32, 450u32, 452u32, 456u32, 459u32, 461u32, 464u32, 731u32],
+    &[42u32, 433u32, 447u32, 450u32, 452u32, 456u32, 459u32, 461u32, 464u32, 732u32],
+    &[42u32, 433u32, 447u32, 450u32, 452u32, 456u32, 459u32, 461u32, 464u32, 731u32],
+    &[42u32, 434u32, 447u32, 451u32, 453u32, 457u32, 459u32, 461u32, 465u32, 732u32],
+    &[
+        25u32, 125u32, 148u32, 213u32, 381u32, 503u32, 624u32, 627u32, 753u32, 755u32,
+        757u32, 763u32, 767u32, 770u32, 772u32, 774u32, 776u32, 779u32, 782u32, 784u32,
+        786u32, 788u32, 790u32, 792u32, 794u32, 796u32, 798u32, 800u32, 802u32, 804u32,
+        806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        24u32, 124u32, 187u32, 212u32, 380u32, 503u32, 624u32, 627u32, 753u32, 755u32,
+        757u32, 763u32, 767u32, 770u32, 772u32, 774u32, 776u32, 779u32, 782u32, 784u32,
+        786u32, 788u32, 790u32, 792u32, 794u32, 796u32, 798u32, 800u32, 802u32, 804u32,
+        806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        24u32, 124u32, 187u32, 212u32, 381u32, 503u32, 624u32, 627u32, 753u32, 755u32,
+        757u32, 763u32, 767u32, 770u32, 772u32, 774u32, 776u32, 779u32, 782u32, 784u32,
+        786u32, 788u32, 790u32, 792u32, 794u32, 796u32, 798u32, 800u32, 802u32, 804u32,
+        806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        24u32, 125u32, 187u32, 212u32, 381u32, 503u32, 624u32, 627u32, 753u32, 755u32,
+        757u32, 763u32, 767u32, 770u32, 772u32, 774u32, 776u32, 779u32, 782u32, 784u32,
+        786u32, 788u32, 790u32, 792u32, 794u32, 796u32, 798u32, 800u32, 802u32, 804u32,
+        806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        24u32, 124u32, 147u32, 212u32, 380u32, 503u32, 624u32, 627u32, 753u32, 755u32,
+        757u32, 763u32, 767u32, 770u32, 772u32, 774u32, 776u32, 779u32, 782u32, 784u32,
+        786u32, 788u32, 790u32, 792u32, 794u32, 796u32, 798u32, 800u32, 802u32, 804u32,
+        806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        24u32, 124u32, 147u32, 212u32, 381u32, 503u32, 624u32, 627u32, 753u32, 755u32,
+        757u32, 763u32, 767u32, 770u32, 772u32, 774u32, 776u32, 779u32, 782u32, 784u32,
+        786u32, 788u32, 790u32, 792u32, 794u32, 796u32, 798u32, 800u32, 802u32, 804u32,
+        806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        25u32, 124u32, 147u32, 212u32, 381u32, 503u32, 624u32, 627u32, 753u32, 755u32,
+        757u32, 763u32, 767u32, 770u32, 772u32, 774u32, 776u32, 779u32, 782u32, 784u32,
+        786u32, 788u32, 790u32, 792u32, 794u32, 796u32, 798u32, 800u32, 802u32, 804u32,
+        806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        25u32, 125u32, 147u32, 212u32, 381u32, 503u32, 624u32, 627u32, 753u32, 755u32,
+        757u32, 763u32, 767u32, 770u32, 772u32, 774u32, 776u32, 779u32, 782u32, 784u32,
+        786u32, 788u32, 790u32, 792u32, 794u32, 796u32, 798u32, 800u32, 802u32, 804u32,
+        806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        25u32, 125u32, 147u32, 213u32, 381u32, 503u32, 624u32, 627u32, 753u32, 755u32,
+        757u32, 763u32, 767u32, 770u32, 772u32, 774u32, 776u32, 779u32, 782u32, 784u32,
+        786u32, 788u32, 790u32, 792u32, 794u32, 796u32, 798u32, 800u32, 802u32, 804u32,
+        806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        24u32, 125u32, 147u32, 212u32, 381u32, 503u32, 624u32, 627u32, 753u32, 755u32,
+        757u32, 763u32, 767u32, 770u32, 772u32, 774u32, 776u32, 779u32, 782u32, 784u32,
+        786u32, 788u32, 790u32, 792u32, 794u32, 796u32, 798u32, 800u32, 802u32, 804u32,
+        806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        25u32, 125u32, 148u32, 213u32, 381u32, 503u32, 624u32, 628u32, 753u32, 755u32,
+        757u32, 764u32, 768u32, 770u32, 772u32, 774u32, 776u32, 780u32, 782u32, 784u32,
+        786u32, 788u32, 790u32, 792u32, 794u32, 796u32, 798u32, 800u32, 802u32, 804u32,
+        806u32, 808u32, 810u32, 812u32,
+    ],
+    &[105u32, 699u32],
+    &[355u32, 678u32, 680u32],
+    &[355u32, 677u32, 679u32],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 179u32, 256u32, 270u32, 328u32, 384u32,
+        412u32, 474u32, 522u32, 607u32, 610u32, 619u32, 631u32, 635u32, 639u32, 643u32,
+        647u32, 651u32, 707u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 179u32, 256u32, 270u32, 328u32, 384u32,
+        412u32, 474u32, 522u32, 607u32, 610u32, 619u32, 632u32, 635u32, 639u32, 643u32,
+        647u32, 651u32, 707u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 179u32, 256u32, 328u32, 385u32, 412u32,
+        474u32, 522u32, 607u32, 610u32, 619u32, 632u32, 636u32, 639u32, 643u32, 647u32,
+        651u32, 707u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        29u32, 38u32, 138u32, 152u32, 168u32, 180u32, 256u32, 328u32, 384u32, 413u32,
+        474u32, 522u32, 607u32, 611u32, 619u32, 632u32, 636u32, 640u32, 644u32, 648u32,
+        651u32, 708u32, 712u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 180u32, 256u32, 328u32, 385u32, 412u32,
+        474u32, 522u32, 607u32, 611u32, 619u32, 632u32, 636u32, 640u32, 644u32, 648u32,
+        651u32, 707u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 179u32, 256u32, 328u32, 385u32, 412u32,
+        474u32, 522u32, 607u32, 611u32, 619u32, 632u32, 636u32, 640u32, 644u32, 648u32,
+        651u32, 707u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        57u32, 120u32, 203u32, 205u32, 402u32, 405u32, 417u32, 498u32, 504u32, 530u32,
+        532u32, 601u32, 734u32,
+    ],
+    &[
+        58u32, 121u32, 203u32, 205u32, 402u32, 405u32, 417u32, 498u32, 504u32, 530u32,
+        532u32, 601u32, 734u32,
+    ],
+    &[
+        57u32, 120u32, 203u32, 205u32, 401u32, 405u32, 417u32, 497u32, 504u32, 529u32,
+        532u32, 601u32, 734u32,
+    ],
+    &[
+        57u32, 121u32, 203u32, 205u32, 402u32, 405u32, 418u32, 498u32, 504u32, 530u32,
+        532u32, 601u32, 734u32,
+    ],
+    &[
+        31u32, 36u32, 46u32, 48u32, 50u32, 52u32, 54u32, 61u32, 65u32, 68u32, 70u32,
+        72u32, 74u32, 76u32, 78u32, 80u32, 82u32, 84u32, 86u32, 88u32, 90u32, 92u32,
+        94u32, 96u32, 98u32, 100u32, 102u32, 104u32, 110u32, 114u32, 130u32, 200u32,
+        216u32, 253u32, 301u32, 303u32, 345u32, 376u32, 389u32, 437u32, 444u32, 463u32,
+        492u32, 496u32, 509u32, 516u32, 527u32, 533u32, 536u32, 561u32, 565u32, 568u32,
+        572u32, 576u32, 579u32, 582u32, 603u32, 688u32, 728u32, 738u32, 741u32, 744u32,
+        747u32, 750u32,
+    ],
+    &[
+        31u32, 36u32, 46u32, 48u32, 50u32, 52u32, 54u32, 61u32, 65u32, 68u32, 70u32,
+        72u32, 74u32, 76u32, 78u32, 80u32, 82u32, 84u32, 86u32, 88u32, 90u32, 92u32,
+        94u32, 96u32, 98u32, 100u32, 102u32, 104u32, 110u32, 114u32, 130u32, 200u32,
+        216u32, 253u32, 301u32, 303u32, 345u32, 376u32, 389u32, 437u32, 445u32, 463u32,
+        491u32, 495u32, 509u32, 516u32, 527u32, 533u32, 536u32, 561u32, 565u32, 569u32,
+        573u32, 576u32, 579u32, 583u32, 603u32, 688u32, 728u32, 738u32, 741u32, 744u32,
+        747u32, 750u32,
+    ],
+    &[
+        31u32, 36u32, 46u32, 48u32, 50u32, 52u32, 54u32, 61u32, 65u32, 68u32, 70u32,
+        72u32, 74u32, 76u32, 78u32, 80u32, 82u32, 84u32, 86u32, 88u32, 90u32, 92u32,
+        94u32, 96u32, 98u32, 100u32, 102u32, 104u32, 110u32, 114u32, 131u32, 200u32,
+        216u32, 253u32, 301u32, 303u32, 346u32, 376u32, 389u32, 437u32, 444u32, 463u32,
+        491u32, 495u32, 509u32, 516u32, 527u32, 533u32, 536u32, 561u32, 565u32, 569u32,
+        572u32, 576u32, 579u32, 582u32, 603u32, 688u32, 728u32, 738u32, 741u32, 744u32,
+        747u32, 750u32,
+    ],
+    &[
+        31u32, 36u32, 46u32, 48u32, 50u32, 52u32, 54u32, 61u32, 65u32, 68u32, 70u32,
+        72u32, 74u32, 76u32, 78u32, 80u32, 82u32, 84u32, 86u32, 88u32, 90u32, 92u32,
+        94u32, 96u32, 98u32, 100u32, 102u32, 104u32, 110u32, 114u32, 130u32, 200u32,
+        216u32, 253u32, 300u32, 303u32, 345u32, 376u32, 389u32, 437u32, 444u32, 463u32,
+        491u32, 495u32, 509u32, 516u32, 527u32, 533u32, 536u32, 561u32, 565u32, 568u32,
+        572u32, 576u32, 579u32, 582u32, 603u32, 688u32, 728u32, 738u32, 741u32, 744u32,
+        747u32, 750u32,
+    ],
+    &[
+        31u32, 36u32, 46u32, 48u32, 50u32, 52u32, 54u32, 62u32, 66u32, 68u32, 70u32,
+        72u32, 74u32, 76u32, 78u32, 80u32, 82u32, 84u32, 86u32, 88u32, 90u32, 92u32,
+        94u32, 96u32, 98u32, 100u32, 102u32, 104u32, 111u32, 115u32, 131u32, 201u32,
+        217u32, 254u32, 301u32, 303u32, 346u32, 377u32, 390u32, 437u32, 445u32, 463u32,
+        491u32, 495u32, 509u32, 516u32, 527u32, 533u32, 536u32, 561u32, 565u32, 569u32,
+        573u32, 577u32, 579u32, 583u32, 603u32, 688u32, 728u32, 738u32, 742u32, 744u32,
+        748u32, 750u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 179u32, 256u32, 261u32, 270u32, 328u32,
+        384u32, 412u32, 474u32, 522u32, 548u32, 606u32, 610u32, 619u32, 632u32, 635u32,
+        639u32, 643u32, 647u32, 651u32, 707u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 179u32, 256u32, 261u32, 270u32, 328u32,
+        384u32, 412u32, 474u32, 522u32, 548u32, 606u32, 610u32, 619u32, 632u32, 636u32,
+        639u32, 643u32, 647u32, 651u32, 707u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 179u32, 256u32, 261u32, 271u32, 328u32,
+        384u32, 412u32, 474u32, 522u32, 548u32, 606u32, 610u32, 619u32, 632u32, 636u32,
+        639u32, 643u32, 647u32, 651u32, 708u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 139u32, 151u32, 168u32, 179u32, 256u32, 261u32, 270u32, 328u32,
+        384u32, 412u32, 474u32, 522u32, 548u32, 606u32, 611u32, 619u32, 632u32, 636u32,
+        640u32, 644u32, 648u32, 652u32, 707u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 180u32, 256u32, 261u32, 270u32, 328u32,
+        384u32, 412u32, 475u32, 522u32, 548u32, 606u32, 611u32, 619u32, 632u32, 636u32,
+        640u32, 644u32, 648u32, 651u32, 707u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 180u32, 256u32, 261u32, 271u32, 328u32,
+        384u32, 412u32, 475u32, 522u32, 548u32, 606u32, 611u32, 619u32, 632u32, 636u32,
+        640u32, 644u32, 648u32, 651u32, 708u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 179u32, 256u32, 261u32, 270u32, 328u32,
+        384u32, 412u32, 474u32, 522u32, 549u32, 606u32, 610u32, 619u32, 631u32, 635u32,
+        639u32, 643u32, 647u32, 651u32, 707u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 179u32, 256u32, 261u32, 270u32, 328u32,
+        384u32, 412u32, 474u32, 522u32, 549u32, 606u32, 610u32, 619u32, 632u32, 635u32,
+        639u32, 643u32, 647u32, 651u32, 707u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 180u32, 256u32, 261u32, 271u32, 328u32,
+        384u32, 412u32, 474u32, 522u32, 548u32, 606u32, 610u32, 619u32, 632u32, 636u32,
+        639u32, 643u32, 647u32, 651u32, 708u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 179u32, 256u32, 261u32, 271u32, 328u32,
+        384u32, 412u32, 474u32, 522u32, 548u32, 606u32, 610u32, 619u32, 632u32, 636u32,
+        640u32, 643u32, 647u32, 651u32, 708u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        29u32, 38u32, 138u32, 151u32, 168u32, 180u32, 256u32, 261u32, 271u32, 328u32,
+        384u32, 412u32, 474u32, 522u32, 548u32, 607u32, 611u32, 619u32, 632u32, 636u32,
+        640u32, 644u32, 648u32, 651u32, 708u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        29u32, 38u32, 138u32, 152u32, 168u32, 180u32, 256u32, 261u32, 271u32, 328u32,
+        384u32, 413u32, 474u32, 522u32, 548u32, 606u32, 611u32, 619u32, 632u32, 636u32,
+        640u32, 644u32, 648u32, 651u32, 708u32, 712u32, 715u32, 725u32,
+    ],
+    &[
+        29u32, 38u32, 138u32, 152u32, 168u32, 180u32, 256u32, 261u32, 271u32, 328u32,
+        384u32, 413u32, 474u32, 522u32, 548u32, 607u32, 611u32, 620u32, 632u32, 636u32,
+        640u32, 644u32, 648u32, 651u32, 708u32, 712u32, 715u32, 725u32,
+    ],
+    &[
+        29u32, 38u32, 138u32, 151u32, 168u32, 180u32, 256u32, 261u32, 271u32, 328u32,
+        384u32, 412u32, 474u32, 522u32, 548u32, 606u32, 611u32, 619u32, 632u32, 636u32,
+        640u32, 644u32, 648u32, 651u32, 708u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        29u32, 38u32, 138u32, 152u32, 168u32, 180u32, 256u32, 261u32, 271u32, 328u32,
+        384u32, 412u32, 474u32, 522u32, 549u32, 606u32, 611u32, 619u32, 632u32, 636u32,
+        640u32, 644u32, 648u32, 651u32, 708u32, 712u32, 715u32, 725u32,
+    ],
+    &[
+        29u32, 38u32, 138u32, 152u32, 168u32, 180u32, 256u32, 261u32, 271u32, 328u32,
+        384u32, 413u32, 474u32, 522u32, 548u32, 606u32, 611u32, 620u32, 632u32, 636u32,
+        640u32, 644u32, 648u32, 651u32, 708u32, 712u32, 716u32, 725u32,
+    ],
+    &[
+        29u32, 38u32, 138u32, 152u32, 168u32, 180u32, 256u32, 261u32, 271u32, 328u32,
+        384u32, 413u32, 474u32, 522u32, 548u32, 606u32, 611u32, 619u32, 632u32, 636u32,
+        640u32, 644u32, 648u32, 651u32, 708u32, 712u32, 716u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 139u32, 151u32, 168u32, 180u32, 256u32, 261u32, 271u32, 328u32,
+        384u32, 412u32, 475u32, 522u32, 548u32, 606u32, 611u32, 619u32, 632u32, 636u32,
+        640u32, 644u32, 648u32, 652u32, 707u32, 711u32, 715u32, 726u32,
+    ],
+    &[
+        31u32, 36u32, 46u32, 48u32, 50u32, 52u32, 54u32, 61u32, 65u32, 68u32, 70u32,
+        72u32, 74u32, 76u32, 78u32, 80u32, 82u32, 84u32, 86u32, 88u32, 90u32, 92u32,
+        94u32, 96u32, 98u32, 100u32, 102u32, 104u32, 110u32, 114u32, 131u32, 200u32,
+        216u32, 253u32, 301u32, 303u32, 342u32, 346u32, 376u32, 389u32, 437u32, 444u32,
+        463u32, 491u32, 495u32, 509u32, 516u32, 527u32, 533u32, 536u32, 550u32, 561u32,
+        565u32, 569u32, 573u32, 576u32, 579u32, 583u32, 603u32, 688u32, 728u32, 735u32,
+        736u32, 738u32, 741u32, 744u32, 747u32, 750u32,
+    ],
+    &[
+        31u32, 36u32, 46u32, 48u32, 50u32, 52u32, 54u32, 61u32, 65u32, 68u32, 70u32,
+        72u32, 74u32, 76u32, 78u32, 80u32, 82u32, 84u32, 86u32, 88u32, 90u32, 92u32,
+        94u32, 96u32, 98u32, 100u32, 102u32, 104u32, 110u32, 114u32, 130u32, 200u32,
+        216u32, 253u32, 301u32, 303u32, 342u32, 345u32, 376u32, 389u32, 437u32, 444u32,
+        463u32, 491u32, 495u32, 509u32, 516u32, 527u32, 533u32, 536u32, 550u32, 561u32,
+        565u32, 568u32, 572u32, 576u32, 579u32, 582u32, 603u32, 688u32, 728u32, 735u32,
+        736u32, 738u32, 741u32, 744u32, 747u32, 750u32,
+    ],
+    &[
+        31u32, 36u32, 46u32, 48u32, 50u32, 52u32, 54u32, 61u32, 65u32, 68u32, 70u32,
+        72u32, 74u32, 76u32, 78u32, 80u32, 82u32, 84u32, 86u32, 88u32, 90u32, 92u32,
+        94u32, 96u32, 98u32, 100u32, 102u32, 104u32, 110u32, 114u32, 130u32, 200u32,
+        216u32, 253u32, 300u32, 303u32, 342u32, 345u32, 376u32, 389u32, 437u32, 444u32,
+        463u32, 491u32, 495u32, 509u32, 516u32, 527u32, 533u32, 536u32, 550u32, 560u32,
+        564u32, 568u32, 572u32, 576u32, 579u32, 582u32, 603u32, 688u32, 728u32, 735u32,
+        736u32, 738u32, 741u32, 744u32, 747u32, 750u32,
+    ],
+    &[
+        31u32, 36u32, 46u32, 48u32, 50u32, 52u32, 54u32, 61u32, 65u32, 68u32, 70u32,
+        72u32, 74u32, 76u32, 78u32, 80u32, 82u32, 84u32, 86u32, 88u32, 90u32, 92u32,
+        94u32, 96u32, 98u32, 100u32, 102u32, 104u32, 110u32, 114u32, 130u32, 200u32,
+        216u32, 253u32, 301u32, 303u32, 342u32, 345u32, 376u32, 389u32, 437u32, 444u32,
+        463u32, 491u32, 495u32, 509u32, 516u32, 527u32, 533u32, 536u32, 550u32, 561u32,
+        565u32, 569u32, 572u32, 576u32, 579u32, 583u32, 603u32, 688u32, 728u32, 735u32,
+        736u32, 738u32, 741u32, 744u32, 747u32, 750u32,
+    ],
+    &[
+        31u32, 36u32, 46u32, 48u32, 50u32, 52u32, 54u32, 61u32, 65u32, 68u32, 70u32,
+        72u32, 74u32, 76u32, 78u32, 80u32, 82u32, 84u32, 86u32, 88u32, 90u32, 92u32,
+        94u32, 96u32, 98u32, 100u32, 102u32, 104u32, 110u32, 114u32, 130u32, 200u32,
+        216u32, 253u32, 301u32, 303u32, 342u32, 345u32, 376u32, 389u32, 437u32, 444u32,
+        463u32, 491u32, 495u32, 509u32, 516u32, 527u32, 533u32, 536u32, 551u32, 560u32,
+        564u32, 568u32, 572u32, 576u32, 579u32, 582u32, 603u32, 688u32, 728u32, 735u32,
+        736u32, 738u32, 741u32, 744u32, 747u32, 750u32,
+    ],
+    &[329u32, 330u32, 331u32, 333u32, 334u32, 335u32, 336u32, 337u32],
+    &[
+        25u32, 125u32, 148u32, 186u32, 213u32, 381u32, 503u32, 624u32, 627u32, 753u32,
+        755u32, 757u32, 763u32, 767u32, 770u32, 772u32, 774u32, 776u32, 779u32, 782u32,
+        784u32, 786u32, 788u32, 790u32, 792u32, 794u32, 796u32, 798u32, 800u32, 802u32,
+        804u32, 806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        24u32, 124u32, 147u32, 187u32, 212u32, 380u32, 503u32, 624u32, 627u32, 753u32,
+        755u32, 757u32, 763u32, 767u32, 770u32, 772u32, 774u32, 776u32, 779u32, 782u32,
+        784u32, 786u32, 788u32, 790u32, 792u32, 794u32, 796u32, 798u32, 800u32, 802u32,
+        804u32, 806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        24u32, 124u32, 147u32, 187u32, 212u32, 381u32, 503u32, 624u32, 627u32, 753u32,
+        755u32, 757u32, 763u32, 767u32, 770u32, 772u32, 774u32, 776u32, 779u32, 782u32,
+        784u32, 786u32, 788u32, 790u32, 792u32, 794u32, 796u32, 798u32, 800u32, 802u32,
+        804u32, 806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        24u32, 125u32, 147u32, 187u32, 212u32, 381u32, 503u32, 624u32, 627u32, 753u32,
+        755u32, 757u32, 763u32, 767u32, 770u32, 772u32, 774u32, 776u32, 779u32, 782u32,
+        784u32, 786u32, 788u32, 790u32, 792u32, 794u32, 796u32, 798u32, 800u32, 802u32,
+        804u32, 806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        24u32, 124u32, 147u32, 186u32, 212u32, 380u32, 503u32, 624u32, 627u32, 753u32,
+        755u32, 757u32, 763u32, 767u32, 770u32, 772u32, 774u32, 776u32, 779u32, 782u32,
+        784u32, 786u32, 788u32, 790u32, 792u32, 794u32, 796u32, 798u32, 800u32, 802u32,
+        804u32, 806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        24u32, 124u32, 147u32, 186u32, 212u32, 381u32, 503u32, 624u32, 627u32, 753u32,
+        755u32, 757u32, 763u32, 767u32, 770u32, 772u32, 774u32, 776u32, 779u32, 782u32,
+        784u32, 786u32, 788u32, 790u32, 792u32, 794u32, 796u32, 798u32, 800u32, 802u32,
+        804u32, 806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        25u32, 124u32, 147u32, 186u32, 212u32, 381u32, 503u32, 624u32, 627u32, 753u32,
+        755u32, 757u32, 763u32, 767u32, 770u32, 772u32, 774u32, 776u32, 779u32, 782u32,
+        784u32, 786u32, 788u32, 790u32, 792u32, 794u32, 796u32, 798u32, 800u32, 802u32,
+        804u32, 806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        25u32, 125u32, 147u32, 186u32, 212u32, 381u32, 503u32, 624u32, 627u32, 753u32,
+        755u32, 757u32, 763u32, 767u32, 770u32, 772u32, 774u32, 776u32, 779u32, 782u32,
+        784u32, 786u32, 788u32, 790u32, 792u32, 794u32, 796u32, 798u32, 800u32, 802u32,
+        804u32, 806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        25u32, 125u32, 147u32, 186u32, 213u32, 381u32, 503u32, 624u32, 627u32, 753u32,
+        755u32, 757u32, 763u32, 767u32, 770u32, 772u32, 774u32, 776u32, 779u32, 782u32,
+        784u32, 786u32, 788u32, 790u32, 792u32, 794u32, 796u32, 798u32, 800u32, 802u32,
+        804u32, 806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        24u32, 125u32, 147u32, 186u32, 212u32, 381u32, 503u32, 624u32, 627u32, 753u32,
+        755u32, 757u32, 763u32, 767u32, 770u32, 772u32, 774u32, 776u32, 779u32, 782u32,
+        784u32, 786u32, 788u32, 790u32, 792u32, 794u32, 796u32, 798u32, 800u32, 802u32,
+        804u32, 806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        25u32, 125u32, 148u32, 186u32, 213u32, 381u32, 503u32, 624u32, 628u32, 753u32,
+        755u32, 757u32, 764u32, 768u32, 770u32, 772u32, 774u32, 776u32, 780u32, 782u32,
+        784u32, 786u32, 788u32, 790u32, 792u32, 794u32, 796u32, 798u32, 800u32, 802u32,
+        804u32, 806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 179u32, 256u32, 261u32, 270u32, 328u32,
+        384u32, 412u32, 474u32, 522u32, 549u32, 607u32, 610u32, 619u32, 631u32, 635u32,
+        639u32, 643u32, 647u32, 651u32, 707u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 179u32, 256u32, 261u32, 270u32, 328u32,
+        384u32, 412u32, 474u32, 522u32, 549u32, 607u32, 610u32, 619u32, 632u32, 635u32,
+        639u32, 643u32, 647u32, 651u32, 707u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 179u32, 256u32, 261u32, 270u32, 328u32,
+        385u32, 412u32, 474u32, 522u32, 548u32, 607u32, 610u32, 619u32, 632u32, 636u32,
+        639u32, 643u32, 647u32, 651u32, 707u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        29u32, 38u32, 138u32, 152u32, 168u32, 180u32, 256u32, 261u32, 271u32, 328u32,
+        384u32, 412u32, 474u32, 522u32, 549u32, 607u32, 611u32, 619u32, 632u32, 636u32,
+        640u32, 644u32, 648u32, 651u32, 708u32, 712u32, 715u32, 725u32,
+    ],
+    &[
+        29u32, 38u32, 138u32, 152u32, 168u32, 180u32, 256u32, 261u32, 271u32, 328u32,
+        384u32, 413u32, 474u32, 522u32, 548u32, 607u32, 611u32, 619u32, 632u32, 636u32,
+        640u32, 644u32, 648u32, 651u32, 708u32, 712u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 180u32, 256u32, 261u32, 270u32, 328u32,
+        385u32, 412u32, 474u32, 522u32, 548u32, 607u32, 611u32, 619u32, 632u32, 636u32,
+        640u32, 644u32, 648u32, 651u32, 707u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 180u32, 256u32, 261u32, 271u32, 328u32,
+        385u32, 412u32, 474u32, 522u32, 548u32, 607u32, 611u32, 619u32, 632u32, 636u32,
+        640u32, 644u32, 648u32, 651u32, 707u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 179u32, 256u32, 261u32, 270u32, 328u32,
+        385u32, 412u32, 474u32, 522u32, 548u32, 607u32, 611u32, 619u32, 632u32, 636u32,
+        640u32, 644u32, 648u32, 651u32, 707u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        28u32, 38u32, 138u32, 151u32, 168u32, 179u32, 256u32, 261u32, 271u32, 328u32,
+        385u32, 412u32, 474u32, 522u32, 548u32, 607u32, 611u32, 619u32, 632u32, 636u32,
+        640u32, 644u32, 648u32, 651u32, 707u32, 711u32, 715u32, 725u32,
+    ],
+    &[
+        31u32, 36u32, 46u32, 48u32, 50u32, 52u32, 54u32, 61u32, 65u32, 68u32, 70u32,
+        72u32, 74u32, 76u32, 78u32, 80u32, 82u32, 84u32, 86u32, 88u32, 90u32, 92u32,
+        94u32, 96u32, 98u32, 100u32, 102u32, 104u32, 110u32, 114u32, 130u32, 200u32,
+        216u32, 253u32, 301u32, 303u32, 342u32, 345u32, 376u32, 389u32, 437u32, 444u32,
+        463u32, 492u32, 496u32, 509u32, 516u32, 527u32, 533u32, 536u32, 550u32, 561u32,
+        565u32, 568u32, 572u32, 576u32, 579u32, 582u32, 603u32, 688u32, 728u32, 735u32,
+        736u32, 738u32, 741u32, 744u32, 747u32, 750u32,
+    ],
+    &[
+        31u32, 36u32, 46u32, 48u32, 50u32, 52u32, 54u32, 61u32, 65u32, 68u32, 70u32,
+        72u32, 74u32, 76u32, 78u32, 80u32, 82u32, 84u32, 86u32, 88u32, 90u32, 92u32,
+        94u32, 96u32, 98u32, 100u32, 102u32, 104u32, 110u32, 114u32, 130u32, 200u32,
+        216u32, 253u32, 301u32, 303u32, 342u32, 345u32, 376u32, 389u32, 437u32, 445u32,
+        463u32, 491u32, 495u32, 509u32, 516u32, 527u32, 533u32, 536u32, 550u32, 561u32,
+        565u32, 569u32, 573u32, 576u32, 579u32, 583u32, 603u32, 688u32, 728u32, 735u32,
+        736u32, 738u32, 741u32, 744u32, 747u32, 750u32,
+    ],
+    &[
+        31u32, 36u32, 46u32, 48u32, 50u32, 52u32, 54u32, 61u32, 65u32, 68u32, 70u32,
+        72u32, 74u32, 76u32, 78u32, 80u32, 82u32, 84u32, 86u32, 88u32, 90u32, 92u32,
+        94u32, 96u32, 98u32, 100u32, 102u32, 104u32, 110u32, 114u32, 131u32, 200u32,
+        216u32, 253u32, 301u32, 303u32, 342u32, 346u32, 376u32, 389u32, 437u32, 444u32,
+        463u32, 491u32, 495u32, 509u32, 516u32, 527u32, 533u32, 536u32, 550u32, 561u32,
+        565u32, 569u32, 572u32, 576u32, 579u32, 582u32, 603u32, 688u32, 728u32, 735u32,
+        736u32, 738u32, 741u32, 744u32, 747u32, 750u32,
+    ],
+    &[
+        31u32, 36u32, 46u32, 48u32, 50u32, 52u32, 54u32, 61u32, 65u32, 68u32, 70u32,
+        72u32, 74u32, 76u32, 78u32, 80u32, 82u32, 84u32, 86u32, 88u32, 90u32, 92u32,
+        94u32, 96u32, 98u32, 100u32, 102u32, 104u32, 110u32, 114u32, 130u32, 200u32,
+        216u32, 253u32, 300u32, 303u32, 342u32, 345u32, 376u32, 389u32, 437u32, 444u32,
+        463u32, 491u32, 495u32, 509u32, 516u32, 527u32, 533u32, 536u32, 550u32, 561u32,
+        565u32, 568u32, 572u32, 576u32, 579u32, 582u32, 603u32, 688u32, 728u32, 735u32,
+        736u32, 738u32, 741u32, 744u32, 747u32, 750u32,
+    ],
+    &[
+        31u32, 36u32, 46u32, 48u32, 50u32, 52u32, 54u32, 62u32, 66u32, 68u32, 70u32,
+        72u32, 74u32, 76u32, 78u32, 80u32, 82u32, 84u32, 86u32, 88u32, 90u32, 92u32,
+        94u32, 96u32, 98u32, 100u32, 102u32, 104u32, 111u32, 115u32, 131u32, 201u32,
+        217u32, 254u32, 301u32, 303u32, 342u32, 346u32, 377u32, 390u32, 437u32, 445u32,
+        463u32, 491u32, 495u32, 509u32, 516u32, 527u32, 533u32, 536u32, 550u32, 561u32,
+        565u32, 569u32, 573u32, 577u32, 579u32, 583u32, 603u32, 688u32, 728u32, 735u32,
+        736u32, 738u32, 742u32, 744u32, 748u32, 750u32,
+    ],
+    &[
+        35u32, 107u32, 117u32, 135u32, 140u32, 156u32, 165u32, 171u32, 175u32, 192u32,
+        219u32, 221u32, 222u32, 225u32, 231u32, 235u32, 237u32, 259u32, 266u32, 267u32,
+        268u32, 269u32, 296u32, 315u32, 326u32, 340u32, 358u32, 362u32, 364u32, 365u32,
+        371u32, 386u32, 400u32, 409u32, 423u32, 427u32, 431u32, 435u32, 440u32, 467u32,
+        470u32, 478u32, 482u32, 484u32, 487u32, 506u32, 512u32, 520u32, 525u32, 535u32,
+        537u32, 538u32, 539u32, 540u32, 541u32, 542u32, 543u32, 544u32, 545u32, 546u32,
+        547u32, 553u32, 556u32, 584u32, 585u32, 586u32, 588u32, 589u32, 591u32, 593u32,
+        595u32, 597u32, 599u32, 600u32, 613u32, 655u32, 659u32, 663u32, 667u32, 670u32,
+        672u32, 674u32, 675u32, 676u32, 681u32, 682u32, 683u32, 684u32, 685u32, 686u32,
+        719u32, 733u32,
+    ],
+    &[
+        144u32, 158u32, 209u32, 293u32, 347u32, 348u32, 351u32, 353u32, 354u32, 370u32,
+        373u32, 501u32, 514u32, 622u32,
+    ],
+    &[
+        143u32, 158u32, 208u32, 293u32, 347u32, 348u32, 351u32, 353u32, 354u32, 369u32,
+        373u32, 501u32, 514u32, 622u32,
+    ],
+    &[
+        41u32, 397u32, 433u32, 447u32, 450u32, 452u32, 456u32, 459u32, 461u32, 464u32,
+        731u32,
+    ],
+    &[
+        41u32, 398u32, 433u32, 447u32, 450u32, 452u32, 456u32, 459u32, 461u32, 464u32,
+        731u32,
+    ],
+    &[
+        42u32, 397u32, 433u32, 447u32, 450u32, 452u32, 456u32, 459u32, 461u32, 464u32,
+        732u32,
+    ],
+    &[
+        42u32, 397u32, 433u32, 447u32, 450u32, 452u32, 456u32, 459u32, 461u32, 464u32,
+        731u32,
+    ],
+    &[
+        42u32, 397u32, 434u32, 447u32, 451u32, 453u32, 457u32, 459u32, 461u32, 465u32,
+        732u32,
+    ],
+    &[
+        25u32, 125u32, 148u32, 186u32, 213u32, 381u32, 503u32, 624u32, 627u32, 751u32,
+        752u32, 754u32, 755u32, 756u32, 758u32, 759u32, 760u32, 763u32, 767u32, 770u32,
+        772u32, 774u32, 776u32, 779u32, 782u32, 784u32, 786u32, 788u32, 790u32, 792u32,
+        794u32, 796u32, 798u32, 800u32, 802u32, 804u32, 806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        24u32, 124u32, 147u32, 187u32, 212u32, 380u32, 503u32, 624u32, 627u32, 751u32,
+        752u32, 753u32, 755u32, 756u32, 757u32, 759u32, 760u32, 763u32, 767u32, 770u32,
+        772u32, 774u32, 776u32, 779u32, 782u32, 784u32, 786u32, 788u32, 790u32, 792u32,
+        794u32, 796u32, 798u32, 800u32, 802u32, 804u32, 806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        24u32, 124u32, 147u32, 187u32, 212u32, 381u32, 503u32, 624u32, 627u32, 751u32,
+        752u32, 753u32, 755u32, 756u32, 757u32, 759u32, 760u32, 763u32, 767u32, 770u32,
+        772u32, 774u32, 776u32, 779u32, 782u32, 784u32, 786u32, 788u32, 790u32, 792u32,
+        794u32, 796u32, 798u32, 800u32, 802u32, 804u32, 806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        24u32, 125u32, 147u32, 187u32, 212u32, 381u32, 503u32, 624u32, 627u32, 751u32,
+        752u32, 753u32, 755u32, 756u32, 757u32, 759u32, 760u32, 763u32, 767u32, 770u32,
+        772u32, 774u32, 776u32, 779u32, 782u32, 784u32, 786u32, 788u32, 790u32, 792u32,
+        794u32, 796u32, 798u32, 800u32, 802u32, 804u32, 806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        24u32, 124u32, 147u32, 186u32, 212u32, 380u32, 503u32, 624u32, 627u32, 751u32,
+        752u32, 753u32, 755u32, 756u32, 757u32, 759u32, 760u32, 763u32, 767u32, 770u32,
+        772u32, 774u32, 776u32, 779u32, 782u32, 784u32, 786u32, 788u32, 790u32, 792u32,
+        794u32, 796u32, 798u32, 800u32, 802u32, 804u32, 806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        24u32, 124u32, 147u32, 186u32, 212u32, 381u32, 503u32, 624u32, 627u32, 751u32,
+        752u32, 753u32, 755u32, 756u32, 757u32, 759u32, 760u32, 763u32, 767u32, 770u32,
+        772u32, 774u32, 776u32, 779u32, 782u32, 784u32, 786u32, 788u32, 790u32, 792u32,
+        794u32, 796u32, 798u32, 800u32, 802u32, 804u32, 806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        25u32, 124u32, 147u32, 186u32, 212u32, 381u32, 503u32, 624u32, 627u32, 751u32,
+        752u32, 754u32, 755u32, 756u32, 758u32, 759u32, 760u32, 763u32, 767u32, 770u32,
+        772u32, 774u32, 776u32, 779u32, 782u32, 784u32, 786u32, 788u32, 790u32, 792u32,
+        794u32, 796u32, 798u32, 800u32, 802u32, 804u32, 806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        25u32, 125u32, 147u32, 186u32, 212u32, 381u32, 503u32, 624u32, 627u32, 751u32,
+        752u32, 754u32, 755u32, 756u32, 758u32, 759u32, 760u32, 763u32, 767u32, 770u32,
+        772u32, 774u32, 776u32, 779u32, 782u32, 784u32, 786u32, 788u32, 790u32, 792u32,
+        794u32, 796u32, 798u32, 800u32, 802u32, 804u32, 806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        25u32, 125u32, 147u32, 186u32, 213u32, 381u32, 503u32, 624u32, 627u32, 751u32,
+        752u32, 754u32, 755u32, 756u32, 758u32, 759u32, 760u32, 763u32, 767u32, 770u32,
+        772u32, 774u32, 776u32, 779u32, 782u32, 784u32, 786u32, 788u32, 790u32, 792u32,
+        794u32, 796u32, 798u32, 800u32, 802u32, 804u32, 806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        24u32, 125u32, 147u32, 186u32, 212u32, 381u32, 503u32, 624u32, 627u32, 751u32,
+        752u32, 753u32, 755u32, 756u32, 757u32, 759u32, 760u32, 763u32, 767u32, 770u32,
+        772u32, 774u32, 776u32, 779u32, 782u32, 784u32, 786u32, 788u32, 790u32, 792u32,
+        794u32, 796u32, 798u32, 800u32, 802u32, 804u32, 806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        25u32, 125u32, 148u32, 186u32, 213u32, 381u32, 503u32, 624u32, 628u32, 751u32,
+        752u32, 754u32, 755u32, 756u32, 758u32, 759u32, 760u32, 764u32, 768u32, 770u32,
+        772u32, 774u32, 776u32, 780u32, 782u32, 784u32, 786u32, 788u32, 790u32, 792u32,
+        794u32, 796u32, 798u32, 800u32, 802u32, 804u32, 806u32, 808u32, 810u32, 812u32,
+    ],
+    &[
+        105u32, 153u32, 197u32, 304u32, 318u32, 414u32, 614u32, 699u32, 700u32, 701u32,
+        702u32, 703u32, 704u32,
+    ],
+    &[355u32, 677u32, 680u32],
+    &[
+        58u32, 121u32, 203u32, 205u32, 402u32, 405u32, 418u32, 498u32, 504u32, 530u32,
+        532u32, 601u32, 734u32,
+    ],
+    &[
+        31u32, 36u32, 46u32, 48u32, 50u32, 52u32, 54u32, 61u32, 65u32, 68u32, 70u32,
+        72u32, 74u32, 76u32, 78u32, 80u32, 82u32, 84u32, 86u32, 88u32, 90u32, 92u32,
+        94u32, 96u32, 98u32, 100u32, 102u32, 104u32, 110u32, 114u32, 131u32, 200u32,
+        216u32, 253u32, 301u32, 303u32, 342u32, 346u32, 376u32, 389u32, 437u32, 445u32,
+        463u32, 491u32, 495u32, 509u32, 516u32, 527u32, 533u32, 536u32, 550u32, 561u32,
+        565u32, 569u32, 573u32, 576u32, 579u32, 583u32, 603u32, 688u32, 728u32, 735u32,
+        736u32, 738u32, 741u32, 744u32, 747u32, 750u32,
+    ],
+];
+/// Each version's targets, and their feature list IDs
+static TARGETS: [(&str, &[(u32, u32)]); 4usize] = [
+    (
+        "1.81.0",
+        &[
+            (9u32, 0u32),
+            (10u32, 1u32),
+            (11u32, 2u32),
+            (12u32, 2u32),
+            (13u32, 1u32),
+            (14u32, 2u32),
+            (15u32, 1u32),
+            (16u32, 2u32),
+            (17u32, 1u32),
+            (18u32, 2u32),
+            (19u32, 3u32),
+            (20u32, 3u32),
+            (21u32, 3u32),
+            (22u32, 3u32),
+            (23u32, 3u32),
+            (24u32, 3u32),
+            (25u32, 3u32),
+            (26u32, 3u32),
+            (27u32, 3u32),
+            (28u32, 3u32),
+            (29u32, 3u32),
+            (30u32, 3u32),
+            (31u32, 3u32),
+            (32u32, 3u32),
+            (33u32, 3u32),
+            (34u32, 3u32),
+            (35u32, 4u32),
+            (37u32, 3u32),
+            (41u32, 3u32),
+            (42u32, 3u32),
+            (43u32, 3u32),
+            (45u32, 3u32),
+            (46u32, 3u32),
+            (47u32, 3u32),
+            (48u32, 3u32),
+            (49u32, 3u32),
+            (50u32, 3u32),
+            (67u32, 5u32),
+            (68u32, 6u32),
+            (69u32, 7u32),
+            (70u32, 6u32),
+            (71u32, 7u32),
+            (72u32, 2u32),
+            (73u32, 0u32),
+            (74u32, 2u32),
+            (77u32, 3u32),
+            (79u32, 8u32),
+            (80u32, 9u32),
+            (81u32, 10u32),
+            (82u32, 11u32),
+            (83u32, 11u32),
+            (84u32, 5u32),
+            (85u32, 5u32),
+            (86u32, 5u32),
+            (87u32, 5u32),
+            (88u32, 12u32),
+            (89u32, 12u32),
+            (90u32, 13u32),
+            (91u32, 14u32),
+            (93u32, 15u32),
+            (94u32, 16u32),
+            (95u32, 17u32),
+            (96u32, 16u32),
+            (97u32, 17u32),
+            (98u32, 16u32),
+            (99u32, 17u32),
+            (100u32, 17u32),
+            (101u32, 16u32),
+            (102u32, 16u32),
+            (104u32, 16u32),
+            (105u32, 17u32),
+            (106u32, 16u32),
+            (107u32, 17u32),
+            (108u32, 16u32),
+            (111u32, 18u32),
+            (112u32, 9u32),
+            (113u32, 10u32),
+            (114u32, 19u32),
+            (115u32, 20u32),
+            (119u32, 21u32),
+            (154u32, 22u32),
+            (155u32, 22u32),
+            (167u32, 23u32),
+            (168u32, 24u32),
+            (243u32, 25u32),
+            (244u32, 25u32),
+            (251u32, 26u32),
+            (252u32, 27u32),
+            (253u32, 28u32),
+            (254u32, 28u32),
+            (255u32, 28u32),
+            (256u32, 28u32),
+            (258u32, 26u32),
+            (259u32, 29u32),
+            (261u32, 27u32),
+            (262u32, 27u32),
+            (263u32, 27u32),
+            (264u32, 27u32),
+            (265u32, 27u32),
+            (266u32, 28u32),
+            (267u32, 27u32),
+            (268u32, 27u32),
+            (269u32, 27u32),
+            (270u32, 27u32),
+            (271u32, 28u32),
+            (272u32, 30u32),
+            (273u32, 27u32),
+            (274u32, 27u32),
+            (276u32, 27u32),
+            (277u32, 27u32),
+            (310u32, 31u32),
+            (311u32, 31u32),
+            (313u32, 31u32),
+            (314u32, 32u32),
+            (325u32, 21u32),
+            (331u32, 33u32),
+            (332u32, 33u32),
+            (333u32, 33u32),
+            (335u32, 34u32),
+            (336u32, 34u32),
+            (337u32, 34u32),
+            (338u32, 34u32),
+            (339u32, 34u32),
+            (342u32, 33u32),
+            (343u32, 33u32),
+            (344u32, 33u32),
+            (345u32, 33u32),
+            (346u32, 33u32),
+            (347u32, 33u32),
+            (348u32, 33u32),
+            (349u32, 34u32),
+            (350u32, 34u32),
+            (351u32, 34u32),
+            (352u32, 34u32),
+            (359u32, 21u32),
+            (368u32, 21u32),
+            (390u32, 35u32),
+            (391u32, 35u32),
+            (392u32, 35u32),
+            (393u32, 35u32),
+            (395u32, 35u32),
+            (396u32, 35u32),
+            (397u32, 35u32),
+            (398u32, 35u32),
+            (400u32, 36u32),
+            (401u32, 37u32),
+            (402u32, 37u32),
+            (403u32, 37u32),
+            (404u32, 37u32),
+            (405u32, 37u32),
+            (406u32, 38u32),
+            (407u32, 38u32),
+            (408u32, 38u32),
+            (434u32, 39u32),
+            (435u32, 39u32),
+            (436u32, 40u32),
+            (437u32, 41u32),
+            (438u32, 41u32),
+            (439u32, 42u32),
+            (440u32, 43u32),
+            (441u32, 43u32),
+            (443u32, 43u32),
+            (444u32, 44u32),
+            (445u32, 44u32),
+            (447u32, 45u32),
+            (448u32, 45u32),
+            (451u32, 46u32),
+            (453u32, 39u32),
+            (454u32, 39u32),
+            (455u32, 39u32),
+            (456u32, 39u32),
+            (457u32, 39u32),
+            (458u32, 39u32),
+            (459u32, 39u32),
+            (461u32, 39u32),
+            (462u32, 43u32),
+            (473u32, 21u32),
+            (474u32, 21u32),
+            (500u32, 21u32),
+            (501u32, 21u32),
+            (502u32, 21u32),
+            (503u32, 21u32),
+            (504u32, 21u32),
+            (506u32, 21u32),
+            (543u32, 47u32),
+            (544u32, 48u32),
+            (545u32, 49u32),
+            (549u32, 50u32),
+            (550u32, 50u32),
+            (551u32, 51u32),
+            (552u32, 51u32),
+            (555u32, 52u32),
+            (557u32, 50u32),
+            (558u32, 50u32),
+            (559u32, 50u32),
+            (560u32, 49u32),
+            (562u32, 52u32),
+            (563u32, 52u32),
+            (615u32, 53u32),
+            (616u32, 53u32),
+            (618u32, 53u32),
+            (619u32, 53u32),
+            (620u32, 54u32),
+            (621u32, 53u32),
+            (623u32, 55u32),
+            (630u32, 26u32),
+            (631u32, 26u32),
+            (632u32, 26u32),
+            (633u32, 26u32),
+            (634u32, 26u32),
+            (635u32, 56u32),
+            (636u32, 27u32),
+            (637u32, 57u32),
+            (639u32, 27u32),
+            (642u32, 27u32),
+            (643u32, 58u32),
+            (644u32, 58u32),
+            (645u32, 58u32),
+            (646u32, 27u32),
+            (647u32, 27u32),
+            (648u32, 27u32),
+            (649u32, 27u32),
+            (650u32, 27u32),
+            (651u32, 56u32),
+            (653u32, 27u32),
+            (654u32, 27u32),
+            (655u32, 27u32),
+            (656u32, 27u32),
+            (657u32, 27u32),
+            (658u32, 27u32),
+            (659u32, 27u32),
+            (660u32, 27u32),
+            (661u32, 30u32),
+            (662u32, 27u32),
+            (663u32, 27u32),
+            (665u32, 30u32),
+            (666u32, 58u32),
+            (667u32, 58u32),
+            (669u32, 27u32),
+            (670u32, 27u32),
+            (671u32, 59u32),
+            (681u32, 21u32),
+            (682u32, 21u32),
+            (683u32, 21u32),
+            (684u32, 21u32),
+            (685u32, 21u32),
+            (686u32, 21u32),
+        ],
+    ),
+    (
+        "1.85.0",
+        &[
+            (9u32, 60u32),
+            (10u32, 61u32),
+            (11u32, 62u32),
+            (12u32, 62u32),
+            (13u32, 61u32),
+            (14u32, 62u32),
+            (15u32, 61u32),
+            (16u32, 62u32),
+            (17u32, 61u32),
+            (18u32, 62u32),
+            (20u32, 63u32),
+            (21u32, 63u32),
+            (22u32, 63u32),
+            (23u32, 63u32),
+            (24u32, 63u32),
+            (25u32, 63u32),
+            (26u32, 63u32),
+            (27u32, 63u32),
+            (28u32, 63u32),
+            (29u32, 63u32),
+            (30u32, 63u32),
+            (31u32, 63u32),
+            (32u32, 63u32),
+            (33u32, 63u32),
+            (34u32, 63u32),
+            (35u32, 64u32),
+            (36u32, 63u32),
+            (37u32, 63u32),
+            (41u32, 63u32),
+            (42u32, 63u32),
+            (43u32, 63u32),
+            (44u32, 65u32),
+            (45u32, 63u32),
+            (46u32, 63u32),
+            (47u32, 63u32),
+            (48u32, 63u32),
+            (49u32, 63u32),
+            (50u32, 63u32),
+            (67u32, 66u32),
+            (68u32, 67u32),
+            (69u32, 68u32),
+            (70u32, 67u32),
+            (71u32, 68u32),
+            (72u32, 62u32),
+            (73u32, 60u32),
+            (74u32, 62u32),
+            (75u32, 62u32),
+            (77u32, 63u32),
+            (79u32, 69u32),
+            (80u32, 70u32),
+            (81u32, 71u32),
+            (82u32, 72u32),
+            (83u32, 72u32),
+            (84u32, 73u32),
+            (85u32, 73u32),
+            (86u32, 73u32),
+            (87u32, 73u32),
+            (88u32, 74u32),
+            (89u32, 74u32),
+            (90u32, 75u32),
+            (91u32, 76u32),
+            (92u32, 77u32),
+            (93u32, 78u32),
+            (94u32, 79u32),
+            (95u32, 80u32),
+            (96u32, 79u32),
+            (97u32, 80u32),
+            (98u32, 79u32),
+            (99u32, 80u32),
+            (100u32, 80u32),
+            (101u32, 79u32),
+            (102u32, 79u32),
+            (103u32, 80u32),
+            (104u32, 79u32),
+            (105u32, 80u32),
+            (106u32, 79u32),
+            (107u32, 80u32),
+            (108u32, 79u32),
+            (111u32, 81u32),
+            (112u32, 70u32),
+            (113u32, 71u32),
+            (114u32, 82u32),
+            (115u32, 83u32),
+            (119u32, 21u32),
+            (154u32, 84u32),
+            (155u32, 84u32),
+            (167u32, 85u32),
+            (168u32, 86u32),
+            (243u32, 87u32),
+            (244u32, 87u32),
+            (251u32, 88u32),
+            (252u32, 89u32),
+            (253u32, 90u32),
+            (254u32, 90u32),
+            (255u32, 90u32),
+            (256u32, 90u32),
+            (258u32, 88u32),
+            (259u32, 91u32),
+            (261u32, 89u32),
+            (262u32, 89u32),
+            (263u32, 89u32),
+            (264u32, 89u32),
+            (265u32, 89u32),
+            (266u32, 90u32),
+            (267u32, 89u32),
+            (268u32, 89u32),
+            (269u32, 89u32),
+            (270u32, 89u32),
+            (271u32, 90u32),
+            (272u32, 92u32),
+            (273u32, 89u32),
+            (274u32, 89u32),
+            (276u32, 89u32),
+            (277u32, 89u32),
+            (310u32, 93u32),
+            (311u32, 93u32),
+            (312u32, 93u32),
+            (313u32, 94u32),
+            (314u32, 95u32),
+            (325u32, 96u32),
+            (331u32, 97u32),
+            (332u32, 97u32),
+            (333u32, 97u32),
+            (335u32, 98u32),
+            (336u32, 98u32),
+            (337u32, 98u32),
+            (338u32, 98u32),
+            (339u32, 98u32),
+            (342u32, 97u32),
+            (343u32, 97u32),
+            (344u32, 97u32),
+            (345u32, 97u32),
+            (346u32, 97u32),
+            (347u32, 97u32),
+            (348u32, 97u32),
+            (349u32, 98u32),
+            (350u32, 98u32),
+            (351u32, 98u32),
+            (352u32, 98u32),
+            (359u32, 21u32),
+            (368u32, 21u32),
+            (390u32, 99u32),
+            (391u32, 99u32),
+            (392u32, 99u32),
+            (393u32, 99u32),
+            (394u32, 99u32),
+            (395u32, 99u32),
+            (396u32, 99u32),
+            (397u32, 99u32),
+            (398u32, 99u32),
+            (400u32, 100u32),
+            (401u32, 101u32),
+            (402u32, 101u32),
+            (403u32, 101u32),
+            (404u32, 101u32),
+            (405u32, 101u32),
+            (406u32, 102u32),
+            (407u32, 102u32),
+            (408u32, 102u32),
+            (430u32, 103u32),
+            (431u32, 104u32),
+            (432u32, 105u32),
+            (433u32, 106u32),
+            (434u32, 103u32),
+            (435u32, 103u32),
+            (436u32, 107u32),
+            (437u32, 108u32),
+            (438u32, 108u32),
+            (439u32, 109u32),
+            (440u32, 110u32),
+            (441u32, 110u32),
+            (442u32, 110u32),
+            (443u32, 110u32),
+            (444u32, 111u32),
+            (445u32, 111u32),
+            (446u32, 111u32),
+            (447u32, 112u32),
+            (448u32, 112u32),
+            (449u32, 112u32),
+            (451u32, 113u32),
+            (452u32, 103u32),
+            (453u32, 103u32),
+            (454u32, 103u32),
+            (455u32, 103u32),
+            (456u32, 103u32),
+            (457u32, 103u32),
+            (458u32, 103u32),
+            (459u32, 103u32),
+            (460u32, 103u32),
+            (461u32, 103u32),
+            (462u32, 110u32),
+            (463u32, 110u32),
+            (473u32, 114u32),
+            (474u32, 114u32),
+            (500u32, 115u32),
+            (501u32, 116u32),
+            (502u32, 115u32),
+            (503u32, 115u32),
+            (504u32, 115u32),
+            (506u32, 115u32),
+            (543u32, 117u32),
+            (544u32, 118u32),
+            (545u32, 119u32),
+            (546u32, 119u32),
+            (549u32, 120u32),
+            (550u32, 120u32),
+            (551u32, 121u32),
+            (552u32, 121u32),
+            (553u32, 121u32),
+            (554u32, 121u32),
+            (555u32, 122u32),
+            (556u32, 122u32),
+            (557u32, 120u32),
+            (558u32, 120u32),
+            (559u32, 120u32),
+            (560u32, 119u32),
+            (561u32, 119u32),
+            (562u32, 122u32),
+            (563u32, 122u32),
+            (564u32, 122u32),
+            (565u32, 122u32),
+            (615u32, 123u32),
+            (616u32, 123u32),
+            (619u32, 123u32),
+            (620u32, 124u32),
+            (621u32, 123u32),
+            (622u32, 125u32),
+            (623u32, 126u32),
+            (630u32, 88u32),
+            (631u32, 88u32),
+            (632u32, 88u32),
+            (633u32, 88u32),
+            (634u32, 88u32),
+            (635u32, 127u32),
+            (637u32, 128u32),
+            (639u32, 89u32),
+            (642u32, 89u32),
+            (643u32, 129u32),
+            (644u32, 129u32),
+            (645u32, 129u32),
+            (646u32, 89u32),
+            (647u32, 89u32),
+            (648u32, 89u32),
+            (649u32, 89u32),
+            (650u32, 89u32),
+            (651u32, 127u32),
+            (652u32, 89u32),
+            (653u32, 89u32),
+            (654u32, 89u32),
+            (655u32, 89u32),
+            (656u32, 89u32),
+            (657u32, 89u32),
+            (658u32, 89u32),
+            (659u32, 89u32),
+            (660u32, 89u32),
+            (661u32, 92u32),
+            (662u32, 89u32),
+            (663u32, 89u32),
+            (664u32, 130u32),
+            (665u32, 92u32),
+            (666u32, 129u32),
+            (667u32, 129u32),
+            (669u32, 89u32),
+            (670u32, 89u32),
+            (671u32, 131u32),
+            (681u32, 21u32),
+            (682u32, 21u32),
+            (683u32, 21u32),
+            (684u32, 21u32),
+            (685u32, 21u32),
+            (686u32, 21u32),
+        ],
+    ),
+    (
+        "1.86.0",
+        &[
+            (9u32, 60u32),
+            (10u32, 61u32),
+            (11u32, 62u32),
+            (12u32, 62u32),
+            (13u32, 61u32),
+            (14u32, 62u32),
+            (15u32, 61u32),
+            (16u32, 62u32),
+            (17u32, 61u32),
+            (18u32, 62u32),
+            (20u32, 63u32),
+            (21u32, 63u32),
+            (22u32, 63u32),
+            (23u32, 63u32),
+            (24u32, 63u32),
+            (25u32, 63u32),
+            (26u32, 63u32),
+            (27u32, 63u32),
+            (28u32, 63u32),
+            (29u32, 63u32),
+            (30u32, 63u32),
+            (31u32, 63u32),
+            (32u32, 63u32),
+            (33u32, 63u32),
+            (34u32, 63u32),
+            (35u32, 64u32),
+            (36u32, 63u32),
+            (37u32, 63u32),
+            (38u32, 63u32),
+            (39u32, 63u32),
+            (40u32, 63u32),
+            (41u32, 63u32),
+            (42u32, 63u32),
+            (43u32, 63u32),
+            (44u32, 65u32),
+            (45u32, 63u32),
+            (46u32, 63u32),
+            (47u32, 65u32),
+            (48u32, 63u32),
+            (49u32, 63u32),
+            (50u32, 63u32),
+            (60u32, 21u32),
+            (67u32, 132u32),
+            (68u32, 133u32),
+            (69u32, 134u32),
+            (70u32, 133u32),
+            (71u32, 134u32),
+            (72u32, 62u32),
+            (73u32, 60u32),
+            (74u32, 62u32),
+            (75u32, 62u32),
+            (77u32, 63u32),
+            (79u32, 135u32),
+            (80u32, 136u32),
+            (81u32, 137u32),
+            (82u32, 138u32),
+            (83u32, 138u32),
+            (84u32, 139u32),
+            (85u32, 139u32),
+            (86u32, 139u32),
+            (87u32, 139u32),
+            (88u32, 140u32),
+            (89u32, 140u32),
+            (90u32, 141u32),
+            (91u32, 142u32),
+            (92u32, 143u32),
+            (93u32, 144u32),
+            (94u32, 145u32),
+            (95u32, 146u32),
+            (96u32, 145u32),
+            (97u32, 146u32),
+            (98u32, 145u32),
+            (99u32, 146u32),
+            (100u32, 146u32),
+            (101u32, 145u32),
+            (102u32, 145u32),
+            (103u32, 146u32),
+            (104u32, 145u32),
+            (105u32, 146u32),
+            (106u32, 145u32),
+            (107u32, 146u32),
+            (108u32, 145u32),
+            (109u32, 146u32),
+            (110u32, 143u32),
+            (111u32, 147u32),
+            (112u32, 136u32),
+            (113u32, 137u32),
+            (114u32, 148u32),
+            (115u32, 149u32),
+            (119u32, 21u32),
+            (154u32, 84u32),
+            (155u32, 84u32),
+            (167u32, 85u32),
+            (168u32, 86u32),
+            (243u32, 87u32),
+            (244u32, 87u32),
+            (251u32, 150u32),
+            (252u32, 151u32),
+            (253u32, 152u32),
+            (254u32, 152u32),
+            (255u32, 152u32),
+            (256u32, 152u32),
+            (257u32, 152u32),
+            (258u32, 150u32),
+            (259u32, 153u32),
+            (261u32, 151u32),
+            (262u32, 151u32),
+            (263u32, 151u32),
+            (264u32, 151u32),
+            (265u32, 151u32),
+            (266u32, 151u32),
+            (267u32, 151u32),
+            (268u32, 151u32),
+            (269u32, 151u32),
+            (270u32, 151u32),
+            (272u32, 154u32),
+            (273u32, 151u32),
+            (274u32, 151u32),
+            (275u32, 151u32),
+            (276u32, 151u32),
+            (277u32, 151u32),
+            (310u32, 93u32),
+            (311u32, 93u32),
+            (312u32, 93u32),
+            (313u32, 94u32),
+            (314u32, 95u32),
+            (325u32, 96u32),
+            (326u32, 155u32),
+            (330u32, 97u32),
+            (331u32, 97u32),
+            (332u32, 97u32),
+            (333u32, 97u32),
+            (335u32, 98u32),
+            (336u32, 98u32),
+            (337u32, 98u32),
+            (338u32, 98u32),
+            (339u32, 98u32),
+            (341u32, 97u32),
+            (342u32, 97u32),
+            (343u32, 97u32),
+            (344u32, 97u32),
+            (345u32, 97u32),
+            (346u32, 97u32),
+            (347u32, 97u32),
+            (348u32, 97u32),
+            (349u32, 98u32),
+            (350u32, 98u32),
+            (351u32, 98u32),
+            (352u32, 98u32),
+            (359u32, 21u32),
+            (368u32, 21u32),
+            (390u32, 99u32),
+            (391u32, 99u32),
+            (392u32, 99u32),
+            (393u32, 99u32),
+            (394u32, 99u32),
+            (395u32, 99u32),
+            (396u32, 99u32),
+            (397u32, 99u32),
+            (398u32, 99u32),
+            (400u32, 100u32),
+            (401u32, 101u32),
+            (402u32, 101u32),
+            (403u32, 101u32),
+            (404u32, 101u32),
+            (405u32, 101u32),
+            (406u32, 102u32),
+            (407u32, 102u32),
+            (408u32, 102u32),
+            (430u32, 156u32),
+            (431u32, 157u32),
+            (432u32, 158u32),
+            (433u32, 159u32),
+            (434u32, 156u32),
+            (435u32, 156u32),
+            (436u32, 160u32),
+            (437u32, 161u32),
+            (438u32, 161u32),
+            (439u32, 162u32),
+            (440u32, 163u32),
+            (441u32, 163u32),
+            (442u32, 163u32),
+            (443u32, 163u32),
+            (444u32, 164u32),
+            (445u32, 164u32),
+            (446u32, 164u32),
+            (447u32, 165u32),
+            (448u32, 165u32),
+            (449u32, 165u32),
+            (451u32, 166u32),
+            (452u32, 156u32),
+            (453u32, 156u32),
+            (454u32, 156u32),
+            (455u32, 156u32),
+            (456u32, 156u32),
+            (457u32, 156u32),
+            (458u32, 156u32),
+            (459u32, 156u32),
+            (460u32, 156u32),
+            (461u32, 156u32),
+            (462u32, 163u32),
+            (463u32, 163u32),
+            (473u32, 114u32),
+            (474u32, 114u32),
+            (500u32, 115u32),
+            (501u32, 116u32),
+            (502u32, 115u32),
+            (503u32, 115u32),
+            (504u32, 115u32),
+            (506u32, 115u32),
+            (543u32, 167u32),
+            (544u32, 168u32),
+            (545u32, 169u32),
+            (546u32, 169u32),
+            (547u32, 170u32),
+            (548u32, 171u32),
+            (549u32, 171u32),
+            (550u32, 171u32),
+            (551u32, 172u32),
+            (552u32, 173u32),
+            (553u32, 172u32),
+            (554u32, 173u32),
+            (555u32, 174u32),
+            (556u32, 174u32),
+            (557u32, 171u32),
+            (558u32, 171u32),
+            (559u32, 171u32),
+            (560u32, 169u32),
+            (561u32, 169u32),
+            (562u32, 174u32),
+            (563u32, 175u32),
+            (564u32, 174u32),
+            (565u32, 175u32),
+            (615u32, 123u32),
+            (616u32, 123u32),
+            (619u32, 123u32),
+            (620u32, 124u32),
+            (621u32, 123u32),
+            (622u32, 125u32),
+            (623u32, 126u32),
+            (630u32, 150u32),
+            (631u32, 150u32),
+            (632u32, 150u32),
+            (633u32, 150u32),
+            (634u32, 150u32),
+            (635u32, 176u32),
+            (637u32, 177u32),
+            (638u32, 151u32),
+            (639u32, 151u32),
+            (640u32, 151u32),
+            (641u32, 151u32),
+            (642u32, 151u32),
+            (643u32, 178u32),
+            (644u32, 178u32),
+            (645u32, 178u32),
+            (646u32, 151u32),
+            (647u32, 151u32),
+            (648u32, 151u32),
+            (649u32, 151u32),
+            (650u32, 151u32),
+            (651u32, 176u32),
+            (652u32, 151u32),
+            (653u32, 151u32),
+            (654u32, 151u32),
+            (655u32, 151u32),
+            (656u32, 151u32),
+            (657u32, 151u32),
+            (658u32, 151u32),
+            (659u32, 151u32),
+            (660u32, 151u32),
+            (661u32, 154u32),
+            (662u32, 151u32),
+            (663u32, 151u32),
+            (664u32, 179u32),
+            (665u32, 154u32),
+            (666u32, 178u32),
+            (667u32, 178u32),
+            (668u32, 151u32),
+            (669u32, 151u32),
+            (670u32, 151u32),
+            (671u32, 180u32),
+            (681u32, 21u32),
+            (682u32, 21u32),
+            (683u32, 21u32),
+            (684u32, 21u32),
+            (685u32, 21u32),
+            (686u32, 21u32),
+        ],
+    ),
+    (
+        "1.87.0",
+        &[
+            (9u32, 60u32),
+            (10u32, 61u32),
+            (11u32, 62u32),
+            (12u32, 62u32),
+            (13u32, 61u32),
+            (14u32, 62u32),
+            (15u32, 61u32),
+            (16u32, 62u32),
+            (17u32, 61u32),
+            (18u32, 62u32),
+            (20u32, 63u32),
+            (21u32, 63u32),
+            (22u32, 63u32),
+            (23u32, 63u32),
+            (24u32, 63u32),
+            (25u32, 63u32),
+            (26u32, 181u32),
+            (27u32, 63u32),
+            (28u32, 63u32),
+            (29u32, 63u32),
+            (30u32, 63u32),
+            (31u32, 63u32),
+            (32u32, 63u32),
+            (33u32, 63u32),
+            (34u32, 63u32),
+            (35u32, 64u32),
+            (36u32, 63u32),
+            (37u32, 63u32),
+            (38u32, 63u32),
+            (39u32, 63u32),
+            (40u32, 63u32),
+            (41u32, 63u32),
+            (42u32, 63u32),
+            (43u32, 63u32),
+            (44u32, 65u32),
+            (45u32, 63u32),
+            (46u32, 63u32),
+            (47u32, 65u32),
+            (48u32, 63u32),
+            (49u32, 63u32),
+            (50u32, 63u32),
+            (60u32, 21u32),
+            (67u32, 132u32),
+            (68u32, 133u32),
+            (69u32, 134u32),
+            (70u32, 133u32),
+            (71u32, 134u32),
+            (72u32, 62u32),
+            (73u32, 60u32),
+            (74u32, 62u32),
+            (75u32, 62u32),
+            (77u32, 63u32),
+            (79u32, 135u32),
+            (80u32, 136u32),
+            (81u32, 137u32),
+            (82u32, 138u32),
+            (83u32, 138u32),
+            (84u32, 139u32),
+            (85u32, 139u32),
+            (86u32, 139u32),
+            (87u32, 139u32),
+            (88u32, 140u32),
+            (89u32, 140u32),
+            (90u32, 141u32),
+            (91u32, 142u32),
+            (92u32, 143u32),
+            (93u32, 144u32),
+            (94u32, 145u32),
+            (95u32, 146u32),
+            (96u32, 145u32),
+            (97u32, 146u32),
+            (98u32, 145u32),
+            (99u32, 146u32),
+            (100u32, 146u32),
+            (101u32, 145u32),
+            (102u32, 145u32),
+            (103u32, 146u32),
+            (104u32, 145u32),
+            (105u32, 146u32),
+            (106u32, 145u32),
+            (107u32, 146u32),
+            (108u32, 145u32),
+            (109u32, 146u32),
+            (110u32, 143u32),
+            (111u32, 147u32),
+            (112u32, 136u32),
+            (113u32, 137u32),
+            (114u32, 148u32),
+            (115u32, 149u32),
+            (118u32, 21u32),
+            (154u32, 84u32),
+            (155u32, 84u32),
+            (167u32, 85u32),
+            (168u32, 86u32),
+            (243u32, 87u32),
+            (244u32, 87u32),
+            (251u32, 150u32),
+            (254u32, 152u32),
+            (255u32, 152u32),
+            (256u32, 152u32),
+            (257u32, 152u32),
+            (258u32, 150u32),
+            (259u32, 153u32),
+            (260u32, 151u32),
+            (261u32, 151u32),
+            (262u32, 151u32),
+            (263u32, 151u32),
+            (264u32, 151u32),
+            (265u32, 151u32),
+            (266u32, 151u32),
+            (267u32, 151u32),
+            (268u32, 151u32),
+            (269u32, 151u32),
+            (270u32, 151u32),
+            (272u32, 154u32),
+            (273u32, 151u32),
+            (274u32, 151u32),
+            (275u32, 151u32),
+            (276u32, 151u32),
+            (277u32, 151u32),
+            (310u32, 182u32),
+            (311u32, 182u32),
+            (312u32, 182u32),
+            (313u32, 182u32),
+            (314u32, 183u32),
+            (325u32, 96u32),
+            (326u32, 155u32),
+            (330u32, 97u32),
+            (331u32, 97u32),
+            (332u32, 97u32),
+            (333u32, 97u32),
+            (335u32, 98u32),
+            (336u32, 98u32),
+            (337u32, 98u32),
+            (338u32, 98u32),
+            (339u32, 98u32),
+            (341u32, 97u32),
+            (342u32, 97u32),
+            (343u32, 97u32),
+            (344u32, 97u32),
+            (345u32, 97u32),
+            (346u32, 97u32),
+            (347u32, 97u32),
+            (348u32, 97u32),
+            (349u32, 98u32),
+            (350u32, 98u32),
+            (351u32, 98u32),
+            (352u32, 98u32),
+            (359u32, 21u32),
+            (368u32, 21u32),
+            (390u32, 184u32),
+            (391u32, 184u32),
+            (392u32, 185u32),
+            (393u32, 184u32),
+            (394u32, 185u32),
+            (395u32, 184u32),
+            (396u32, 184u32),
+            (397u32, 184u32),
+            (398u32, 185u32),
+            (400u32, 186u32),
+            (401u32, 187u32),
+            (402u32, 187u32),
+            (403u32, 187u32),
+            (404u32, 187u32),
+            (405u32, 187u32),
+            (406u32, 188u32),
+            (407u32, 188u32),
+            (408u32, 188u32),
+            (430u32, 189u32),
+            (431u32, 190u32),
+            (432u32, 191u32),
+            (433u32, 192u32),
+            (434u32, 189u32),
+            (435u32, 189u32),
+            (436u32, 193u32),
+            (437u32, 194u32),
+            (438u32, 194u32),
+            (439u32, 195u32),
+            (440u32, 196u32),
+            (441u32, 196u32),
+            (442u32, 196u32),
+            (443u32, 196u32),
+            (444u32, 197u32),
+            (445u32, 197u32),
+            (446u32, 197u32),
+            (447u32, 198u32),
+            (448u32, 198u32),
+            (449u32, 198u32),
+            (451u32, 199u32),
+            (452u32, 189u32),
+            (453u32, 189u32),
+            (454u32, 189u32),
+            (455u32, 189u32),
+            (456u32, 189u32),
+            (457u32, 189u32),
+            (458u32, 189u32),
+            (459u32, 189u32),
+            (460u32, 189u32),
+            (461u32, 189u32),
+            (462u32, 196u32),
+            (463u32, 196u32),
+            (473u32, 200u32),
+            (474u32, 200u32),
+            (500u32, 115u32),
+            (501u32, 116u32),
+            (502u32, 201u32),
+            (503u32, 201u32),
+            (504u32, 201u32),
+            (506u32, 201u32),
+            (543u32, 167u32),
+            (544u32, 168u32),
+            (545u32, 169u32),
+            (546u32, 169u32),
+            (547u32, 170u32),
+            (548u32, 171u32),
+            (549u32, 171u32),
+            (550u32, 171u32),
+            (551u32, 172u32),
+            (552u32, 173u32),
+            (553u32, 172u32),
+            (554u32, 173u32),
+            (555u32, 174u32),
+            (556u32, 174u32),
+            (557u32, 171u32),
+            (558u32, 171u32),
+            (559u32, 171u32),
+            (560u32, 169u32),
+            (561u32, 169u32),
+            (562u32, 174u32),
+            (563u32, 175u32),
+            (564u32, 174u32),
+            (565u32, 175u32),
+            (615u32, 126u32),
+            (616u32, 126u32),
+            (617u32, 202u32),
+            (619u32, 126u32),
+            (620u32, 202u32),
+            (621u32, 126u32),
+            (622u32, 125u32),
+            (623u32, 126u32),
+            (630u32, 150u32),
+            (631u32, 150u32),
+            (632u32, 150u32),
+            (633u32, 150u32),
+            (634u32, 150u32),
+            (635u32, 176u32),
+            (637u32, 177u32),
+            (638u32, 151u32),
+            (639u32, 151u32),
+            (640u32, 151u32),
+            (641u32, 151u32),
+            (642u32, 151u32),
+            (643u32, 178u32),
+            (644u32, 178u32),
+            (645u32, 178u32),
+            (646u32, 151u32),
+            (647u32, 151u32),
+            (648u32, 151u32),
+            (649u32, 203u32),
+            (650u32, 151u32),
+            (651u32, 176u32),
+            (652u32, 151u32),
+            (653u32, 151u32),
+            (654u32, 151u32),
+            (655u32, 151u32),
+            (656u32, 151u32),
+            (657u32, 151u32),
+            (658u32, 151u32),
+            (659u32, 151u32),
+            (660u32, 151u32),
+            (661u32, 154u32),
+            (662u32, 151u32),
+            (663u32, 151u32),
+            (664u32, 179u32),
+            (665u32, 154u32),
+            (666u32, 178u32),
+            (667u32, 178u32),
+            (668u32, 151u32),
+            (669u32, 151u32),
+            (670u32, 151u32),
+            (671u32, 180u32),
+            (681u32, 21u32),
+            (682u32, 21u32),
+            (683u32, 21u32),
+            (684u32, 21u32),
+            (685u32, 21u32),
+            (686u32, 21u32),
+        ],
+    ),
+];
+const fn feature(
+    name: u32,
+    gate: u32,
+    globally_enabled: bool,
+    implies_features: u32,
+) -> consts::Feature {
+    consts::Feature {
+        name: NAMES[name as usize],
+        unstable_feature_gate: match gate.checked_sub(1) {
+            Some(idx) => Some(NAMES[idx as usize]),
+            None => None,
+        },
+        globally_enabled,
+        implies_features: match implies_features.checked_sub(1) {
+            Some(idx) => Some(IMPLIES_FEATURES[idx as usize]),
+            None => None,
+        },
+    }
+}
+/// Find a target, returning its name and the IDs of its features.
+pub(crate) const fn find(
+    rust_version: &str,
+    target: &str,
+) -> Option<(&'static str, &'static [u32])> {
+    let mut v = 0;
+    while v < TARGETS.len() {
+        let (version, targets) = TARGETS[v];
+        if consts::str_eq(version, rust_version) {
+            let mut t = 0;
+            while t < targets.len() {
+                let (name, feature_list) = targets[t];
+                if consts::str_eq(NAMES[name as usize], target) {
+                    return Some((
+                        NAMES[name as usize],
+                        FEATURE_LISTS[feature_list as usize],
+                    ));
+                }
+                t += 1;
+            }
+            return None;
+        }
+        v += 1;
+    }
+    None
+}
+pub(crate) const fn get_feature(id: u32) -> consts::Feature {
+    FEATURES[id as usize]
+}
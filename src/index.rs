@@ -0,0 +1,60 @@
+//! Finding targets by target feature.
+//!
+//! [`find()`](crate::find) answers which features a target has. This answers the reverse: which
+//! targets have a feature, using an index built by the generator.
+//!
+//! ```
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use rust_target_feature_data::index;
+//!
+//! let sse2 = index::targets_with_feature("1.86.0", "sse2")?;
+//! assert!(sse2.globally_enabled.contains(&"x86_64-unknown-linux-gnu"));
+//! assert!(!sse2.globally_enabled.contains(&"i586-unknown-linux-gnu"));
+//! assert!(sse2.exists.contains(&"i586-unknown-linux-gnu"));
+//! assert!(sse2.gated.is_empty());
+//! # Ok(()) }
+//! ```
+
+use crate::{NotFoundError, QueryError};
+
+#[cfg(test)]
+mod tests;
+
+/// The targets which have a target feature, in one Rust version.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct FeatureTargets {
+    /// The targets where this feature exists, sorted.
+    pub exists: Vec<&'static str>,
+    /// The targets which enable this feature by default, sorted.
+    pub globally_enabled: Vec<&'static str>,
+    /// The targets where this feature is unstable, sorted.
+    pub gated: Vec<&'static str>,
+}
+
+/// Find the targets which have a target feature.
+///
+/// Returns [`QueryError::UnknownFeature`] if no target has this feature in this Rust version.
+pub fn targets_with_feature(
+    rust_version: &str,
+    feature: &str,
+) -> Result<FeatureTargets, QueryError> {
+    let (_, mut features) = crate::generated::feature_index()
+        .find(|(version, _)| *version == rust_version)
+        .ok_or_else(|| NotFoundError::CompilerNotFound(rust_version.into()))?;
+    let (_, [exists, globally_enabled, gated]) = features
+        .find(|(name, _)| *name == feature)
+        .ok_or_else(|| QueryError::UnknownFeature(feature.into()))?;
+    Ok(FeatureTargets {
+        exists: crate::generated::get_target_list(exists).collect(),
+        globally_enabled: crate::generated::get_target_list(globally_enabled).collect(),
+        gated: crate::generated::get_target_list(gated).collect(),
+    })
+}
+
+/// List every target feature name in a Rust version, sorted.
+pub fn feature_names(rust_version: &str) -> Result<Vec<&'static str>, NotFoundError> {
+    let (_, features) = crate::generated::feature_index()
+        .find(|(version, _)| *version == rust_version)
+        .ok_or_else(|| NotFoundError::CompilerNotFound(rust_version.into()))?;
+    Ok(features.map(|(name, _)| name).collect())
+}
@@ -0,0 +1,62 @@
+use super::*;
+
+#[test]
+fn matches_find() {
+    for (version, targets) in crate::generated::all() {
+        let targets: Vec<(&str, Vec<_>)> = targets
+            .map(|(target, features)| (target, features.collect()))
+            .collect();
+        for name in feature_names(version).unwrap() {
+            let index = targets_with_feature(version, name).unwrap();
+            let matching = |predicate: &dyn Fn(&crate::TargetFeature) -> bool| -> Vec<&str> {
+                targets
+                    .iter()
+                    .filter(|(_, features)| features.iter().any(|f| f.name == name && predicate(f)))
+                    .map(|(target, _)| *target)
+                    .collect()
+            };
+            assert_eq!(index.exists, matching(&|_| true), "{} {}", version, name);
+            assert_eq!(
+                index.globally_enabled,
+                matching(&|f| f.globally_enabled),
+                "{} {}",
+                version,
+                name
+            );
+            assert_eq!(
+                index.gated,
+                matching(&|f| f.unstable_feature_gate.is_some()),
+                "{} {}",
+                version,
+                name
+            );
+        }
+    }
+}
+
+#[test]
+fn neon() {
+    let neon = targets_with_feature("1.86.0", "neon").unwrap();
+    assert!(neon.exists.contains(&"armv7-unknown-linux-gnueabihf"));
+    assert!(neon.globally_enabled.contains(&"aarch64-apple-darwin"));
+    assert!(
+        !neon
+            .globally_enabled
+            .contains(&"aarch64-unknown-none-softfloat")
+    );
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        targets_with_feature("1.86.0", "avx3"),
+        Err(QueryError::UnknownFeature("avx3".into()))
+    );
+    assert_eq!(
+        targets_with_feature("1.0.0", "sse2"),
+        Err(QueryError::NotFound(NotFoundError::CompilerNotFound(
+            "1.0.0".into()
+        )))
+    );
+    assert!(feature_names("1.86.0").unwrap().contains(&"sse2"));
+}
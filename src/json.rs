@@ -0,0 +1,210 @@
+//! A minimal JSON parser, supporting what target specification files contain.
+//!
+//! Everything in RFC 8259 is understood, but numbers are kept as written without being validated,
+//! since they're not needed.
+
+/// A JSON value.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) enum Value {
+    Null,
+    Bool(bool),
+    /// A number, as written
+    Number(String),
+    String(String),
+    Array(Vec<Value>),
+    /// An object's members, in order
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    /// Find a member of an object by name.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        match self {
+            Value::Object(members) => members
+                .iter()
+                .find(|(member, _)| member == name)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+/// An error parsing JSON.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct Error {
+    /// The 1-based line number where the error was found.
+    pub line: usize,
+    pub message: String,
+}
+
+/// Parse a JSON document.
+pub(crate) fn parse(json: &str) -> Result<Value, Error> {
+    let mut parser = Parser { json, offset: 0 };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    if parser.offset < json.len() {
+        return Err(parser.error("unexpected characters after value"));
+    }
+    Ok(value)
+}
+
+struct Parser<'a> {
+    json: &'a str,
+    offset: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, message: &str) -> Error {
+        Error {
+            line: self.json[..self.offset].matches('\n').count() + 1,
+            message: message.into(),
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.json[self.offset..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.offset += rest.len() - rest.trim_start_matches([' ', '\t', '\n', '\r']).len();
+    }
+
+    /// Consume `token`, after any whitespace, returning whether it was there.
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.offset += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, Error> {
+        self.skip_whitespace();
+        if self.eat("null") {
+            Ok(Value::Null)
+        } else if self.eat("true") {
+            Ok(Value::Bool(true))
+        } else if self.eat("false") {
+            Ok(Value::Bool(false))
+        } else if self.rest().starts_with('"') {
+            self.string().map(Value::String)
+        } else if self.eat("[") {
+            let mut values = Vec::new();
+            if self.eat("]") {
+                return Ok(Value::Array(values));
+            }
+            loop {
+                values.push(self.value()?);
+                if self.eat("]") {
+                    return Ok(Value::Array(values));
+                } else if !self.eat(",") {
+                    return Err(self.error("expected , or ] in array"));
+                }
+            }
+        } else if self.eat("{") {
+            let mut members = Vec::new();
+            if self.eat("}") {
+                return Ok(Value::Object(members));
+            }
+            loop {
+                self.skip_whitespace();
+                if !self.rest().starts_with('"') {
+                    return Err(self.error("expected a string key"));
+                }
+                let name = self.string()?;
+                if !self.eat(":") {
+                    return Err(self.error("expected : after key"));
+                }
+                members.push((name, self.value()?));
+                if self.eat("}") {
+                    return Ok(Value::Object(members));
+                } else if !self.eat(",") {
+                    return Err(self.error("expected , or } in object"));
+                }
+            }
+        } else {
+            let len = self
+                .rest()
+                .find(|c: char| !(c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E')))
+                .unwrap_or(self.rest().len());
+            if len == 0 {
+                return Err(self.error("expected a value"));
+            }
+            let number = self.rest()[..len].to_string();
+            self.offset += len;
+            Ok(Value::Number(number))
+        }
+    }
+
+    /// Parse a string, starting at its opening quote.
+    fn string(&mut self) -> Result<String, Error> {
+        self.offset += 1;
+        let mut value = String::new();
+        loop {
+            let mut chars = self.rest().chars();
+            let Some(c) = chars.next() else {
+                return Err(self.error("unterminated string"));
+            };
+            self.offset += c.len_utf8();
+            match c {
+                '"' => return Ok(value),
+                '\\' => {
+                    let escape = chars
+                        .next()
+                        .ok_or_else(|| self.error("unterminated string"))?;
+                    self.offset += escape.len_utf8();
+                    match escape {
+                        '"' | '\\' | '/' => value.push(escape),
+                        'b' => value.push('\u{8}'),
+                        'f' => value.push('\u{c}'),
+                        'n' => value.push('\n'),
+                        'r' => value.push('\r'),
+                        't' => value.push('\t'),
+                        'u' => {
+                            let high = self.hex4()?;
+                            let c = if (0xd800..0xdc00).contains(&high) {
+                                // A surrogate pair
+                                if !self.rest().starts_with("\\u") {
+                                    return Err(self.error("unpaired surrogate"));
+                                }
+                                self.offset += 2;
+                                match self.hex4()? {
+                                    low @ 0xdc00..0xe000 => char::from_u32(
+                                        0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00),
+                                    ),
+                                    _ => None,
+                                }
+                            } else {
+                                char::from_u32(high)
+                            };
+                            value.push(c.ok_or_else(|| self.error("invalid \\u escape"))?);
+                        }
+                        _ => return Err(self.error("invalid escape")),
+                    }
+                }
+                c if c < ' ' => return Err(self.error("control character in string")),
+                c => value.push(c),
+            }
+        }
+    }
+
+    fn hex4(&mut self) -> Result<u32, Error> {
+        let digits = self.rest().get(..4).unwrap_or_default();
+        if digits.len() != 4 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(self.error("invalid \\u escape"));
+        }
+        let value = u32::from_str_radix(digits, 16).unwrap();
+        self.offset += 4;
+        Ok(value)
+    }
+}
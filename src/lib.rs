@@ -8,6 +8,7 @@
 //!
 //! This crate provides target feature data for all targets covering Rust versions:
 //!
+//! * `"1.81.0"` (implied features are unknown, so `implies_features` is `None`)
 //! * `"1.85.0"` (1.85.1 is identical)
 //! * `"1.86.0"`
 //! * `"1.87.0"` (from 1.87.0-beta.5)
@@ -36,6 +37,34 @@ use std::collections::BTreeSet;
 
 #[rustfmt::skip]
 mod generated;
+#[cfg(feature = "const")]
+#[rustfmt::skip]
+mod generated_const;
+
+pub mod build_script;
+pub mod cargo_config;
+pub mod catalog;
+pub mod cc;
+#[cfg(feature = "const")]
+pub mod consts;
+pub mod custom_target;
+pub mod detect;
+pub mod equivalence;
+pub mod gates;
+pub mod index;
+mod json;
+#[cfg(target_os = "linux")]
+pub mod linux;
+pub mod llvm;
+pub mod msrv;
+pub mod multiversion;
+pub mod safe_call;
+pub mod scan;
+mod toml;
+pub mod toolchain;
+pub mod triple;
+
+use triple::target_arch;
 
 /// Information about a target feature.
 ///
@@ -63,7 +92,10 @@ pub struct TargetFeature {
     /// The name of this target feature.
     pub name: &'static str,
     /// Other target features which are implied by this target feature, if any.
-    pub implies_features: BTreeSet<&'static str>,
+    ///
+    /// `None` if the data for this Rust version doesn't record implications, as for 1.81.0. This
+    /// is distinct from `Some` of an empty set, which means the feature implies nothing.
+    pub implies_features: Option<BTreeSet<&'static str>>,
     /// If this target feature is unstable, the name of the associated language feature gate.
     pub unstable_feature_gate: Option<&'static str>,
     /// Whether this feature is globally enabled by default.
@@ -105,6 +137,44 @@ impl std::fmt::Display for NotFoundError {
     }
 }
 
+/// An error answering a question about specific target features.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum QueryError {
+    /// The compiler version or target was not found
+    NotFound(NotFoundError),
+    /// The target feature does not exist for this compiler version and target
+    UnknownFeature(String),
+    /// The target feature's implications are not recorded for this compiler version
+    UnknownImplications(String),
+}
+
+impl std::error::Error for QueryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            QueryError::NotFound(e) => Some(e),
+            QueryError::UnknownFeature(_) | QueryError::UnknownImplications(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for QueryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            QueryError::NotFound(e) => e.fmt(f),
+            QueryError::UnknownFeature(name) => write!(f, "target feature {:?} not found", name),
+            QueryError::UnknownImplications(name) => {
+                write!(f, "implications of target feature {:?} are unknown", name)
+            }
+        }
+    }
+}
+
+impl From<NotFoundError> for QueryError {
+    fn from(value: NotFoundError) -> Self {
+        QueryError::NotFound(value)
+    }
+}
+
 /// Find the target features applicable to a Rust version and target.
 ///
 /// ```
@@ -156,5 +226,62 @@ pub fn find(
         .ok_or_else(|| NotFoundError::TargetNotFound(target.into()))
 }
 
+/// Expand a list of target feature names to include every feature they imply, transitively.
+///
+/// Returns [`QueryError::UnknownFeature`] for the first name which is not in `features`, and
+/// [`QueryError::UnknownImplications`] for the first feature whose implications are unknown.
+pub(crate) fn implied_closure<'a>(
+    features: &[TargetFeature],
+    names: impl IntoIterator<Item = &'a str>,
+) -> Result<BTreeSet<&'static str>, QueryError> {
+    let mut closure = BTreeSet::new();
+    let mut pending: Vec<&str> = names.into_iter().collect();
+    while let Some(name) = pending.pop() {
+        let feature = features
+            .iter()
+            .find(|f| f.name == name)
+            .ok_or_else(|| QueryError::UnknownFeature(name.into()))?;
+        if closure.insert(feature.name) {
+            let implies_features = feature
+                .implies_features
+                .as_ref()
+                .ok_or_else(|| QueryError::UnknownImplications(feature.name.into()))?;
+            pending.extend(implies_features.iter().copied());
+        }
+    }
+    Ok(closure)
+}
+
+/// List the embedded Rust versions, oldest first.
+pub(crate) fn versions() -> Vec<&'static str> {
+    let mut versions: Vec<&'static str> = generated::all().map(|(version, _)| version).collect();
+    versions.sort_by_key(|version| {
+        version
+            .split('.')
+            .map(|part| part.parse::<u64>().unwrap_or(0))
+            .collect::<Vec<_>>()
+    });
+    versions
+}
+
+/// Find the embedded Rust version describing a `rustc` release.
+///
+/// Pre-releases use the data of the release they lead up to, and patch releases use the data of
+/// their minor release.
+pub(crate) fn resolve_version(release: &str) -> Option<&'static str> {
+    let versions = || generated::all().map(|(version, _)| version);
+    if let Some(version) = versions().find(|version| *version == release) {
+        return Some(version);
+    }
+
+    let release = release
+        .split_once('-')
+        .map_or(release, |(release, _)| release);
+    let mut parts = release.split('.');
+    let (major, minor) = (parts.next()?, parts.next()?);
+    let minor_release = format!("{}.{}.0", major, minor);
+    versions().find(|version| *version == minor_release)
+}
+
 #[cfg(test)]
 mod tests;
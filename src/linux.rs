@@ -0,0 +1,629 @@
+//! Runtime CPU feature detection on Linux.
+//!
+//! The running machine describes its capabilities in two places: the flags listed in
+//! `/proc/cpuinfo`, and the `AT_HWCAP`/`AT_HWCAP2` words in the auxiliary vector, which are readable
+//! from `/proc/self/auxv`. Neither uses Rust's target feature names. This module reads both
+//! sources and translates them into the target features which exist for a particular Rust version
+//! and target, so that they can be compared against the features a binary was compiled for.
+//!
+//! x86, x86-64, AArch64, RISC-V, and PowerPC hosts are supported.
+//!
+//! ```no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use rust_target_feature_data::{find, linux};
+//!
+//! let host = linux::detect("1.86.0", "x86_64-unknown-linux-gnu")?;
+//! let missing = host.missing(find("1.86.0", "x86_64-unknown-linux-gnu")?);
+//! assert!(missing.is_empty(), "this host cannot run this binary: {:?}", missing);
+//! # Ok(()) }
+//! ```
+
+use crate::{NotFoundError, TargetFeature};
+use std::collections::BTreeSet;
+
+#[cfg(test)]
+mod tests;
+
+/// Target features detected on a Linux host.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct HostFeatures {
+    /// Target features which the host supports.
+    pub supported: BTreeSet<&'static str>,
+    /// Target features which can be detected, but which the host does not support.
+    pub unsupported: BTreeSet<&'static str>,
+    /// Target features which neither `/proc/cpuinfo` nor the auxiliary vector describe.
+    pub undetectable: BTreeSet<&'static str>,
+}
+
+impl HostFeatures {
+    /// Find the globally enabled target features which this host does not support.
+    ///
+    /// A binary compiled with any of these features enabled may execute instructions which this
+    /// host cannot run. Features in [`undetectable`](Self::undetectable) are not reported.
+    pub fn missing(
+        &self,
+        compiled: impl IntoIterator<Item = TargetFeature>,
+    ) -> BTreeSet<&'static str> {
+        compiled
+            .into_iter()
+            .filter(|feature| feature.globally_enabled)
+            .map(|feature| feature.name)
+            .filter(|name| self.unsupported.contains(name))
+            .collect()
+    }
+}
+
+/// An error detecting host features on Linux.
+#[derive(Debug)]
+pub enum LinuxError {
+    /// The Rust version or target was not found
+    NotFound(NotFoundError),
+    /// The target's architecture is not supported by this module
+    UnsupportedArchitecture(String),
+    /// Reading `/proc/cpuinfo` or `/proc/self/auxv` failed
+    Io(std::io::Error),
+}
+
+impl std::error::Error for LinuxError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LinuxError::NotFound(e) => Some(e),
+            LinuxError::UnsupportedArchitecture(_) => None,
+            LinuxError::Io(e) => Some(e),
+        }
+    }
+}
+
+impl std::fmt::Display for LinuxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            LinuxError::NotFound(e) => e.fmt(f),
+            LinuxError::UnsupportedArchitecture(arch) => {
+                write!(f, "architecture {:?} is not supported", arch)
+            }
+            LinuxError::Io(e) => write!(f, "reading host information: {}", e),
+        }
+    }
+}
+
+impl From<NotFoundError> for LinuxError {
+    fn from(value: NotFoundError) -> Self {
+        LinuxError::NotFound(value)
+    }
+}
+
+/// Detect the target features supported by the running machine.
+///
+/// `target` should be the target the caller was compiled for, which determines both the
+/// architecture and the set of target features under consideration.
+pub fn detect(rust_version: &str, target: &str) -> Result<HostFeatures, LinuxError> {
+    let cpuinfo = std::fs::read_to_string("/proc/cpuinfo").map_err(LinuxError::Io)?;
+    let auxv = std::fs::read("/proc/self/auxv").map_err(LinuxError::Io)?;
+    detect_from(rust_version, target, &cpuinfo, &auxv)
+}
+
+/// Detect target features from captured `/proc/cpuinfo` and `/proc/self/auxv` contents.
+///
+/// The auxiliary vector is decoded using the word size and byte order of `target`.
+pub fn detect_from(
+    rust_version: &str,
+    target: &str,
+    cpuinfo: &str,
+    auxv: &[u8],
+) -> Result<HostFeatures, LinuxError> {
+    let features = crate::find(rust_version, target)?;
+    let arch = crate::target_arch(target);
+    let Some(table) = Arch::for_target_arch(arch) else {
+        return Err(LinuxError::UnsupportedArchitecture(arch.into()));
+    };
+
+    // Gather everything the host says about itself, in the host's own vocabulary
+    let mut host: BTreeSet<&str> = table.cpuinfo_flags(cpuinfo);
+    let (hwcap, hwcap2) = parse_auxv(auxv, auxv_layout(target));
+    for (word, names) in [(hwcap, table.hwcap), (hwcap2, table.hwcap2)] {
+        let word = word.unwrap_or(0);
+        host.extend(
+            names
+                .iter()
+                .enumerate()
+                .filter(|(bit, name)| !name.is_empty() && word & (1 << bit) != 0)
+                .map(|(_, name)| *name),
+        );
+    }
+
+    let mut detected = HostFeatures {
+        supported: BTreeSet::new(),
+        unsupported: BTreeSet::new(),
+        undetectable: BTreeSet::new(),
+    };
+    for feature in features {
+        match table
+            .features
+            .iter()
+            .find(|(name, _)| *name == feature.name)
+        {
+            Some((_, requires)) if requires.iter().all(|r| host.contains(r)) => {
+                detected.supported.insert(feature.name)
+            }
+            Some(_) => detected.unsupported.insert(feature.name),
+            None => detected.undetectable.insert(feature.name),
+        };
+    }
+    Ok(detected)
+}
+
+/// The word size in bytes and byte order of the auxiliary vector for a target.
+fn auxv_layout(target: &str) -> (usize, bool) {
+    let word_size = match crate::target_arch(target) {
+        "x86_64" | "aarch64" | "riscv64" | "powerpc64" => 8,
+        _ => 4,
+    };
+    let big_endian = target.starts_with("aarch64_be-")
+        || (target.starts_with("powerpc") && !target.starts_with("powerpc64le-"));
+    (word_size, big_endian)
+}
+
+/// Extract `AT_HWCAP` and `AT_HWCAP2` from the auxiliary vector.
+fn parse_auxv(auxv: &[u8], (word_size, big_endian): (usize, bool)) -> (Option<u64>, Option<u64>) {
+    const AT_NULL: u64 = 0;
+    const AT_HWCAP: u64 = 16;
+    const AT_HWCAP2: u64 = 26;
+
+    let mut words = auxv.chunks_exact(word_size).map(|bytes| {
+        let mut value = 0u64;
+        for i in 0..word_size {
+            let byte = if big_endian {
+                bytes[i]
+            } else {
+                bytes[word_size - 1 - i]
+            };
+            value = (value << 8) | u64::from(byte);
+        }
+        value
+    });
+
+    let (mut hwcap, mut hwcap2) = (None, None);
+    while let (Some(key), Some(value)) = (words.next(), words.next()) {
+        match key {
+            AT_NULL => break,
+            AT_HWCAP => hwcap = Some(value),
+            AT_HWCAP2 => hwcap2 = Some(value),
+            _ => {}
+        }
+    }
+    (hwcap, hwcap2)
+}
+
+/// How one architecture describes its features on Linux.
+struct Arch {
+    /// The `/proc/cpuinfo` field listing features, if any.
+    cpuinfo_key: Option<&'static str>,
+    /// Names of `AT_HWCAP` bits, indexed by bit number.
+    hwcap: &'static [&'static str],
+    /// Names of `AT_HWCAP2` bits, indexed by bit number.
+    hwcap2: &'static [&'static str],
+    /// Rust target features, and the host names which must all be present to support them.
+    features: &'static [(&'static str, &'static [&'static str])],
+}
+
+impl Arch {
+    fn for_target_arch(arch: &str) -> Option<&'static Arch> {
+        match arch {
+            "x86" | "x86_64" => Some(&X86),
+            "aarch64" => Some(&AARCH64),
+            "riscv32" | "riscv64" => Some(&RISCV),
+            "powerpc" | "powerpc64" => Some(&POWERPC),
+            _ => None,
+        }
+    }
+
+    /// Extract feature names from the first processor listed in `/proc/cpuinfo`.
+    fn cpuinfo_flags<'a>(&self, cpuinfo: &'a str) -> BTreeSet<&'a str> {
+        let Some(key) = self.cpuinfo_key else {
+            return BTreeSet::new();
+        };
+        let Some(value) = cpuinfo.lines().find_map(|line| {
+            let (k, v) = line.split_once(':')?;
+            (k.trim() == key).then_some(v.trim())
+        }) else {
+            return BTreeSet::new();
+        };
+
+        if key == "isa" {
+            parse_riscv_isa(value)
+        } else {
+            value.split_whitespace().collect()
+        }
+    }
+}
+
+/// Split a RISC-V ISA string like `rv64imafdc_zicsr_zba` into extension names.
+///
+/// Extensions may have versions, like `rv64i2p1_m2p0_zicsr2p0`, which are ignored.
+fn parse_riscv_isa(isa: &str) -> BTreeSet<&str> {
+    let isa = isa
+        .strip_prefix("rv32")
+        .or_else(|| isa.strip_prefix("rv64"))
+        .unwrap_or(isa);
+
+    let mut extensions = BTreeSet::new();
+    for (i, part) in isa.split('_').enumerate() {
+        if part.is_empty() {
+            continue;
+        }
+        // The first part is all single-letter extensions, and so is any later part which is one
+        // letter and a version
+        let single = i == 0
+            || part
+                .get(1..)
+                .is_some_and(|version| version_len(version) == version.len());
+        if !single {
+            extensions.insert(strip_trailing_version(part));
+            continue;
+        }
+        let mut rest = part;
+        while let Some(c) = rest.chars().next() {
+            if c == 'g' {
+                extensions.extend(["i", "m", "a", "f", "d", "zicsr", "zifencei"]);
+            } else if c.is_ascii_lowercase() {
+                extensions.insert(&rest[..1]);
+            }
+            let rest_start = c.len_utf8();
+            rest = &rest[rest_start + version_len(&rest[rest_start..])..];
+        }
+    }
+    extensions
+}
+
+/// Find the length of the `<major>` or `<major>p<minor>` version which `s` starts with.
+fn version_len(s: &str) -> usize {
+    let digits = |s: &str| s.bytes().take_while(u8::is_ascii_digit).count();
+    let major = digits(s);
+    if major == 0 {
+        return 0;
+    }
+    match s[major..].strip_prefix('p').map(digits) {
+        Some(minor) if minor > 0 => major + 1 + minor,
+        _ => major,
+    }
+}
+
+/// Remove the version from the end of a multi-letter extension name, like `zba1p0`.
+fn strip_trailing_version(name: &str) -> &str {
+    let without_minor = name.trim_end_matches(|c: char| c.is_ascii_digit());
+    if let Some(major) = without_minor
+        .strip_suffix('p')
+        .filter(|major| major.ends_with(|c: char| c.is_ascii_digit()))
+    {
+        return major.trim_end_matches(|c: char| c.is_ascii_digit());
+    }
+    without_minor
+}
+
+static X86: Arch = Arch {
+    cpuinfo_key: Some("flags"),
+    // CPUID leaf 1, EDX
+    hwcap: &[
+        "fpu", "vme", "de", "pse", "tsc", "msr", "pae", "mce", "cx8", "apic", "", "sep", "mtrr",
+        "pge", "mca", "cmov", "pat", "pse36", "pn", "clflush", "", "dts", "acpi", "mmx", "fxsr",
+        "sse", "sse2", "ss", "ht", "tm", "ia64", "pbe",
+    ],
+    hwcap2: &["ring3mwait", "fsgsbase"],
+    features: &[
+        ("adx", &["adx"]),
+        ("aes", &["aes"]),
+        ("amx-bf16", &["amx_bf16"]),
+        ("amx-complex", &["amx_complex"]),
+        ("amx-fp16", &["amx_fp16"]),
+        ("amx-int8", &["amx_int8"]),
+        ("amx-tile", &["amx_tile"]),
+        ("avx", &["avx"]),
+        ("avx2", &["avx2"]),
+        ("avx512bf16", &["avx512_bf16"]),
+        ("avx512bitalg", &["avx512_bitalg"]),
+        ("avx512bw", &["avx512bw"]),
+        ("avx512cd", &["avx512cd"]),
+        ("avx512dq", &["avx512dq"]),
+        ("avx512f", &["avx512f"]),
+        ("avx512fp16", &["avx512_fp16"]),
+        ("avx512ifma", &["avx512ifma"]),
+        ("avx512vbmi", &["avx512vbmi"]),
+        ("avx512vbmi2", &["avx512_vbmi2"]),
+        ("avx512vl", &["avx512vl"]),
+        ("avx512vnni", &["avx512_vnni"]),
+        ("avx512vp2intersect", &["avx512_vp2intersect"]),
+        ("avx512vpopcntdq", &["avx512_vpopcntdq"]),
+        ("avxifma", &["avx_ifma"]),
+        ("avxneconvert", &["avx_ne_convert"]),
+        ("avxvnni", &["avx_vnni"]),
+        ("avxvnniint16", &["avx_vnni_int16"]),
+        ("avxvnniint8", &["avx_vnni_int8"]),
+        ("bmi1", &["bmi1"]),
+        ("bmi2", &["bmi2"]),
+        ("cmpxchg16b", &["cx16"]),
+        ("ermsb", &["erms"]),
+        ("f16c", &["f16c"]),
+        ("fma", &["fma"]),
+        ("fxsr", &["fxsr"]),
+        ("gfni", &["gfni"]),
+        ("lahfsahf", &["lahf_lm"]),
+        ("lzcnt", &["abm"]),
+        ("movbe", &["movbe"]),
+        ("pclmulqdq", &["pclmulqdq"]),
+        ("popcnt", &["popcnt"]),
+        ("prfchw", &["3dnowprefetch"]),
+        ("rdrand", &["rdrand"]),
+        ("rdseed", &["rdseed"]),
+        ("rtm", &["rtm"]),
+        ("sha", &["sha_ni"]),
+        ("sha512", &["sha512"]),
+        ("sm3", &["sm3"]),
+        ("sm4", &["sm4"]),
+        ("sse", &["sse"]),
+        ("sse2", &["sse2"]),
+        ("sse3", &["pni"]),
+        ("sse4.1", &["sse4_1"]),
+        ("sse4.2", &["sse4_2"]),
+        ("sse4a", &["sse4a"]),
+        ("ssse3", &["ssse3"]),
+        ("tbm", &["tbm"]),
+        ("vaes", &["vaes"]),
+        ("vpclmulqdq", &["vpclmulqdq"]),
+        ("x87", &["fpu"]),
+        ("xop", &["xop"]),
+        ("xsave", &["xsave"]),
+        ("xsavec", &["xsavec"]),
+        ("xsaveopt", &["xsaveopt"]),
+        ("xsaves", &["xsaves"]),
+    ],
+};
+
+static AARCH64: Arch = Arch {
+    cpuinfo_key: Some("Features"),
+    hwcap: &[
+        "fp", "asimd", "evtstrm", "aes", "pmull", "sha1", "sha2", "crc32", "atomics", "fphp",
+        "asimdhp", "cpuid", "asimdrdm", "jscvt", "fcma", "lrcpc", "dcpop", "sha3", "sm3", "sm4",
+        "asimddp", "sha512", "sve", "asimdfhm", "dit", "uscat", "ilrcpc", "flagm", "ssbs", "sb",
+        "paca", "pacg",
+    ],
+    hwcap2: &[
+        "dcpodp",
+        "sve2",
+        "sveaes",
+        "svepmull",
+        "svebitperm",
+        "svesha3",
+        "svesm4",
+        "flagm2",
+        "frint",
+        "svei8mm",
+        "svef32mm",
+        "svef64mm",
+        "svebf16",
+        "i8mm",
+        "bf16",
+        "dgh",
+        "rng",
+        "bti",
+        "mte",
+        "ecv",
+        "afp",
+        "rpres",
+        "mte3",
+        "sme",
+        "smei16i64",
+        "smef64f64",
+        "smei8i32",
+        "smef16f32",
+        "smeb16f32",
+        "smef32f32",
+        "smefa64",
+        "wfxt",
+        "ebf16",
+        "sveebf16",
+        "cssc",
+        "rprfm",
+        "sve2p1",
+        "sme2",
+        "sme2p1",
+        "smei16i32",
+        "smebi32i32",
+        "smeb16b16",
+        "smef16f16",
+        "mops",
+        "hbc",
+        "sveb16b16",
+        "lrcpc3",
+        "lse128",
+    ],
+    features: &[
+        ("aes", &["aes", "pmull"]),
+        ("bf16", &["bf16"]),
+        ("bti", &["bti"]),
+        ("crc", &["crc32"]),
+        ("cssc", &["cssc"]),
+        ("dit", &["dit"]),
+        ("dotprod", &["asimddp"]),
+        ("dpb", &["dcpop"]),
+        ("dpb2", &["dcpodp"]),
+        ("ecv", &["ecv"]),
+        ("f32mm", &["svef32mm"]),
+        ("f64mm", &["svef64mm"]),
+        ("fcma", &["fcma"]),
+        ("fhm", &["asimdfhm"]),
+        ("flagm", &["flagm"]),
+        ("flagm2", &["flagm2"]),
+        ("fp16", &["fphp", "asimdhp"]),
+        ("frintts", &["frint"]),
+        ("hbc", &["hbc"]),
+        ("i8mm", &["i8mm"]),
+        ("jsconv", &["jscvt"]),
+        ("lse", &["atomics"]),
+        ("lse128", &["lse128"]),
+        ("lse2", &["uscat"]),
+        ("mops", &["mops"]),
+        ("mte", &["mte"]),
+        ("neon", &["fp", "asimd"]),
+        ("paca", &["paca"]),
+        ("pacg", &["pacg"]),
+        ("rand", &["rng"]),
+        ("rcpc", &["lrcpc"]),
+        ("rcpc2", &["ilrcpc"]),
+        ("rcpc3", &["lrcpc3"]),
+        ("rdm", &["asimdrdm"]),
+        ("sb", &["sb"]),
+        ("sha2", &["sha1", "sha2"]),
+        ("sha3", &["sha3", "sha512"]),
+        ("sm4", &["sm3", "sm4"]),
+        ("sme", &["sme"]),
+        ("sme-b16b16", &["smeb16b16"]),
+        ("sme-f16f16", &["smef16f16"]),
+        ("sme-f64f64", &["smef64f64"]),
+        ("sme-fa64", &["smefa64"]),
+        ("sme-i16i64", &["smei16i64"]),
+        ("sme2", &["sme2"]),
+        ("sme2p1", &["sme2p1"]),
+        ("ssbs", &["ssbs"]),
+        ("sve", &["sve"]),
+        ("sve-b16b16", &["sveb16b16"]),
+        ("sve2", &["sve2"]),
+        ("sve2-aes", &["sveaes", "svepmull"]),
+        ("sve2-bitperm", &["svebitperm"]),
+        ("sve2-sha3", &["svesha3"]),
+        ("sve2-sm4", &["svesm4"]),
+        ("sve2p1", &["sve2p1"]),
+        ("wfxt", &["wfxt"]),
+    ],
+};
+
+static RISCV: Arch = Arch {
+    cpuinfo_key: Some("isa"),
+    // One bit per single-letter extension
+    hwcap: &[
+        "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p", "q", "r",
+        "s", "t", "u", "v", "w", "x", "y", "z",
+    ],
+    hwcap2: &[],
+    features: &[
+        ("a", &["a"]),
+        ("c", &["c"]),
+        ("d", &["d"]),
+        ("e", &["e"]),
+        ("f", &["f"]),
+        ("m", &["m"]),
+        ("v", &["v"]),
+        ("zaamo", &["zaamo"]),
+        ("zabha", &["zabha"]),
+        ("zalrsc", &["zalrsc"]),
+        ("zba", &["zba"]),
+        ("zbb", &["zbb"]),
+        ("zbc", &["zbc"]),
+        ("zbkb", &["zbkb"]),
+        ("zbkc", &["zbkc"]),
+        ("zbkx", &["zbkx"]),
+        ("zbs", &["zbs"]),
+        ("zdinx", &["zdinx"]),
+        ("zfh", &["zfh"]),
+        ("zfhmin", &["zfhmin"]),
+        ("zfinx", &["zfinx"]),
+        ("zhinx", &["zhinx"]),
+        ("zhinxmin", &["zhinxmin"]),
+        ("zk", &["zk"]),
+        ("zkn", &["zkn"]),
+        ("zknd", &["zknd"]),
+        ("zkne", &["zkne"]),
+        ("zknh", &["zknh"]),
+        ("zkr", &["zkr"]),
+        ("zks", &["zks"]),
+        ("zksed", &["zksed"]),
+        ("zksh", &["zksh"]),
+        ("zkt", &["zkt"]),
+    ],
+};
+
+static POWERPC: Arch = Arch {
+    // `/proc/cpuinfo` names the processor model, but does not list features
+    cpuinfo_key: None,
+    // `PPC_FEATURE_*`
+    hwcap: &[
+        "ppcle",
+        "true_le",
+        "",
+        "",
+        "",
+        "",
+        "pseries_perfmon_compat",
+        "vsx",
+        "arch_2_06",
+        "power6x",
+        "dfp",
+        "pa6t",
+        "arch_2_05",
+        "ic_snoop",
+        "smt",
+        "booke",
+        "cellbe",
+        "power5+",
+        "power5",
+        "power4",
+        "notb",
+        "efpdouble",
+        "efpsingle",
+        "spe",
+        "ucache",
+        "4xxmac",
+        "mmu",
+        "fpu",
+        "altivec",
+        "ppc601",
+        "ppc64",
+        "ppc32",
+    ],
+    // `PPC_FEATURE2_*`
+    hwcap2: &[
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "mma",
+        "arch_3_1",
+        "htm-no-suspend",
+        "scv",
+        "darn",
+        "ieee128",
+        "arch_3_00",
+        "htm-nosc",
+        "vcrypto",
+        "tar",
+        "isel",
+        "ebb",
+        "dscr",
+        "htm",
+        "arch_2_07",
+    ],
+    features: &[
+        ("altivec", &["altivec"]),
+        ("partword-atomics", &["arch_2_07"]),
+        ("power10-vector", &["vsx", "arch_3_1"]),
+        ("power8-altivec", &["altivec", "arch_2_07"]),
+        ("power8-crypto", &["altivec", "arch_2_07", "vcrypto"]),
+        ("power8-vector", &["vsx", "arch_2_07"]),
+        ("power9-altivec", &["altivec", "arch_3_00"]),
+        ("power9-vector", &["vsx", "arch_3_00"]),
+        ("quadword-atomics", &["arch_2_07"]),
+        ("vsx", &["vsx"]),
+    ],
+};
@@ -0,0 +1,150 @@
+use super::*;
+
+fn fixture(arch: &str, target: &str) -> HostFeatures {
+    let cpuinfo = std::fs::read_to_string(format!("fixtures/linux/{}/cpuinfo", arch)).unwrap();
+    let auxv = std::fs::read(format!("fixtures/linux/{}/auxv", arch)).unwrap();
+    detect_from("1.86.0", target, &cpuinfo, &auxv).unwrap()
+}
+
+fn set(names: &[&'static str]) -> BTreeSet<&'static str> {
+    names.iter().copied().collect()
+}
+
+#[test]
+fn x86_64() {
+    let host = fixture("x86_64", "x86_64-unknown-linux-gnu");
+    assert_eq!(
+        host.supported,
+        set(&[
+            "adx",
+            "aes",
+            "avx",
+            "avx2",
+            "bmi1",
+            "bmi2",
+            "cmpxchg16b",
+            "ermsb",
+            "f16c",
+            "fma",
+            "fxsr",
+            "lahfsahf",
+            "lzcnt",
+            "movbe",
+            "pclmulqdq",
+            "popcnt",
+            "prfchw",
+            "rdrand",
+            "rdseed",
+            "sha",
+            "sse",
+            "sse2",
+            "sse3",
+            "sse4.1",
+            "sse4.2",
+            "sse4a",
+            "ssse3",
+            "vaes",
+            "vpclmulqdq",
+            "x87",
+            "xsave",
+            "xsavec",
+            "xsaveopt",
+        ])
+    );
+    assert!(host.unsupported.contains("avx512f"));
+    assert!(host.undetectable.contains("soft-float"));
+}
+
+#[test]
+fn x86_64_auxv_only() {
+    let auxv = std::fs::read("fixtures/linux/x86_64/auxv").unwrap();
+    let host = detect_from("1.86.0", "x86_64-unknown-linux-gnu", "", &auxv).unwrap();
+    assert_eq!(host.supported, set(&["fxsr", "sse", "sse2", "x87"]));
+}
+
+#[test]
+fn aarch64() {
+    let host = fixture("aarch64", "aarch64-unknown-linux-gnu");
+    assert_eq!(
+        host.supported,
+        set(&[
+            "aes", "bf16", "crc", "dit", "dotprod", "dpb", "dpb2", "fcma", "fhm", "flagm", "fp16",
+            "i8mm", "jsconv", "lse", "lse2", "neon", "paca", "pacg", "rand", "rcpc", "rcpc2",
+            "rdm", "sha2", "sha3", "sm4", "ssbs", "sve",
+        ])
+    );
+    assert!(host.unsupported.contains("sve2"));
+}
+
+#[test]
+fn riscv64() {
+    let host = fixture("riscv64", "riscv64gc-unknown-linux-gnu");
+    assert_eq!(
+        host.supported,
+        set(&["a", "c", "d", "f", "m", "zba", "zbb"])
+    );
+    assert!(host.unsupported.contains("v"));
+}
+
+#[test]
+fn powerpc64le() {
+    let host = fixture("powerpc64le", "powerpc64le-unknown-linux-gnu");
+    assert_eq!(
+        host.supported,
+        set(&[
+            "altivec",
+            "partword-atomics",
+            "power8-altivec",
+            "power8-crypto",
+            "power8-vector",
+            "power9-altivec",
+            "power9-vector",
+            "quadword-atomics",
+            "vsx",
+        ])
+    );
+    assert_eq!(host.unsupported, set(&["power10-vector"]));
+}
+
+#[test]
+fn missing() {
+    let host = fixture("riscv64", "riscv64gc-unknown-linux-gnu");
+    let compiled = crate::find("1.86.0", "riscv64gc-unknown-linux-gnu").unwrap();
+    assert_eq!(host.missing(compiled), set(&[]));
+
+    let compiled = crate::find("1.86.0", "riscv64gc-unknown-linux-gnu")
+        .unwrap()
+        .map(|mut f| {
+            f.globally_enabled |= f.name == "v" || f.name == "zba";
+            f
+        });
+    assert_eq!(host.missing(compiled), set(&["v"]));
+}
+
+#[test]
+fn unsupported_architecture() {
+    let err = detect_from("1.86.0", "s390x-unknown-linux-gnu", "", &[]).unwrap_err();
+    assert!(matches!(err, LinuxError::UnsupportedArchitecture(arch) if arch == "s390x"));
+}
+
+#[test]
+fn riscv_isa() {
+    assert_eq!(
+        parse_riscv_isa("rv64gc_zba"),
+        BTreeSet::from(["a", "c", "d", "f", "i", "m", "zba", "zicsr", "zifencei"])
+    );
+
+    // Versions are ignored, including on single-letter extensions
+    assert_eq!(
+        parse_riscv_isa(
+            "rv64i2p1_m2p0_a2p1_f2p2_d2p2_c2p0_v1p0_zicsr2p0_zifencei2p0_zicbop1p0_zve32x1p0_zvl128b1p0"
+        ),
+        BTreeSet::from([
+            "a", "c", "d", "f", "i", "m", "v", "zicbop", "zicsr", "zifencei", "zve32x", "zvl128b"
+        ])
+    );
+    assert_eq!(
+        parse_riscv_isa("rv32i2p1mac2_zicbop__zba1_"),
+        BTreeSet::from(["a", "c", "i", "m", "zba", "zicbop"])
+    );
+}
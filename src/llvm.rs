@@ -0,0 +1,89 @@
+//! Converting between Rust and LLVM target feature names.
+//!
+//! Rust passes target features to LLVM under different names in several places, like
+//! `pclmulqdq` vs. `pclmul` on x86 and `fp16` vs. `fullfp16` on AArch64. Some Rust target
+//! features enable more than one LLVM feature, and some enable none at all, if the compiler's LLVM
+//! does not support them.
+//!
+//! ```
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use rust_target_feature_data::llvm::LlvmNames;
+//!
+//! let names = LlvmNames::new("1.86.0", "x86_64-unknown-linux-gnu")?;
+//! assert_eq!(names.llvm_version, "19.1.7");
+//! assert_eq!(names.to_llvm("pclmulqdq"), Some(&["pclmul"][..]));
+//! assert_eq!(names.to_llvm("avx512f"), Some(&["avx512f", "evex512"][..]));
+//! assert_eq!(names.from_llvm("pclmul"), ["pclmulqdq"]);
+//!
+//! let names = LlvmNames::new("1.86.0", "aarch64-unknown-linux-gnu")?;
+//! assert_eq!(names.to_llvm("fp16"), Some(&["fullfp16"][..]));
+//! assert_eq!(names.from_llvm("pauth"), ["paca", "pacg"]);
+//! # Ok(()) }
+//! ```
+//!
+//! These tables were recorded by compiling with each target feature enabled, and reading the
+//! `"target-features"` attribute from the resulting LLVM IR.
+
+use crate::{NotFoundError, generated};
+
+#[cfg(test)]
+mod tests;
+
+/// The mapping between Rust and LLVM target feature names for one Rust version and target.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LlvmNames {
+    /// The version of LLVM used by this Rust version, e.g. `"19.1.7"`.
+    pub llvm_version: &'static str,
+    /// Each of the target's features, and the LLVM features it enables, in the order the compiler
+    /// passes them.
+    pub features: Vec<(&'static str, Vec<&'static str>)>,
+}
+
+impl LlvmNames {
+    /// Find the mapping for a Rust version and target.
+    pub fn new(rust_version: &str, target: &str) -> Result<Self, NotFoundError> {
+        let target_features: Vec<_> = crate::find(rust_version, target)?.collect();
+        let (_, llvm_version, entries) = generated::llvm()
+            .find(|(version, _, _)| *version == rust_version)
+            .ok_or_else(|| NotFoundError::CompilerNotFound(rust_version.into()))?;
+
+        let arch = crate::target_arch(target);
+        let mut features: Vec<(&'static str, Vec<&'static str>)> = Vec::new();
+        for (name, entry_arch, llvm) in entries {
+            if entry_arch.is_some_and(|entry_arch| entry_arch != arch)
+                || !target_features.iter().any(|f| f.name == name)
+            {
+                continue;
+            }
+            features.push((name, llvm.collect()));
+        }
+
+        Ok(LlvmNames {
+            llvm_version,
+            features,
+        })
+    }
+
+    /// Find the LLVM features enabled by a Rust target feature.
+    ///
+    /// Returns `None` if the target has no such feature, and an empty slice if the compiler does
+    /// not pass this feature to LLVM.
+    pub fn to_llvm(&self, rust_feature: &str) -> Option<&[&'static str]> {
+        self.features
+            .iter()
+            .find(|(name, _)| *name == rust_feature)
+            .map(|(_, llvm)| llvm.as_slice())
+    }
+
+    /// Find the Rust target features which enable an LLVM feature.
+    ///
+    /// This is often a single feature, but may be several, like `paca` and `pacg` both enabling
+    /// `pauth` on AArch64, or none.
+    pub fn from_llvm(&self, llvm_feature: &str) -> Vec<&'static str> {
+        self.features
+            .iter()
+            .filter(|(_, llvm)| llvm.contains(&llvm_feature))
+            .map(|(name, _)| *name)
+            .collect()
+    }
+}
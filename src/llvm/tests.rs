@@ -0,0 +1,49 @@
+use super::*;
+
+#[test]
+fn every_feature_is_mapped() {
+    for (version, targets) in generated::all() {
+        for (target, features) in targets {
+            let names = LlvmNames::new(version, target).unwrap();
+            for feature in features {
+                assert!(
+                    names.to_llvm(feature.name).is_some(),
+                    "{} {} {}",
+                    version,
+                    target,
+                    feature.name
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn architecture_specific() {
+    let aarch64 = LlvmNames::new("1.86.0", "aarch64-unknown-linux-gnu").unwrap();
+    assert_eq!(aarch64.to_llvm("neon"), Some(&["neon", "fp-armv8"][..]));
+    assert_eq!(aarch64.from_llvm("fp-armv8"), ["neon"]);
+
+    let arm = LlvmNames::new("1.86.0", "armv7-unknown-linux-gnueabihf").unwrap();
+    assert_eq!(arm.to_llvm("neon"), Some(&["neon"][..]));
+    assert_eq!(arm.to_llvm("pclmulqdq"), None);
+}
+
+#[test]
+fn llvm_versions() {
+    let x86 = LlvmNames::new("1.86.0", "x86_64-unknown-linux-gnu").unwrap();
+    assert_eq!(x86.from_llvm("crc32"), ["sse4.2"]);
+    assert_eq!(x86.from_llvm("evex512").len(), 14);
+    assert!(x86.from_llvm("nonexistent").is_empty());
+
+    // LLVM 19 does not support this feature, so Rust does not pass it along
+    let wasm = LlvmNames::new("1.86.0", "wasm32-unknown-unknown").unwrap();
+    assert_eq!(wasm.to_llvm("wide-arithmetic"), Some(&[][..]));
+
+    let wasm = LlvmNames::new("1.87.0", "wasm32-unknown-unknown").unwrap();
+    assert_eq!(wasm.llvm_version, "20.1.1");
+    assert_eq!(
+        wasm.to_llvm("wide-arithmetic"),
+        Some(&["wide-arithmetic"][..])
+    );
+}
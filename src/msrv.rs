@@ -0,0 +1,125 @@
+//! Minimum supported Rust versions for target features.
+//!
+//! A crate using `#[target_feature(enable = "…")]` on stable Rust needs a compiler in which every
+//! one of those target features exists and is stable for the relevant target.
+//!
+//! ```
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use rust_target_feature_data::msrv;
+//!
+//! assert_eq!(msrv::msrv("x86_64-unknown-linux-gnu", &["avx2", "fma"])?, "1.81.0");
+//!
+//! // This target was added in 1.86.0
+//! assert_eq!(msrv::msrv("aarch64-unknown-nuttx", &["neon"])?, "1.86.0");
+//! # Ok(()) }
+//! ```
+//!
+//! Versions are limited to those embedded in this crate, so the true MSRV may be older than the
+//! version returned.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+#[cfg(test)]
+mod tests;
+
+/// An error determining a minimum supported Rust version.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum MsrvError {
+    /// The target was not found in any embedded Rust version
+    TargetNotFound(String),
+    /// These target features do not exist for the target in any embedded Rust version
+    UnknownFeatures(BTreeSet<String>),
+    /// These target features are unstable in every embedded Rust version, requiring these gates
+    Unstable(BTreeMap<String, &'static str>),
+    /// Each target feature is stable in some embedded Rust version, but no version has all of them
+    NoCommonVersion,
+}
+
+impl std::error::Error for MsrvError {}
+
+impl std::fmt::Display for MsrvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            MsrvError::TargetNotFound(name) => write!(f, "target {:?} not found", name),
+            MsrvError::UnknownFeatures(names) => {
+                write!(f, "target features not found: {:?}", names)
+            }
+            MsrvError::Unstable(names) => {
+                write!(f, "target features unstable in every version: ")?;
+                for (i, (name, gate)) in names.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{} (requires {})", name, gate)?;
+                }
+                Ok(())
+            }
+            MsrvError::NoCommonVersion => {
+                write!(f, "no version has all of these target features stable")
+            }
+        }
+    }
+}
+
+/// Find the oldest embedded Rust version in which every one of `features` exists and is stable
+/// for `target`.
+pub fn msrv(target: &str, features: &[&str]) -> Result<&'static str, MsrvError> {
+    let mut found_target = false;
+    let mut seen: BTreeSet<&str> = BTreeSet::new();
+    let mut stable_somewhere: BTreeSet<&str> = BTreeSet::new();
+    let mut latest_gate: BTreeMap<&str, &'static str> = BTreeMap::new();
+
+    for version in crate::versions() {
+        let Ok(target_features) = crate::find(version, target) else {
+            continue;
+        };
+        found_target = true;
+
+        let mut all_stable = true;
+        let target_features: Vec<_> = target_features.collect();
+        for name in features {
+            match target_features.iter().find(|f| f.name == *name) {
+                Some(feature) => {
+                    seen.insert(name);
+                    match feature.unstable_feature_gate {
+                        Some(gate) => {
+                            latest_gate.insert(name, gate);
+                            all_stable = false;
+                        }
+                        None => {
+                            stable_somewhere.insert(name);
+                        }
+                    }
+                }
+                None => all_stable = false,
+            }
+        }
+        if all_stable {
+            return Ok(version);
+        }
+    }
+
+    if !found_target {
+        return Err(MsrvError::TargetNotFound(target.into()));
+    }
+
+    let unknown: BTreeSet<String> = features
+        .iter()
+        .filter(|name| !seen.contains(*name))
+        .map(|name| name.to_string())
+        .collect();
+    if !unknown.is_empty() {
+        return Err(MsrvError::UnknownFeatures(unknown));
+    }
+
+    let unstable: BTreeMap<String, &'static str> = latest_gate
+        .into_iter()
+        .filter(|(name, _)| !stable_somewhere.contains(name))
+        .map(|(name, gate)| (name.to_string(), gate))
+        .collect();
+    if !unstable.is_empty() {
+        return Err(MsrvError::Unstable(unstable));
+    }
+
+    Err(MsrvError::NoCommonVersion)
+}
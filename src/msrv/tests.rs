@@ -0,0 +1,42 @@
+use super::*;
+
+#[test]
+fn stable() {
+    assert_eq!(
+        msrv("x86_64-unknown-linux-gnu", &["avx2", "fma", "sse2"]).unwrap(),
+        "1.81.0"
+    );
+    assert_eq!(msrv("x86_64-unknown-linux-gnu", &[]).unwrap(), "1.81.0");
+}
+
+#[test]
+fn new_target() {
+    assert_eq!(msrv("aarch64-unknown-nuttx", &["neon"]).unwrap(), "1.86.0");
+}
+
+#[test]
+fn target_not_found() {
+    assert_eq!(
+        msrv("mos-c64-none", &[]).unwrap_err(),
+        MsrvError::TargetNotFound("mos-c64-none".into())
+    );
+}
+
+#[test]
+fn unknown_features() {
+    assert_eq!(
+        msrv("x86_64-unknown-linux-gnu", &["avx2", "avx3", "neon"]).unwrap_err(),
+        MsrvError::UnknownFeatures(BTreeSet::from(["avx3".into(), "neon".into()]))
+    );
+}
+
+#[test]
+fn unstable() {
+    assert_eq!(
+        msrv("x86_64-unknown-linux-gnu", &["avx2", "avx512f", "sha512"]).unwrap_err(),
+        MsrvError::Unstable(BTreeMap::from([
+            ("avx512f".into(), "avx512_target_feature"),
+            ("sha512".into(), "sha512_sm_x86"),
+        ]))
+    );
+}
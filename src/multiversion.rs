@@ -0,0 +1,499 @@
+//! Generating function multiversioning code.
+//!
+//! A multiversioned function is compiled several times, once for each tier of target features,
+//! and calls the best version the CPU supports. [`generate()`] takes a function signature and an
+//! ordered list of tiers, best first, and emits Rust source containing:
+//!
+//! * one `#[target_feature]` function per tier, plus a fallback with no extra target features,
+//! * a dispatcher with the original signature, which detects the best tier on the first call and
+//!   caches the result, and
+//! * `cfg!(target_feature)` short-circuits, so tiers enabled at compile time need no runtime
+//!   detection.
+//!
+//! Every version calls `<name>_impl` with the same arguments, which must be written by hand and
+//! marked `#[inline(always)]` so that it is compiled with each version's target features.
+//!
+//! ```
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use rust_target_feature_data::multiversion;
+//!
+//! let generated = multiversion::generate(
+//!     "1.86.0",
+//!     "x86_64-unknown-linux-gnu",
+//!     "pub fn sum(values: &[f32]) -> f32",
+//!     &[&["avx2", "fma"], &["sse4.1"]],
+//! )?;
+//! assert!(generated.source.contains(r#"#[target_feature(enable = "avx2,fma")]"#));
+//! assert!(generated.source.contains("unsafe fn sum_avx2_fma(values: &[f32]) -> f32"));
+//! assert!(generated.feature_gates.is_empty());
+//! # Ok(()) }
+//! ```
+//!
+//! If a tier's features are all enabled by default on the target, that tier is always available:
+//! it becomes the last tier, and no fallback is generated. Any tiers after it would never be used,
+//! so they're left out, noted in a comment, and listed in
+//! [`unreachable_tiers`](Multiversioned::unreachable_tiers).
+//!
+//! In a build script, [`BuildContext::multiversion()`](crate::build_script::BuildContext::multiversion)
+//! generates code for the target being built, which can be written to `OUT_DIR` and `include!()`d.
+
+use crate::NotFoundError;
+use crate::detect::{DetectError, Detection};
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+#[cfg(test)]
+mod tests;
+
+/// Generated multiversioning code.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Multiversioned {
+    /// The Rust source.
+    pub source: String,
+    /// Language feature gates which the source requires, for unstable target features or unstable
+    /// runtime detection.
+    pub feature_gates: BTreeSet<&'static str>,
+    /// Tiers which were left out, because an earlier tier is always available.
+    pub unreachable_tiers: Vec<Vec<&'static str>>,
+}
+
+/// An error generating multiversioning code.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum MultiversionError {
+    /// The compiler version or target was not found
+    NotFound(NotFoundError),
+    /// The function signature is not supported
+    Signature(String),
+    /// No tiers were given, or a tier has no target features
+    EmptyTier,
+    /// Two tiers have the same target features
+    DuplicateTier(String),
+    /// A target feature in a tier does not exist, or cannot be detected at runtime
+    Feature(DetectError),
+}
+
+impl std::error::Error for MultiversionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MultiversionError::NotFound(e) => Some(e),
+            MultiversionError::Feature(e) => Some(e),
+            MultiversionError::Signature(_)
+            | MultiversionError::EmptyTier
+            | MultiversionError::DuplicateTier(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for MultiversionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            MultiversionError::NotFound(e) => e.fmt(f),
+            MultiversionError::Signature(message) => {
+                write!(f, "unsupported function signature: {}", message)
+            }
+            MultiversionError::EmptyTier => write!(f, "every tier needs at least one feature"),
+            MultiversionError::DuplicateTier(tier) => {
+                write!(f, "tier {:?} is given more than once", tier)
+            }
+            MultiversionError::Feature(e) => e.fmt(f),
+        }
+    }
+}
+
+impl From<NotFoundError> for MultiversionError {
+    fn from(value: NotFoundError) -> Self {
+        MultiversionError::NotFound(value)
+    }
+}
+
+impl From<DetectError> for MultiversionError {
+    fn from(value: DetectError) -> Self {
+        MultiversionError::Feature(value)
+    }
+}
+
+/// A parsed function signature.
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Signature<'a> {
+    /// The visibility, like `pub`, or an empty string.
+    visibility: &'a str,
+    name: &'a str,
+    generics: &'a str,
+    /// Parameter names and types.
+    params: Vec<(&'a str, &'a str)>,
+    /// The return type, like `-> f32`, or an empty string.
+    output: &'a str,
+}
+
+/// One tier of the generated code.
+struct Tier<'a> {
+    features: Vec<&'a str>,
+    function: String,
+    check: String,
+}
+
+/// Generate multiversioning code for a function.
+///
+/// `signature` is a function signature like `pub fn sum(values: &[f32]) -> f32`. Lifetime
+/// parameters are supported, but type parameters, `self`, `where` clauses, `impl Trait` return
+/// types, and qualifiers like `unsafe` are not. `tiers` lists sets of target features, best first.
+pub fn generate(
+    rust_version: &str,
+    target: &str,
+    signature: &str,
+    tiers: &[&[&str]],
+) -> Result<Multiversioned, MultiversionError> {
+    let signature = parse_signature(signature)?;
+    let features: Vec<_> = crate::find(rust_version, target)?.collect();
+    let detection = Detection::new(rust_version, target)?;
+    if tiers.is_empty() {
+        return Err(MultiversionError::EmptyTier);
+    }
+
+    let mut feature_gates = BTreeSet::new();
+    let mut checked = Vec::new();
+    let mut always_available = None;
+    let mut unreachable_tiers: Vec<Vec<&'static str>> = Vec::new();
+    for (i, tier) in tiers.iter().enumerate() {
+        if tier.is_empty() {
+            return Err(MultiversionError::EmptyTier);
+        }
+        // Each tier's function is named after its features, so they must differ
+        let set: BTreeSet<&str> = tier.iter().copied().collect();
+        if tiers[..i]
+            .iter()
+            .any(|earlier| earlier.iter().copied().collect::<BTreeSet<_>>() == set)
+        {
+            return Err(MultiversionError::DuplicateTier(tier.join(",")));
+        }
+        let mut tier_features = Vec::new();
+        for name in tier.iter() {
+            let feature = features
+                .iter()
+                .find(|f| f.name == *name)
+                .ok_or_else(|| DetectError::UnknownFeature(name.to_string()))?;
+            tier_features.push(feature);
+        }
+        if always_available.is_some() {
+            unreachable_tiers.push(tier_features.iter().map(|f| f.name).collect());
+            continue;
+        }
+        feature_gates.extend(tier_features.iter().filter_map(|f| f.unstable_feature_gate));
+        let check = detection.runtime_check(tier)?;
+        feature_gates.extend(check.feature_gates);
+
+        let mut function = format!("{}_", signature.name);
+        for (i, name) in tier.iter().enumerate() {
+            if i > 0 {
+                function.push('_');
+            }
+            function.extend(name.chars().map(|c| match c {
+                'a'..='z' | 'A'..='Z' | '0'..='9' => c,
+                _ => '_',
+            }));
+        }
+        let tier = Tier {
+            features: tier.to_vec(),
+            function,
+            check: check.expression,
+        };
+        if tier.check == "true" {
+            always_available = Some(tier);
+        } else {
+            checked.push(tier);
+        }
+    }
+
+    let mut source = String::new();
+    if let Some(tier) = &always_available {
+        for unreachable in &unreachable_tiers {
+            writeln!(
+                source,
+                "// Tier {:?} is never used, since {:?} is always available\n",
+                unreachable.join(","),
+                tier.features.join(",")
+            )
+            .unwrap();
+        }
+    }
+    let params: String = signature
+        .params
+        .iter()
+        .map(|(name, ty)| format!("{}: {}", name, ty))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let args: String = signature
+        .params
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>()
+        .join(", ");
+    let declare = |name: &str| {
+        let mut declaration = format!("fn {}{}({})", name, signature.generics, params);
+        if !signature.output.is_empty() {
+            declaration.push(' ');
+            declaration.push_str(signature.output);
+        }
+        declaration
+    };
+    let cfg = |tier: &Tier| {
+        let features: Vec<String> = tier
+            .features
+            .iter()
+            .map(|f| format!("target_feature = {:?}", f))
+            .collect();
+        format!("cfg!(all({}))", features.join(", "))
+    };
+
+    for tier in checked.iter().chain(&always_available) {
+        writeln!(
+            source,
+            "#[target_feature(enable = {:?})]",
+            tier.features.join(",")
+        )
+        .unwrap();
+        writeln!(source, "unsafe {} {{", declare(&tier.function)).unwrap();
+        writeln!(source, "    {}_impl({})", signature.name, args).unwrap();
+        writeln!(source, "}}\n").unwrap();
+    }
+    let fallback = format!("{}_fallback", signature.name);
+    if always_available.is_none() {
+        writeln!(source, "{} {{", declare(&fallback)).unwrap();
+        writeln!(source, "    {}_impl({})", signature.name, args).unwrap();
+        writeln!(source, "}}\n").unwrap();
+    }
+
+    let visibility = match signature.visibility {
+        "" => String::new(),
+        visibility => format!("{} ", visibility),
+    };
+    writeln!(source, "{}{} {{", visibility, declare(signature.name)).unwrap();
+    let Some(best) = checked.first() else {
+        let tier = always_available.as_ref().unwrap();
+        writeln!(
+            source,
+            "    // SAFETY: the target enables these target features by default"
+        )
+        .unwrap();
+        writeln!(source, "    unsafe {{ {}({}) }}", tier.function, args).unwrap();
+        writeln!(source, "}}").unwrap();
+        return Ok(Multiversioned {
+            source,
+            feature_gates,
+            unreachable_tiers,
+        });
+    };
+    writeln!(source, "    if {} {{", cfg(best)).unwrap();
+    writeln!(
+        source,
+        "        // SAFETY: these target features are enabled at compile time"
+    )
+    .unwrap();
+    writeln!(
+        source,
+        "        return unsafe {{ {}({}) }};",
+        best.function, args
+    )
+    .unwrap();
+    writeln!(source, "    }}\n").unwrap();
+    writeln!(source, "    use std::sync::atomic::{{AtomicU8, Ordering}};").unwrap();
+    writeln!(source, "    static SELECTED: AtomicU8 = AtomicU8::new(0);").unwrap();
+    writeln!(
+        source,
+        "    let selected = match SELECTED.load(Ordering::Relaxed) {{"
+    )
+    .unwrap();
+    writeln!(source, "        0 => {{").unwrap();
+    for (i, tier) in checked.iter().enumerate() {
+        let keyword = if i == 0 {
+            "let selected = if"
+        } else {
+            "} else if"
+        };
+        writeln!(
+            source,
+            "            {} {} || ({}) {{",
+            keyword,
+            cfg(tier),
+            tier.check
+        )
+        .unwrap();
+        writeln!(source, "                {}", i + 1).unwrap();
+    }
+    writeln!(source, "            }} else {{").unwrap();
+    writeln!(source, "                {}", checked.len() + 1).unwrap();
+    writeln!(source, "            }};").unwrap();
+    writeln!(
+        source,
+        "            SELECTED.store(selected, Ordering::Relaxed);"
+    )
+    .unwrap();
+    writeln!(source, "            selected").unwrap();
+    writeln!(source, "        }}").unwrap();
+    writeln!(source, "        selected => selected,").unwrap();
+    writeln!(source, "    }};").unwrap();
+    writeln!(source, "    match selected {{").unwrap();
+    for (i, tier) in checked.iter().enumerate() {
+        writeln!(
+            source,
+            "        // SAFETY: these target features were detected at runtime"
+        )
+        .unwrap();
+        writeln!(
+            source,
+            "        {} => unsafe {{ {}({}) }},",
+            i + 1,
+            tier.function,
+            args
+        )
+        .unwrap();
+    }
+    match &always_available {
+        Some(tier) => {
+            writeln!(
+                source,
+                "        // SAFETY: the target enables these target features by default"
+            )
+            .unwrap();
+            writeln!(
+                source,
+                "        _ => unsafe {{ {}({}) }},",
+                tier.function, args
+            )
+            .unwrap();
+        }
+        None => writeln!(source, "        _ => {}({}),", fallback, args).unwrap(),
+    }
+    writeln!(source, "    }}").unwrap();
+    writeln!(source, "}}").unwrap();
+
+    Ok(Multiversioned {
+        source,
+        feature_gates,
+        unreachable_tiers,
+    })
+}
+
+/// Parse a function signature into its parts.
+fn parse_signature(signature: &str) -> Result<Signature<'_>, MultiversionError> {
+    let error = |message: &str| MultiversionError::Signature(message.into());
+    let signature = signature.trim().trim_end_matches(';').trim_end();
+
+    let (qualifiers, rest) = signature
+        .split_once("fn ")
+        .ok_or_else(|| error("expected `fn`"))?;
+    let visibility = qualifiers.trim();
+    if !(visibility.is_empty()
+        || visibility == "pub"
+        || visibility.starts_with("pub(") && visibility.ends_with(')'))
+    {
+        return Err(error("only visibility qualifiers are supported"));
+    }
+
+    let rest = rest.trim_start();
+    let name_end = rest
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .ok_or_else(|| error("expected parameters"))?;
+    let (name, rest) = rest.split_at(name_end);
+    if name.is_empty() {
+        return Err(error("expected a function name"));
+    }
+
+    let rest = rest.trim_start();
+    let (generics, rest) = if rest.starts_with('<') {
+        let end = closing(rest).ok_or_else(|| error("unclosed `<`"))?;
+        let generics = &rest[..=end];
+        let only_lifetimes = split_top_level(&generics[1..end])
+            .iter()
+            .all(|param| param.starts_with('\''));
+        if !only_lifetimes {
+            return Err(error("only lifetime parameters are supported"));
+        }
+        (generics, rest[end + 1..].trim_start())
+    } else {
+        ("", rest)
+    };
+
+    if !rest.starts_with('(') {
+        return Err(error("expected parameters"));
+    }
+    let end = closing(rest).ok_or_else(|| error("unclosed `(`"))?;
+    let mut params = Vec::new();
+    for param in split_top_level(&rest[1..end]) {
+        let (name, ty) = param
+            .split_once(':')
+            .ok_or_else(|| error("expected `name: type` parameters"))?;
+        let name = name.trim();
+        let name = name.strip_prefix("mut ").unwrap_or(name).trim();
+        if name == "self" || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return Err(error("parameters must be plain names"));
+        }
+        params.push((name, ty.trim()));
+    }
+
+    let output = rest[end + 1..].trim();
+    if !(output.is_empty() || output.starts_with("->")) || output.contains(" where ") {
+        return Err(error("`where` clauses are not supported"));
+    }
+    // Each version would return a different opaque type
+    if output
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .any(|word| word == "impl")
+    {
+        return Err(error("`impl Trait` return types are not supported"));
+    }
+
+    Ok(Signature {
+        visibility,
+        name,
+        generics,
+        params,
+        output,
+    })
+}
+
+/// Find the byte offset of the bracket closing the one `s` starts with.
+fn closing(s: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut previous = ' ';
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' | '[' | '<' => depth += 1,
+            // The `>` in `->` is not a bracket
+            '>' if previous == '-' => {}
+            ')' | ']' | '>' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+        previous = c;
+    }
+    None
+}
+
+/// Split a list on commas which are not inside brackets.
+fn split_top_level(s: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    let mut previous = ' ';
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' | '[' | '<' => depth += 1,
+            '>' if previous == '-' => {}
+            ')' | ']' | '>' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(s[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+        previous = c;
+    }
+    parts.push(s[start..].trim());
+    parts.retain(|part| !part.is_empty());
+    parts
+}
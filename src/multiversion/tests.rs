@@ -0,0 +1,123 @@
+use super::*;
+
+#[test]
+fn x86_64() {
+    let generated = generate(
+        "1.86.0",
+        "x86_64-unknown-linux-gnu",
+        "pub fn sum(values: &[f32]) -> f32",
+        &[&["avx2", "fma"], &["sse4.1"]],
+    )
+    .unwrap();
+    assert_eq!(
+        generated.source,
+        include_str!("../../fixtures/multiversion/sum.rs")
+    );
+    assert!(generated.feature_gates.is_empty());
+}
+
+#[test]
+fn always_available() {
+    // SSE2 is part of the x86_64 baseline, so there's no need for a fallback
+    let generated = generate(
+        "1.86.0",
+        "x86_64-unknown-linux-gnu",
+        "fn copy<'a>(mut dst: &'a mut [u8], src: &[u8]) -> &'a mut [u8];",
+        &[&["avx512f"], &["sse2"], &["sse"]],
+    )
+    .unwrap();
+    assert_eq!(
+        generated.source,
+        include_str!("../../fixtures/multiversion/copy.rs")
+    );
+    assert_eq!(
+        generated.feature_gates,
+        BTreeSet::from(["avx512_target_feature"])
+    );
+    assert_eq!(generated.unreachable_tiers, [["sse"]]);
+
+    let generated = generate("1.86.0", "x86_64-unknown-linux-gnu", "fn f()", &[&["sse2"]]).unwrap();
+    assert!(!generated.source.contains("SELECTED"));
+    assert!(!generated.source.contains("f_fallback"));
+    assert!(generated.unreachable_tiers.is_empty());
+
+    // Later tiers are still checked, though they're left out
+    assert_eq!(
+        generate(
+            "1.86.0",
+            "x86_64-unknown-linux-gnu",
+            "fn f()",
+            &[&["sse2"], &["avx3"]]
+        ),
+        Err(MultiversionError::Feature(DetectError::UnknownFeature(
+            "avx3".into()
+        )))
+    );
+}
+
+#[test]
+fn errors() {
+    let x86_64 =
+        |signature, tiers| generate("1.86.0", "x86_64-unknown-linux-gnu", signature, tiers);
+    assert_eq!(
+        x86_64("fn f()", &[&["avx3"]]),
+        Err(MultiversionError::Feature(DetectError::UnknownFeature(
+            "avx3".into()
+        )))
+    );
+    assert_eq!(
+        x86_64("fn f()", &[&["avx2"], &["lahfsahf"]]),
+        Err(MultiversionError::Feature(DetectError::Undetectable(
+            "lahfsahf".into()
+        )))
+    );
+    assert_eq!(x86_64("fn f()", &[]), Err(MultiversionError::EmptyTier));
+    assert_eq!(
+        x86_64("fn f()", &[&["avx2"], &[]]),
+        Err(MultiversionError::EmptyTier)
+    );
+    assert_eq!(
+        x86_64("fn f()", &[&["avx2", "fma"], &["sse4.1"], &["fma", "avx2"]]),
+        Err(MultiversionError::DuplicateTier("fma,avx2".into()))
+    );
+    assert_eq!(
+        x86_64("fn f()", &[&["sse2"], &["sse2"]]),
+        Err(MultiversionError::DuplicateTier("sse2".into()))
+    );
+    for signature in [
+        "f()",
+        "unsafe fn f()",
+        "fn f<T>(t: T)",
+        "fn f(&self)",
+        "fn f((a, b): (u8, u8))",
+        "fn f<T>() where T: Copy",
+        "fn f() -> impl Iterator<Item = u8>",
+        "fn f() -> Box<impl Fn()>",
+    ] {
+        assert!(
+            matches!(
+                x86_64(signature, &[&["avx2"]]),
+                Err(MultiversionError::Signature(_))
+            ),
+            "{}",
+            signature
+        );
+    }
+}
+
+#[test]
+fn signatures() {
+    let signature =
+        parse_signature("pub(crate) fn apply(f: fn(u8) -> u8, values: Vec<(u8, u8)>) -> u8")
+            .unwrap();
+    assert_eq!(
+        signature,
+        Signature {
+            visibility: "pub(crate)",
+            name: "apply",
+            generics: "",
+            params: vec![("f", "fn(u8) -> u8"), ("values", "Vec<(u8, u8)>")],
+            output: "-> u8",
+        }
+    );
+}
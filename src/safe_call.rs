@@ -0,0 +1,98 @@
+//! Safe calls between `#[target_feature]` functions.
+//!
+//! Since Rust 1.86, functions annotated with `#[target_feature(enable = "…")]` can be safe
+//! functions. Calling such a function is only safe when the caller is guaranteed to have every
+//! target feature the callee requires: either because the caller enables those features itself,
+//! because they are implied by features the caller enables, or because the target enables them
+//! globally. Otherwise, the call requires `unsafe`.
+//!
+//! ```
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use rust_target_feature_data::safe_call::{self, CallSafety};
+//!
+//! // `avx2` implies `avx`
+//! assert_eq!(
+//!     safe_call::check("1.86.0", "x86_64-unknown-linux-gnu", &["avx2"], &["avx"])?,
+//!     CallSafety::Safe,
+//! );
+//!
+//! // `avx` does not imply `avx2` or `fma`
+//! let CallSafety::RequiresUnsafe { missing } =
+//!     safe_call::check("1.86.0", "x86_64-unknown-linux-gnu", &["avx"], &["avx2", "fma"])?
+//! else {
+//!     unreachable!()
+//! };
+//! assert_eq!(missing.into_iter().collect::<Vec<_>>(), ["avx2", "fma"]);
+//! # Ok(()) }
+//! ```
+
+use crate::{QueryError, TargetFeature};
+use std::collections::BTreeSet;
+
+#[cfg(test)]
+mod tests;
+
+/// Whether a call between `#[target_feature]` functions is safe.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum CallSafety {
+    /// The caller has every target feature the callee requires.
+    Safe,
+    /// The call requires `unsafe`.
+    RequiresUnsafe {
+        /// Target features which the callee requires, but which the caller is not guaranteed to
+        /// have.
+        missing: BTreeSet<&'static str>,
+    },
+}
+
+impl std::fmt::Display for CallSafety {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            CallSafety::Safe => write!(f, "call is safe"),
+            CallSafety::RequiresUnsafe { missing } => {
+                write!(f, "call requires unsafe; caller is missing target features")?;
+                for (i, name) in missing.iter().enumerate() {
+                    write!(f, "{} {}", if i == 0 { ":" } else { "," }, name)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Determine whether a function annotated with the `caller` target features can call a function
+/// annotated with the `callee` target features without `unsafe`.
+pub fn check(
+    rust_version: &str,
+    target: &str,
+    caller: &[&str],
+    callee: &[&str],
+) -> Result<CallSafety, QueryError> {
+    check_features(crate::find(rust_version, target)?, caller, callee)
+}
+
+/// Determine whether a call is safe, given a target's features.
+///
+/// See [`check()`].
+pub fn check_features(
+    features: impl IntoIterator<Item = TargetFeature>,
+    caller: &[&str],
+    callee: &[&str],
+) -> Result<CallSafety, QueryError> {
+    let features: Vec<TargetFeature> = features.into_iter().collect();
+    let mut available = crate::implied_closure(&features, caller.iter().copied())?;
+    available.extend(
+        features
+            .iter()
+            .filter(|f| f.globally_enabled)
+            .map(|f| f.name),
+    );
+    let required = crate::implied_closure(&features, callee.iter().copied())?;
+
+    let missing: BTreeSet<&'static str> = required.difference(&available).copied().collect();
+    if missing.is_empty() {
+        Ok(CallSafety::Safe)
+    } else {
+        Ok(CallSafety::RequiresUnsafe { missing })
+    }
+}
@@ -0,0 +1,66 @@
+use super::*;
+
+const X86_64: &str = "x86_64-unknown-linux-gnu";
+
+#[test]
+fn implied() {
+    assert_eq!(
+        check("1.86.0", X86_64, &["avx2"], &["avx", "sse4.1"]).unwrap(),
+        CallSafety::Safe
+    );
+    assert_eq!(
+        check("1.86.0", X86_64, &["avx"], &["avx2"]).unwrap(),
+        CallSafety::RequiresUnsafe {
+            missing: BTreeSet::from(["avx2"])
+        }
+    );
+}
+
+#[test]
+fn callee_implications_are_required() {
+    // `avx` implies `sse4.2`, which the caller does not have
+    assert_eq!(
+        check("1.86.0", X86_64, &["sse4.1"], &["avx"]).unwrap(),
+        CallSafety::RequiresUnsafe {
+            missing: BTreeSet::from(["avx", "sse4.2"])
+        }
+    );
+}
+
+#[test]
+fn globally_enabled() {
+    assert_eq!(
+        check("1.86.0", X86_64, &[], &["sse2"]).unwrap(),
+        CallSafety::Safe
+    );
+    assert_eq!(
+        check("1.86.0", "aarch64-unknown-linux-gnu", &[], &["neon"]).unwrap(),
+        CallSafety::Safe
+    );
+    assert_eq!(
+        check("1.86.0", "armv7-unknown-linux-gnueabihf", &[], &["neon"]).unwrap(),
+        CallSafety::RequiresUnsafe {
+            missing: BTreeSet::from(["d32", "neon", "vfp3"])
+        }
+    );
+}
+
+#[test]
+fn unknown_feature() {
+    assert_eq!(
+        check("1.86.0", X86_64, &["avx3"], &[]).unwrap_err(),
+        QueryError::UnknownFeature("avx3".into())
+    );
+    assert_eq!(
+        check("1.86.0", X86_64, &[], &["neon"]).unwrap_err(),
+        QueryError::UnknownFeature("neon".into())
+    );
+}
+
+#[test]
+fn unknown_implications() {
+    assert_eq!(
+        check("1.81.0", X86_64, &["avx2"], &["avx"]).unwrap_err(),
+        QueryError::UnknownImplications("avx2".into())
+    );
+}
@@ -0,0 +1,435 @@
+//! Scanning Rust source code for target feature usage.
+//!
+//! Target feature names in `#[target_feature(enable = "…")]` and `cfg(target_feature = "…")` are
+//! plain strings. A misspelled name in `#[target_feature]` is an error, but only for targets on
+//! which it is compiled, and a misspelled name in `cfg(target_feature)` is silently never true.
+//! This module finds these names in source code and checks them against the embedded data.
+//!
+//! ```
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use rust_target_feature_data::scan::{self, ProblemKind};
+//! use std::path::Path;
+//!
+//! let usages = scan::scan_source(
+//!     Path::new("src/lib.rs"),
+//!     r#"
+//!         #[target_feature(enable = "avx2,fma")]
+//!         unsafe fn fast() {}
+//!
+//!         #[cfg(target_feature = "sse4")]
+//!         fn unreachable() {}
+//!     "#,
+//! );
+//! assert_eq!(usages.len(), 3);
+//!
+//! let problems = scan::check("1.86.0", &["x86_64-unknown-linux-gnu"], &usages)?;
+//! assert_eq!(problems.len(), 1);
+//! assert_eq!(problems[0].usage.feature, "sse4");
+//! assert_eq!(problems[0].usage.line, 5);
+//! assert_eq!(problems[0].kind, ProblemKind::NeverTrue);
+//! # Ok(()) }
+//! ```
+
+use crate::{NotFoundError, TargetFeature};
+use std::path::{Path, PathBuf};
+
+#[cfg(test)]
+mod tests;
+
+/// One target feature name found in source code.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Usage {
+    /// The file containing this usage.
+    pub path: PathBuf,
+    /// The 1-based line number of the string containing this target feature name.
+    pub line: usize,
+    /// How this target feature name was used.
+    pub kind: UsageKind,
+    /// The target feature name.
+    pub feature: String,
+}
+
+/// How a target feature name was used.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum UsageKind {
+    /// `#[target_feature(enable = "…")]`, whether written directly or applied by `cfg_attr`
+    TargetFeature,
+    /// `target_feature = "…"` in a `cfg` predicate, including `#[cfg(…)]` and `cfg!(…)`
+    Cfg,
+    /// `target_feature = "…"` in a `cfg_attr` predicate
+    CfgAttr,
+}
+
+/// A problem with a target feature usage.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Problem {
+    /// The usage with a problem.
+    pub usage: Usage,
+    /// The problem.
+    pub kind: ProblemKind,
+}
+
+/// A kind of problem with a target feature usage.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum ProblemKind {
+    /// The target feature does not exist on any of the targets
+    Unknown,
+    /// The target feature is unstable, requiring this language feature gate
+    Gated(&'static str),
+    /// The `cfg` predicate names a target feature which does not exist on any of the targets, so
+    /// it can never be true
+    NeverTrue,
+}
+
+impl std::fmt::Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}: target feature {:?} ",
+            self.usage.path.display(),
+            self.usage.line,
+            self.usage.feature
+        )?;
+        match &self.kind {
+            ProblemKind::Unknown => write!(f, "does not exist"),
+            ProblemKind::Gated(gate) => write!(f, "is unstable, requiring #![feature({})]", gate),
+            ProblemKind::NeverTrue => write!(f, "does not exist, so this cfg is never true"),
+        }
+    }
+}
+
+/// Scan every `.rs` file under `dir` for target feature usage.
+///
+/// Directories named `target` and hidden directories are skipped.
+pub fn scan_dir(dir: &Path) -> std::io::Result<Vec<Usage>> {
+    let mut entries = std::fs::read_dir(dir)?.collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by_key(|entry| entry.file_name());
+
+    let mut usages = Vec::new();
+    for entry in entries {
+        let path = entry.path();
+        let file_name = entry.file_name();
+        let Some(name) = file_name.to_str() else {
+            continue;
+        };
+        if entry.file_type()?.is_dir() {
+            if name != "target" && !name.starts_with('.') {
+                usages.extend(scan_dir(&path)?);
+            }
+        } else if name.ends_with(".rs") {
+            let source = std::fs::read_to_string(&path)?;
+            usages.extend(scan_source(&path, &source));
+        }
+    }
+    Ok(usages)
+}
+
+/// Scan Rust source code for target feature usage.
+///
+/// `path` is recorded in each [`Usage`], and is not read.
+pub fn scan_source(path: &Path, source: &str) -> Vec<Usage> {
+    let tokens = tokenize(source);
+    let mut usages = Vec::new();
+    let mut usage = |line, kind, feature: &str| {
+        usages.push(Usage {
+            path: path.into(),
+            line,
+            kind,
+            feature: feature.into(),
+        })
+    };
+
+    // The identifier preceding each open delimiter, like `cfg` in `cfg(` or `cfg!(`
+    let mut stack: Vec<Option<&str>> = Vec::new();
+    for (i, token) in tokens.iter().enumerate() {
+        match token.kind {
+            TokenKind::Open => {
+                let name = match i.checked_sub(1).map(|i| &tokens[i].kind) {
+                    Some(TokenKind::Ident(name)) => Some(*name),
+                    Some(TokenKind::Punct('!')) => {
+                        match i.checked_sub(2).map(|i| &tokens[i].kind) {
+                            Some(TokenKind::Ident(name)) => Some(*name),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                };
+                stack.push(name);
+            }
+            TokenKind::Close => {
+                stack.pop();
+            }
+            TokenKind::Ident("target_feature") => match &tokens[i + 1..] {
+                [
+                    Token {
+                        kind: TokenKind::Punct('='),
+                        ..
+                    },
+                    Token {
+                        kind: TokenKind::Str(feature),
+                        line,
+                    },
+                    ..,
+                ] => {
+                    let kind = stack.iter().rev().find_map(|name| match name {
+                        Some("cfg") => Some(UsageKind::Cfg),
+                        Some("cfg_attr") => Some(UsageKind::CfgAttr),
+                        _ => None,
+                    });
+                    if let Some(kind) = kind {
+                        usage(*line, kind, feature);
+                    }
+                }
+                [
+                    Token {
+                        kind: TokenKind::Open,
+                        ..
+                    },
+                    rest @ ..,
+                ] => {
+                    let mut depth = 0;
+                    for window in rest.windows(3) {
+                        match &window[0].kind {
+                            TokenKind::Open => depth += 1,
+                            TokenKind::Close if depth == 0 => break,
+                            TokenKind::Close => depth -= 1,
+                            TokenKind::Ident("enable") if depth == 0 => {
+                                if let (TokenKind::Punct('='), TokenKind::Str(list)) =
+                                    (&window[1].kind, &window[2].kind)
+                                {
+                                    for feature in list.split(',') {
+                                        usage(window[2].line, UsageKind::TargetFeature, feature);
+                                    }
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+    usages
+}
+
+/// Check target feature usage against a Rust version and a list of targets.
+///
+/// Names which exist on at least one of the targets are considered valid, since code using them
+/// is usually conditionally compiled for an appropriate architecture. Likewise, names are only
+/// reported as unstable if they are unstable on every target where they exist.
+pub fn check(
+    rust_version: &str,
+    targets: &[&str],
+    usages: &[Usage],
+) -> Result<Vec<Problem>, NotFoundError> {
+    let target_features = targets
+        .iter()
+        .map(|target| Ok(crate::find(rust_version, target)?.collect()))
+        .collect::<Result<Vec<Vec<TargetFeature>>, NotFoundError>>()?;
+
+    let problems = usages
+        .iter()
+        .filter_map(|usage| {
+            let mut found = target_features
+                .iter()
+                .flatten()
+                .filter(|f| f.name == usage.feature)
+                .peekable();
+            let kind = if found.peek().is_none() {
+                match usage.kind {
+                    UsageKind::TargetFeature => ProblemKind::Unknown,
+                    UsageKind::Cfg | UsageKind::CfgAttr => ProblemKind::NeverTrue,
+                }
+            } else {
+                let gates: Option<Vec<&'static str>> =
+                    found.map(|f| f.unstable_feature_gate).collect();
+                ProblemKind::Gated(gates?[0])
+            };
+            Some(Problem {
+                usage: usage.clone(),
+                kind,
+            })
+        })
+        .collect();
+    Ok(problems)
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Token<'a> {
+    kind: TokenKind<'a>,
+    line: usize,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum TokenKind<'a> {
+    Ident(&'a str),
+    /// The contents of a string literal, without processing escapes
+    Str(&'a str),
+    Open,
+    Close,
+    Punct(char),
+    Other,
+}
+
+/// Split Rust source code into tokens, discarding comments and whitespace.
+///
+/// This only distinguishes what [`scan_source()`] needs, and tolerates invalid input.
+fn tokenize(source: &str) -> Vec<Token<'_>> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut i = 0;
+
+    // Advance past a quoted string starting at `start`, returning its contents
+    let string = |start: usize, hashes: usize, raw: bool, line: &mut usize| {
+        let mut j = start;
+        while j < bytes.len() {
+            match bytes[j] {
+                b'\\' if !raw => {
+                    // The escaped byte may be the newline of a line continuation
+                    j += 1;
+                    if bytes.get(j) == Some(&b'\n') {
+                        *line += 1;
+                    }
+                }
+                b'\n' => *line += 1,
+                b'"' if bytes[j + 1..]
+                    .iter()
+                    .take(hashes)
+                    .filter(|b| **b == b'#')
+                    .count()
+                    == hashes =>
+                {
+                    return (&source[start..j], j + 1 + hashes);
+                }
+                _ => {}
+            }
+            j += 1;
+        }
+        (&source[start.min(bytes.len())..], bytes.len())
+    };
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        let token_line = line;
+        let kind = match c {
+            b'\n' => {
+                line += 1;
+                i += 1;
+                continue;
+            }
+            _ if c.is_ascii_whitespace() => {
+                i += 1;
+                continue;
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+                continue;
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                let mut depth = 0;
+                while i < bytes.len() {
+                    if bytes[i..].starts_with(b"/*") {
+                        depth += 1;
+                        i += 2;
+                    } else if bytes[i..].starts_with(b"*/") {
+                        depth -= 1;
+                        i += 2;
+                        if depth == 0 {
+                            break;
+                        }
+                    } else {
+                        if bytes[i] == b'\n' {
+                            line += 1;
+                        }
+                        i += 1;
+                    }
+                }
+                continue;
+            }
+            b'"' => {
+                let (contents, end) = string(i + 1, 0, false, &mut line);
+                i = end;
+                TokenKind::Str(contents)
+            }
+            b'\'' => {
+                // A character literal, or a lifetime or label
+                if bytes.get(i + 1) == Some(&b'\\') {
+                    i += 3;
+                    while i < bytes.len() && bytes[i] != b'\'' {
+                        i += 1;
+                    }
+                    i += 1;
+                } else {
+                    let len = source[i + 1..].chars().next().map_or(0, char::len_utf8);
+                    if bytes.get(i + 1 + len) == Some(&b'\'') {
+                        i += len + 2;
+                    } else {
+                        i += 1;
+                    }
+                }
+                TokenKind::Other
+            }
+            b'(' | b'[' | b'{' => {
+                i += 1;
+                TokenKind::Open
+            }
+            b')' | b']' | b'}' => {
+                i += 1;
+                TokenKind::Close
+            }
+            _ if c == b'_' || c.is_ascii_alphabetic() => {
+                let start = i;
+                while i < bytes.len() && (bytes[i] == b'_' || bytes[i].is_ascii_alphanumeric()) {
+                    i += 1;
+                }
+                let ident = &source[start..i];
+                let hashes = bytes[i..].iter().take_while(|b| **b == b'#').count();
+                match (ident, bytes.get(i + hashes)) {
+                    ("b" | "c", Some(b'"')) if hashes == 0 => {
+                        let (contents, end) = string(i + 1, 0, false, &mut line);
+                        i = end;
+                        TokenKind::Str(contents)
+                    }
+                    ("r" | "br" | "cr", Some(b'"')) => {
+                        let (contents, end) = string(i + hashes + 1, hashes, true, &mut line);
+                        i = end;
+                        TokenKind::Str(contents)
+                    }
+                    ("r", _) if hashes == 1 => {
+                        // A raw identifier
+                        let start = i + 1;
+                        i = start;
+                        while i < bytes.len()
+                            && (bytes[i] == b'_' || bytes[i].is_ascii_alphanumeric())
+                        {
+                            i += 1;
+                        }
+                        TokenKind::Ident(&source[start..i])
+                    }
+                    _ => TokenKind::Ident(ident),
+                }
+            }
+            _ if c.is_ascii_digit() => {
+                while i < bytes.len() && (bytes[i] == b'_' || bytes[i].is_ascii_alphanumeric()) {
+                    i += 1;
+                }
+                TokenKind::Other
+            }
+            _ => {
+                let c = source[i..].chars().next().unwrap();
+                i += c.len_utf8();
+                TokenKind::Punct(c)
+            }
+        };
+        tokens.push(Token {
+            kind,
+            line: token_line,
+        });
+    }
+    tokens
+}
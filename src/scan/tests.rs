@@ -0,0 +1,110 @@
+use super::*;
+
+fn usage(path: &str, line: usize, kind: UsageKind, feature: &str) -> Usage {
+    Usage {
+        path: path.into(),
+        line,
+        kind,
+        feature: feature.into(),
+    }
+}
+
+#[test]
+fn scan_fixture() {
+    let usages = scan_dir(Path::new("fixtures/scan")).unwrap();
+    let lib = "fixtures/scan/src/lib.rs";
+    let arch = "fixtures/scan/src/arch/mod.rs";
+    assert_eq!(
+        usages,
+        [
+            usage(arch, 5, UsageKind::TargetFeature, "avx2"),
+            usage(arch, 5, UsageKind::TargetFeature, "fma"),
+            usage(arch, 10, UsageKind::Cfg, "avx512"),
+            usage(arch, 10, UsageKind::Cfg, "avx512f"),
+            usage(arch, 11, UsageKind::TargetFeature, "avx512vl"),
+            usage(arch, 11, UsageKind::TargetFeature, "avx512bw"),
+            usage(arch, 16, UsageKind::TargetFeature, "sse4"),
+            usage(lib, 11, UsageKind::Cfg, "sse4.1"),
+            usage(lib, 18, UsageKind::CfgAttr, "avx512f"),
+            usage(lib, 19, UsageKind::TargetFeature, "neon"),
+            usage(lib, 19, UsageKind::TargetFeature, "fp16"),
+        ]
+    );
+}
+
+#[test]
+fn check_fixture() {
+    let usages = scan_dir(Path::new("fixtures/scan")).unwrap();
+    let problems = check(
+        "1.86.0",
+        &["x86_64-unknown-linux-gnu", "aarch64-unknown-linux-gnu"],
+        &usages,
+    )
+    .unwrap();
+    let problems: Vec<String> = problems.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        problems,
+        [
+            "fixtures/scan/src/arch/mod.rs:10: target feature \"avx512\" does not exist, so this cfg is never true",
+            "fixtures/scan/src/arch/mod.rs:10: target feature \"avx512f\" is unstable, requiring #![feature(avx512_target_feature)]",
+            "fixtures/scan/src/arch/mod.rs:11: target feature \"avx512vl\" is unstable, requiring #![feature(avx512_target_feature)]",
+            "fixtures/scan/src/arch/mod.rs:11: target feature \"avx512bw\" is unstable, requiring #![feature(avx512_target_feature)]",
+            "fixtures/scan/src/arch/mod.rs:16: target feature \"sse4\" does not exist",
+            "fixtures/scan/src/lib.rs:18: target feature \"avx512f\" is unstable, requiring #![feature(avx512_target_feature)]",
+        ]
+    );
+}
+
+#[test]
+fn check_gated_on_some_targets() {
+    let usages = scan_source(
+        Path::new("lib.rs"),
+        r#"#[target_feature(enable = "neon")] fn f() {}"#,
+    );
+    let problems = check("1.86.0", &["armv7-unknown-linux-gnueabihf"], &usages).unwrap();
+    assert_eq!(problems[0].kind, ProblemKind::Gated("arm_target_feature"));
+
+    let problems = check(
+        "1.86.0",
+        &["armv7-unknown-linux-gnueabihf", "aarch64-unknown-linux-gnu"],
+        &usages,
+    )
+    .unwrap();
+    assert!(problems.is_empty());
+}
+
+#[test]
+fn scan_after_line_continuation() {
+    let usages = scan_source(
+        Path::new("lib.rs"),
+        "const S: &str = \"a \\\n    b \\\n    c\";\n#[target_feature(enable = \"avx2\")]\nfn f() {}\n",
+    );
+    assert_eq!(
+        usages,
+        [usage("lib.rs", 4, UsageKind::TargetFeature, "avx2")]
+    );
+}
+
+#[test]
+fn tokenize_literals() {
+    let tokens: Vec<TokenKind> = tokenize("r#\"a\"b\"# b\"c\" 'd' 'e: '\\'' r#type 1.5 // f\n")
+        .into_iter()
+        .map(|t| t.kind)
+        .collect();
+    assert_eq!(
+        tokens,
+        [
+            TokenKind::Str("a\"b"),
+            TokenKind::Str("c"),
+            TokenKind::Other,
+            TokenKind::Other,
+            TokenKind::Ident("e"),
+            TokenKind::Punct(':'),
+            TokenKind::Other,
+            TokenKind::Ident("type"),
+            TokenKind::Other,
+            TokenKind::Punct('.'),
+            TokenKind::Other,
+        ]
+    );
+}
@@ -11,30 +11,49 @@ fn smoke() {
         .collect();
 
     let avx2 = features.iter().find(|f| f.name == "avx2").unwrap();
-    assert_eq!(avx2.globally_enabled, false);
+    assert!(!avx2.globally_enabled);
     assert!(
         avx2.implies_features
             .iter()
+            .flatten()
             .find(|f| **f == "avx")
             .is_some()
     );
 
     let sse2 = features.iter().find(|f| f.name == "sse2").unwrap();
-    assert_eq!(sse2.globally_enabled, true);
+    assert!(sse2.globally_enabled);
     assert!(
         sse2.implies_features
             .iter()
+            .flatten()
             .find(|f| **f == "sse")
             .is_some()
     );
     assert!(
         sse2.implies_features
             .iter()
+            .flatten()
             .find(|f| **f == "avx2")
             .is_none()
     );
 }
 
+#[test]
+fn unknown_implications() {
+    let features: Vec<_> = find("1.81.0", "x86_64-unknown-linux-gnu")
+        .unwrap()
+        .collect();
+    assert!(features.iter().all(|f| f.implies_features.is_none()));
+
+    let features: Vec<_> = find("1.85.0", "x86_64-unknown-linux-gnu")
+        .unwrap()
+        .collect();
+    let aes = features.iter().find(|f| f.name == "aes").unwrap();
+    assert_eq!(aes.implies_features, Some(BTreeSet::from(["sse2"])));
+    let adx = features.iter().find(|f| f.name == "adx").unwrap();
+    assert_eq!(adx.implies_features, Some(BTreeSet::new()));
+}
+
 #[test]
 fn compiler_not_found() {
     assert_eq!(
@@ -51,6 +70,45 @@ fn target_not_found() {
     );
 }
 
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn blob_values() {
+    // Values on either side of each encoded width, including the old u16 and 15-bit gate limits
+    let values: Vec<usize> = [0, 127, 128, 16383, 16384, 32767, 32768, 65535, 65536]
+        .into_iter()
+        .chain([u32::MAX as usize, u32::MAX as usize + 1, usize::MAX])
+        .collect();
+    let blob = dev::leb128::encode_all(values.iter().copied());
+    assert_eq!(generated::read_values(&blob).collect::<Vec<_>>(), values);
+}
+
+#[test]
+fn corrupt_blob_values() {
+    // A truncated value ends the sequence
+    assert_eq!(
+        generated::read_values(&[0x05, 0x80]).collect::<Vec<_>>(),
+        [5]
+    );
+
+    // So does a value too large for `usize`, whether it has too many bytes or too many bits
+    let mut blob = vec![0x05];
+    blob.extend([0xff; 16]);
+    blob.extend([0x01, 0x06]);
+    assert_eq!(generated::read_values(&blob).collect::<Vec<_>>(), [5]);
+    let mut blob = vec![0x05];
+    dev::leb128::encode(usize::MAX, &mut blob);
+    *blob.last_mut().unwrap() += 1;
+    blob.push(0x06);
+    assert_eq!(generated::read_values(&blob).collect::<Vec<_>>(), [5]);
+}
+
+#[test]
+fn shipped_data_is_valid() {
+    if let Err(e) = dev::load_strict() {
+        panic!("{}", e);
+    }
+}
+
 #[test]
 fn compare_all() {
     for compiler in rust_target_feature_data_dev::load().unwrap() {
@@ -67,7 +125,7 @@ fn compare(compiler: &str, target: &str, expected: &BTreeSet<dev::TargetFeature>
     if actual.len() != expected.len() {
         bail!("actual and expected are not the same length");
     }
-    for (actual, expected) in actual.into_iter().zip(expected.into_iter()) {
+    for (actual, expected) in actual.into_iter().zip(expected) {
         if actual.name != expected.name {
             bail!("actual name: {}, expected: {}", actual.name, expected.name);
         }
@@ -78,9 +136,7 @@ fn compare(compiler: &str, target: &str, expected: &BTreeSet<dev::TargetFeature>
                 expected.globally_enabled
             );
         }
-        if actual.unstable_feature_gate
-            != expected.unstable_feature_gate.as_ref().map(String::as_str)
-        {
+        if actual.unstable_feature_gate != expected.unstable_feature_gate.as_deref() {
             bail!(
                 "actual unstable_feature_gate: {:?}, expected: {:?}",
                 actual.unstable_feature_gate,
@@ -88,12 +144,13 @@ fn compare(compiler: &str, target: &str, expected: &BTreeSet<dev::TargetFeature>
             );
         }
 
-        let actual_implies_features: Vec<&str> = actual.implies_features.iter().copied().collect();
-        let expected_implies_features: Vec<&str> = expected
+        let actual_implies_features: Option<Vec<&str>> = actual
             .implies_features
-            .iter()
-            .map(String::as_str)
-            .collect();
+            .map(|implies| implies.into_iter().collect());
+        let expected_implies_features: Option<Vec<&str>> = expected
+            .implies_features
+            .as_ref()
+            .map(|implies| implies.iter().map(String::as_str).collect());
 
         if actual_implies_features != expected_implies_features {
             bail!(
@@ -0,0 +1,314 @@
+//! A minimal TOML parser, supporting what configuration files like `.cargo/config.toml` and
+//! `rust-toolchain.toml` typically contain.
+//!
+//! Tables, dotted and quoted keys, strings (including multi-line strings), and arrays (including
+//! arrays spanning several lines) are understood. Other values, including inline tables, are
+//! skipped and parsed as [`Value::Other`].
+
+/// A `key = value` pair, with the key prefixed by its table's key.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct Entry {
+    /// The 1-based line number where this entry starts.
+    pub line: usize,
+    pub key: Vec<String>,
+    pub value: Value,
+}
+
+/// An error parsing TOML.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) struct Error {
+    /// The 1-based line number where the error was found.
+    pub line: usize,
+    pub message: String,
+}
+
+/// Parse TOML into a list of entries, in order.
+pub(crate) fn parse(toml: &str) -> Result<Vec<Entry>, Error> {
+    let mut entries = Vec::new();
+    let mut table: Vec<String> = Vec::new();
+    let mut rest = toml;
+
+    while !rest.is_empty() {
+        let line_number = toml[..toml.len() - rest.len()].matches('\n').count() + 1;
+        let error = |message: String| Error {
+            line: line_number,
+            message,
+        };
+        let (raw_line, next) = rest.split_once('\n').unwrap_or((rest, ""));
+        let line = strip_comment(raw_line).trim();
+        if line.is_empty() {
+            rest = next;
+            continue;
+        }
+
+        if line.starts_with('[') {
+            let header = line
+                .strip_prefix("[[")
+                .and_then(|line| line.strip_suffix("]]"))
+                .or_else(|| line.strip_prefix('[')?.strip_suffix(']'))
+                .ok_or_else(|| error("invalid table header".into()))?;
+            table = parse_key(header).map_err(error)?;
+            rest = next;
+            continue;
+        }
+
+        // Values like arrays and multi-line strings may span several lines, so parse the value
+        // from the rest of the document
+        let (key, value) =
+            split_key_value(raw_line).ok_or_else(|| error("expected key = value".into()))?;
+        let value_start = raw_line.len() - value.len();
+        let (value, after) = parse_value_prefix(&rest[value_start..]).map_err(error)?;
+        let (tail, next) = after.split_once('\n').unwrap_or((after, ""));
+        let tail = strip_comment(tail).trim();
+        if !tail.is_empty() {
+            return Err(error(format!("unexpected {:?} after value", tail)));
+        }
+        entries.push(Entry {
+            line: line_number,
+            key: [table.clone(), parse_key(key).map_err(error)?].concat(),
+            value,
+        });
+        rest = next;
+    }
+    Ok(entries)
+}
+
+/// A TOML value.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) enum Value {
+    String(String),
+    Array(Vec<Value>),
+    /// Numbers, booleans, dates, and inline tables, which are not needed
+    Other,
+}
+
+impl Value {
+    /// Convert an array of strings into a `Vec`.
+    pub fn into_strings(self) -> Option<Vec<String>> {
+        match self {
+            Value::Array(values) => values
+                .into_iter()
+                .map(|value| match value {
+                    Value::String(s) => Some(s),
+                    _ => None,
+                })
+                .collect(),
+            _ => None,
+        }
+    }
+}
+
+/// Remove a trailing `#` comment, respecting quotes.
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match (quote, c) {
+            (Some('"'), '\\') if !escaped => {
+                escaped = true;
+                continue;
+            }
+            (Some(q), c) if c == q && !escaped => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '#') => return &line[..i],
+            _ => {}
+        }
+        escaped = false;
+    }
+    line
+}
+
+/// Split a `key = value` line, where the key may contain a quoted `=`.
+fn split_key_value(line: &str) -> Option<(&str, &str)> {
+    let mut quote = None;
+    for (i, c) in line.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '=') => return Some((&line[..i], &line[i + 1..])),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Parse a dotted key like `target."x86_64-unknown-linux-gnu".rustflags`.
+fn parse_key(key: &str) -> Result<Vec<String>, String> {
+    let mut parts = Vec::new();
+    let mut rest = key.trim();
+    loop {
+        let (part, remainder) = match rest.chars().next() {
+            Some(q @ ('"' | '\'')) => {
+                let end = rest[1..]
+                    .find(q)
+                    .ok_or_else(|| format!("unterminated key {:?}", key))?;
+                (rest[1..1 + end].to_string(), &rest[end + 2..])
+            }
+            Some(_) => {
+                let end = rest.find('.').unwrap_or(rest.len());
+                (rest[..end].trim().to_string(), &rest[end..])
+            }
+            None => return Err(format!("invalid key {:?}", key)),
+        };
+        parts.push(part);
+        rest = remainder.trim_start();
+        match rest.strip_prefix('.') {
+            Some(remainder) => rest = remainder.trim_start(),
+            None if rest.is_empty() => return Ok(parts),
+            None => return Err(format!("invalid key {:?}", key)),
+        }
+    }
+}
+
+/// Parse a value at the start of `input`, returning it and the rest of `input`.
+///
+/// Strings and arrays are understood. Other values, including inline tables, are skipped.
+fn parse_value_prefix(input: &str) -> Result<(Value, &str), String> {
+    let input = input.trim_start_matches([' ', '\t']);
+    if let Some(rest) = input.strip_prefix("\"\"\"") {
+        // A newline immediately after the opening quotes is not part of the string
+        let rest = rest.strip_prefix('\n').unwrap_or(rest);
+        let end = rest.find("\"\"\"").ok_or("unterminated string")?;
+        // Up to two more quotes may end the string
+        let end = end + rest[end + 3..].len() - rest[end + 3..].trim_start_matches('"').len();
+        let value = unescape(&rest[..end], true)?;
+        return Ok((Value::String(value), &rest[end + 3..]));
+    }
+    if let Some(rest) = input.strip_prefix("\'\'\'") {
+        let rest = rest.strip_prefix('\n').unwrap_or(rest);
+        let end = rest.find("\'\'\'").ok_or("unterminated string")?;
+        let end = end + rest[end + 3..].len() - rest[end + 3..].trim_start_matches('\'').len();
+        return Ok((Value::String(rest[..end].into()), &rest[end + 3..]));
+    }
+    match input.chars().next() {
+        Some('"') => {
+            let mut escaped = false;
+            for (i, c) in input.char_indices().skip(1) {
+                match c {
+                    '"' if !escaped => {
+                        let value = unescape(&input[1..i], false)?;
+                        return Ok((Value::String(value), &input[i + 1..]));
+                    }
+                    '\n' => break,
+                    '\\' => {
+                        escaped = !escaped;
+                        continue;
+                    }
+                    _ => {}
+                }
+                escaped = false;
+            }
+            Err("unterminated string".into())
+        }
+        Some('\'') => {
+            let line = input.split('\n').next().unwrap_or_default();
+            let end = line[1..].find('\'').ok_or("unterminated string")?;
+            Ok((Value::String(input[1..1 + end].into()), &input[end + 2..]))
+        }
+        Some('[') => {
+            let mut values = Vec::new();
+            let mut rest = skip_whitespace_and_comments(&input[1..]);
+            loop {
+                if let Some(remainder) = rest.strip_prefix(']') {
+                    return Ok((Value::Array(values), remainder));
+                }
+                if rest.is_empty() {
+                    return Err("unterminated array".into());
+                }
+                let (value, remainder) = parse_value_prefix(rest)?;
+                values.push(value);
+                rest = skip_whitespace_and_comments(remainder);
+                if let Some(remainder) = rest.strip_prefix(',') {
+                    rest = skip_whitespace_and_comments(remainder);
+                } else if rest.is_empty() {
+                    return Err("unterminated array".into());
+                } else if !rest.starts_with(']') {
+                    return Err("expected , or ] in array".into());
+                }
+            }
+        }
+        Some('{') => {
+            // Inline tables are not needed, but their values must be skipped correctly
+            let mut rest = input[1..].trim_start_matches([' ', '\t']);
+            loop {
+                if let Some(remainder) = rest.strip_prefix('}') {
+                    return Ok((Value::Other, remainder));
+                }
+                let line = rest.split('\n').next().unwrap_or_default();
+                let (key, value) = split_key_value(line).ok_or("expected key = value")?;
+                parse_key(key)?;
+                let (_, remainder) = parse_value_prefix(&rest[line.len() - value.len()..])?;
+                rest = remainder.trim_start_matches([' ', '\t']);
+                if let Some(remainder) = rest.strip_prefix(',') {
+                    rest = remainder.trim_start_matches([' ', '\t']);
+                } else if !rest.starts_with('}') {
+                    return Err("expected , or } in inline table".into());
+                }
+            }
+        }
+        Some('\n' | '\r' | '#') | None => Err("expected a value".into()),
+        Some(_) => {
+            // Numbers, booleans, and dates are not needed
+            let end = input
+                .find([',', ']', '}', '#', '\r', '\n'])
+                .unwrap_or(input.len());
+            Ok((Value::Other, &input[end..]))
+        }
+    }
+}
+
+/// Skip whitespace, newlines, and comments between array elements.
+fn skip_whitespace_and_comments(mut input: &str) -> &str {
+    loop {
+        input = input.trim_start();
+        match input.strip_prefix('#') {
+            Some(comment) => input = comment.split_once('\n').map_or("", |(_, rest)| rest),
+            None => return input,
+        }
+    }
+}
+
+/// Process escapes in a basic string. In multi-line strings, a backslash at the end of a line
+/// removes the newline and any whitespace which follows it.
+fn unescape(string: &str, multi_line: bool) -> Result<String, String> {
+    let mut value = String::new();
+    let mut chars = string.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            value.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => value.push('\n'),
+            Some('t') => value.push('\t'),
+            Some('r') => value.push('\r'),
+            Some(c @ (' ' | '\t' | '\r' | '\n')) if multi_line => {
+                let mut newline = c == '\n';
+                while let Some(c @ (' ' | '\t' | '\r' | '\n')) = chars.peek().copied() {
+                    newline |= c == '\n';
+                    chars.next();
+                }
+                if !newline {
+                    return Err("invalid escape".into());
+                }
+            }
+            Some('b') => value.push('\u{8}'),
+            Some('f') => value.push('\u{c}'),
+            Some(c @ ('"' | '\\')) => value.push(c),
+            Some(c @ ('u' | 'U')) => {
+                let len = if c == 'u' { 4 } else { 8 };
+                let digits: String = chars.by_ref().take(len).collect();
+                let c = Some(&digits)
+                    .filter(|digits| digits.len() == len)
+                    .filter(|digits| digits.chars().all(|c| c.is_ascii_hexdigit()))
+                    .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+                    .and_then(char::from_u32)
+                    .ok_or("invalid unicode escape")?;
+                value.push(c);
+            }
+            Some(_) => return Err("invalid escape".into()),
+            None => return Err("unterminated string".into()),
+        }
+    }
+    Ok(value)
+}
@@ -0,0 +1,269 @@
+//! Choosing a Rust version and targets from a project's `rust-toolchain.toml`.
+//!
+//! ```
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use rust_target_feature_data::toolchain::Toolchain;
+//!
+//! let toolchain = Toolchain::parse(r#"
+//!     [toolchain]
+//!     channel = "nightly-2025-03-01"
+//!     targets = ["aarch64-unknown-linux-gnu"]
+//! "#)?;
+//! assert_eq!(toolchain.rust_version, "1.87.0");
+//!
+//! for (_target, features) in toolchain.datasets()? {
+//!     assert!(features.iter().any(|f| f.name == "neon"));
+//! }
+//! # Ok(()) }
+//! ```
+//!
+//! # Channels
+//!
+//! Channels are mapped to embedded Rust versions as follows:
+//!
+//! | Channel              | Rust version                                                        |
+//! |----------------------|---------------------------------------------------------------------|
+//! | `1.86.0`, `1.86.1`   | `1.86.0`                                                            |
+//! | `1.86`               | `1.86.0`                                                            |
+//! | `beta-YYYY-MM-DD`    | One minor version after the latest stable release on that date      |
+//! | `nightly-YYYY-MM-DD` | Two minor versions after the latest stable release on that date     |
+//!
+//! Stable release dates are known up to 1.89.0, released 2025-08-07. Betas and nightlies are
+//! attributed to versions by these dates, but the beta branch is actually created a few days
+//! before each release, so a nightly from those few days may be one version newer than reported.
+//!
+//! Unpinned channels like `stable`, `beta`, and `nightly` change over time, so they produce
+//! [`ToolchainError::UnpinnedChannel`] rather than a guess.
+
+use crate::{NotFoundError, TargetFeature};
+use std::path::Path;
+
+#[cfg(test)]
+mod tests;
+
+/// Stable release dates, in order.
+const RELEASE_DATES: &[(u32, &str)] = &[
+    (81, "2024-09-05"),
+    (82, "2024-10-17"),
+    (83, "2024-11-28"),
+    (84, "2025-01-09"),
+    (85, "2025-02-20"),
+    (86, "2025-04-03"),
+    (87, "2025-05-15"),
+    (88, "2025-06-26"),
+    (89, "2025-08-07"),
+];
+
+/// A toolchain specified by `rust-toolchain.toml` or `rust-toolchain`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Toolchain {
+    /// The channel, as written.
+    pub channel: String,
+    /// The embedded Rust version corresponding to the channel.
+    pub rust_version: &'static str,
+    /// Targets listed in `targets = […]`.
+    pub targets: Vec<String>,
+}
+
+/// An error reading a toolchain file.
+#[derive(Debug)]
+pub enum ToolchainError {
+    /// Neither `rust-toolchain.toml` nor `rust-toolchain` exists
+    NoToolchainFile,
+    /// The toolchain file could not be read
+    Io(std::io::Error),
+    /// The toolchain file is not valid TOML
+    Syntax { line: usize, message: String },
+    /// The toolchain file does not specify a channel
+    MissingChannel,
+    /// The channel is not pinned to a version or date, like `stable` or `nightly`
+    UnpinnedChannel(String),
+    /// The channel is not understood
+    UnknownChannel(String),
+    /// The channel is dated outside the known release dates
+    UnknownDate(String),
+    /// The channel corresponds to this Rust version, which is not embedded
+    UnsupportedVersion { channel: String, version: String },
+}
+
+impl std::error::Error for ToolchainError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ToolchainError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ToolchainError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ToolchainError::NoToolchainFile => {
+                write!(f, "neither rust-toolchain.toml nor rust-toolchain found")
+            }
+            ToolchainError::Io(e) => write!(f, "reading toolchain file: {}", e),
+            ToolchainError::Syntax { line, message } => {
+                write!(f, "toolchain file line {}: {}", line, message)
+            }
+            ToolchainError::MissingChannel => write!(f, "toolchain file has no channel"),
+            ToolchainError::UnpinnedChannel(channel) => {
+                write!(
+                    f,
+                    "channel {:?} is not pinned to a version or date",
+                    channel
+                )
+            }
+            ToolchainError::UnknownChannel(channel) => {
+                write!(f, "channel {:?} not understood", channel)
+            }
+            ToolchainError::UnknownDate(channel) => {
+                write!(
+                    f,
+                    "channel {:?} is outside the known release dates",
+                    channel
+                )
+            }
+            ToolchainError::UnsupportedVersion { channel, version } => write!(
+                f,
+                "channel {:?} corresponds to Rust {}, which is not embedded",
+                channel, version
+            ),
+        }
+    }
+}
+
+impl From<crate::toml::Error> for ToolchainError {
+    fn from(error: crate::toml::Error) -> Self {
+        ToolchainError::Syntax {
+            line: error.line,
+            message: error.message,
+        }
+    }
+}
+
+impl Toolchain {
+    /// Read the toolchain file in `dir`.
+    ///
+    /// Like rustup, this prefers `rust-toolchain` over `rust-toolchain.toml` if both exist.
+    pub fn load(dir: &Path) -> Result<Self, ToolchainError> {
+        for name in ["rust-toolchain", "rust-toolchain.toml"] {
+            match std::fs::read_to_string(dir.join(name)) {
+                Ok(contents) => return Self::parse(&contents),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(ToolchainError::Io(e)),
+            }
+        }
+        Err(ToolchainError::NoToolchainFile)
+    }
+
+    /// Parse the contents of a toolchain file.
+    ///
+    /// This accepts both the TOML format and the legacy format, which is just a channel name.
+    pub fn parse(contents: &str) -> Result<Self, ToolchainError> {
+        let legacy = contents.trim();
+        if !legacy.is_empty() && !legacy.contains(['=', '[', '\n']) {
+            return Self::new(legacy, Vec::new());
+        }
+
+        let mut channel = None;
+        let mut targets = Vec::new();
+        for entry in crate::toml::parse(contents)? {
+            let error = |message: &str| ToolchainError::Syntax {
+                line: entry.line,
+                message: message.into(),
+            };
+            let key: Vec<&str> = entry.key.iter().map(String::as_str).collect();
+            match (key.as_slice(), entry.value) {
+                (["toolchain", "channel"], crate::toml::Value::String(value)) => {
+                    channel = Some(value)
+                }
+                (["toolchain", "channel"], _) => return Err(error("channel must be a string")),
+                (["toolchain", "targets"], value) => {
+                    targets = value
+                        .into_strings()
+                        .ok_or_else(|| error("targets must be an array of strings"))?
+                }
+                _ => {}
+            }
+        }
+        Self::new(&channel.ok_or(ToolchainError::MissingChannel)?, targets)
+    }
+
+    fn new(channel: &str, targets: Vec<String>) -> Result<Self, ToolchainError> {
+        Ok(Toolchain {
+            channel: channel.into(),
+            rust_version: resolve_channel(channel)?,
+            targets,
+        })
+    }
+
+    /// Find the target features of each listed target.
+    pub fn datasets(&self) -> Result<Vec<(&str, Vec<TargetFeature>)>, NotFoundError> {
+        self.targets
+            .iter()
+            .map(|target| {
+                Ok((
+                    target.as_str(),
+                    crate::find(self.rust_version, target)?.collect(),
+                ))
+            })
+            .collect()
+    }
+}
+
+/// Map a channel to an embedded Rust version, as described in the [module documentation](self).
+pub fn resolve_channel(channel: &str) -> Result<&'static str, ToolchainError> {
+    let unsupported = |version: String| ToolchainError::UnsupportedVersion {
+        channel: channel.into(),
+        version,
+    };
+
+    if let Some((channel_name, date)) = channel.split_once('-') {
+        let offset = match channel_name {
+            "beta" => 1,
+            "nightly" => 2,
+            _ => 0,
+        };
+        if offset > 0 {
+            if !is_date(date) {
+                return Err(ToolchainError::UnknownChannel(channel.into()));
+            }
+            let next_release = RELEASE_DATES.iter().position(|(_, d)| *d > date);
+            let stable = match next_release {
+                Some(0) | None => return Err(ToolchainError::UnknownDate(channel.into())),
+                Some(next) => RELEASE_DATES[next - 1].0,
+            };
+            let version = format!("1.{}.0", stable + offset);
+            return crate::resolve_version(&version).ok_or_else(|| unsupported(version));
+        }
+    }
+
+    match channel {
+        "stable" | "beta" | "nightly" => Err(ToolchainError::UnpinnedChannel(channel.into())),
+        _ => {
+            let mut parts = channel.split('.');
+            let valid = parts.next() == Some("1")
+                && parts
+                    .next()
+                    .is_some_and(|minor| minor.parse::<u32>().is_ok())
+                && parts
+                    .next()
+                    .is_none_or(|patch| patch.parse::<u32>().is_ok())
+                && parts.next().is_none();
+            if !valid {
+                return Err(ToolchainError::UnknownChannel(channel.into()));
+            }
+            crate::resolve_version(channel).ok_or_else(|| unsupported(channel.into()))
+        }
+    }
+}
+
+/// Check for a `YYYY-MM-DD` date.
+fn is_date(date: &str) -> bool {
+    let bytes = date.as_bytes();
+    bytes.len() == 10
+        && bytes.iter().enumerate().all(|(i, b)| match i {
+            4 | 7 => *b == b'-',
+            _ => b.is_ascii_digit(),
+        })
+}
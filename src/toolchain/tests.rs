@@ -0,0 +1,102 @@
+use super::*;
+
+#[test]
+fn load_fixtures() {
+    let toolchain = Toolchain::load(Path::new("fixtures/toolchain/toml")).unwrap();
+    assert_eq!(
+        toolchain,
+        Toolchain {
+            channel: "1.86.0".into(),
+            rust_version: "1.86.0",
+            targets: vec![
+                "x86_64-unknown-linux-gnu".into(),
+                "aarch64-apple-darwin".into()
+            ],
+        }
+    );
+    let datasets = toolchain.datasets().unwrap();
+    assert_eq!(datasets.len(), 2);
+    assert_eq!(datasets[0].0, "x86_64-unknown-linux-gnu");
+    assert!(datasets[0].1.iter().any(|f| f.name == "avx2"));
+    assert_eq!(datasets[1].0, "aarch64-apple-darwin");
+    assert!(datasets[1].1.iter().any(|f| f.name == "neon"));
+
+    let toolchain = Toolchain::load(Path::new("fixtures/toolchain/legacy")).unwrap();
+    assert_eq!(toolchain.rust_version, "1.85.0");
+    assert!(toolchain.targets.is_empty());
+
+    let toolchain = Toolchain::load(Path::new("fixtures/toolchain/both")).unwrap();
+    assert_eq!(toolchain.channel, "1.85.1");
+    assert_eq!(toolchain.rust_version, "1.85.0");
+
+    assert!(matches!(
+        Toolchain::load(Path::new("fixtures/toolchain")),
+        Err(ToolchainError::NoToolchainFile)
+    ));
+}
+
+#[test]
+fn channels() {
+    assert_eq!(resolve_channel("1.86.0").unwrap(), "1.86.0");
+    assert_eq!(resolve_channel("1.86").unwrap(), "1.86.0");
+    assert_eq!(resolve_channel("1.85.1").unwrap(), "1.85.0");
+    assert_eq!(resolve_channel("beta-2025-04-02").unwrap(), "1.86.0");
+    assert_eq!(resolve_channel("beta-2025-04-03").unwrap(), "1.87.0");
+    assert_eq!(resolve_channel("nightly-2025-01-09").unwrap(), "1.86.0");
+    assert_eq!(resolve_channel("nightly-2025-02-19").unwrap(), "1.86.0");
+    assert_eq!(resolve_channel("nightly-2025-02-20").unwrap(), "1.87.0");
+
+    for channel in ["stable", "beta", "nightly"] {
+        assert!(matches!(
+            resolve_channel(channel),
+            Err(ToolchainError::UnpinnedChannel(_))
+        ));
+    }
+    for channel in [
+        "1",
+        "1.x",
+        "2.0.0",
+        "nightly-2025-1-1",
+        "stable-2025-01-01",
+        "my-toolchain",
+    ] {
+        assert!(
+            matches!(
+                resolve_channel(channel),
+                Err(ToolchainError::UnknownChannel(_))
+            ),
+            "{}",
+            channel
+        );
+    }
+    for channel in ["nightly-2024-01-01", "beta-2026-01-01"] {
+        assert!(matches!(
+            resolve_channel(channel),
+            Err(ToolchainError::UnknownDate(_))
+        ));
+    }
+    assert!(matches!(
+        resolve_channel("1.70.0"),
+        Err(ToolchainError::UnsupportedVersion { .. })
+    ));
+    assert!(matches!(
+        resolve_channel("nightly-2025-06-01"),
+        Err(ToolchainError::UnsupportedVersion { .. })
+    ));
+}
+
+#[test]
+fn parse_errors() {
+    assert!(matches!(
+        Toolchain::parse("[toolchain]\ncomponents = [\"rustfmt\"]\n"),
+        Err(ToolchainError::MissingChannel)
+    ));
+    assert!(matches!(
+        Toolchain::parse("[toolchain]\nchannel = \"stable\"\n"),
+        Err(ToolchainError::UnpinnedChannel(_))
+    ));
+    assert!(matches!(
+        Toolchain::parse("[toolchain]\ntargets = \"x86_64-unknown-linux-gnu\"\n"),
+        Err(ToolchainError::Syntax { line: 2, .. })
+    ));
+}
@@ -0,0 +1,195 @@
+//! Parsing target triples into their components.
+//!
+//! Target triples name an architecture, a vendor, an operating system, and an environment, but
+//! despite the name, they don't always have three parts. Components are omitted when they don't
+//! apply, and the environment is often combined with an ABI suffix:
+//!
+//! ```
+//! use rust_target_feature_data::triple::TargetTriple;
+//!
+//! let triple = TargetTriple::parse("armv7-unknown-linux-gnueabihf").unwrap();
+//! assert_eq!(triple.arch, "arm");
+//! assert_eq!(triple.sub_arch, "armv7");
+//! assert_eq!(triple.vendor, Some("unknown"));
+//! assert_eq!(triple.os, Some("linux"));
+//! assert_eq!(triple.env, Some("gnu"));
+//! assert_eq!(triple.abi, Some("eabihf"));
+//!
+//! let triple = TargetTriple::parse("thumbv8m.main-none-eabi").unwrap();
+//! assert_eq!((triple.arch, triple.sub_arch), ("arm", "thumbv8m.main"));
+//! assert_eq!((triple.vendor, triple.os, triple.env), (None, Some("none"), None));
+//! assert_eq!(triple.to_string(), "thumbv8m.main-none-eabi");
+//! ```
+//!
+//! Components are reported as written, so `os` is `darwin` rather than the `cfg(target_os)` value
+//! `macos`. The exception is `arch`, which is the `cfg(target_arch)` value.
+
+use crate::NotFoundError;
+
+#[cfg(test)]
+mod tests;
+
+/// Vendors which appear in target triples, used to tell `arch-vendor-os` from `arch-os-env`.
+const VENDORS: &[&str] = &[
+    "amd", "apple", "esp", "esp32", "esp32s2", "esp32s3", "fortanix", "ibm", "kmc", "mti",
+    "nintendo", "nvidia", "openwrt", "pc", "risc0", "sony", "sun", "unikraft", "unknown", "uwp",
+    "wali", "win7", "wrs",
+];
+
+/// ABI suffixes which may follow an environment, and how each is written when it does.
+const ABIS: &[(&str, &str)] = &[
+    ("eabihf", "eabihf"),
+    ("eabi", "eabi"),
+    ("abiv2hf", "abiv2hf"),
+    ("abiv2", "abiv2"),
+    ("abi64", "abi64"),
+    ("spe", "spe"),
+    ("x32", "x32"),
+    ("ilp32", "_ilp32"),
+];
+
+/// A target triple, split into its components.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct TargetTriple<'a> {
+    /// The `cfg(target_arch)` value, e.g. `"arm"`.
+    pub arch: &'a str,
+    /// The architecture as written, which may name a sub-architecture, e.g. `"armv7"`.
+    pub sub_arch: &'a str,
+    /// The vendor, e.g. `"unknown"` or `"apple"`.
+    pub vendor: Option<&'a str>,
+    /// The operating system, e.g. `"linux"`, or `"none"` for bare metal targets.
+    pub os: Option<&'a str>,
+    /// The environment, e.g. `"gnu"` or `"musl"`.
+    pub env: Option<&'a str>,
+    /// The ABI suffix, e.g. `"eabihf"`.
+    pub abi: Option<&'a str>,
+}
+
+impl<'a> TargetTriple<'a> {
+    /// Split a target triple into its components.
+    ///
+    /// Returns `None` if the triple has fewer than two or more than four components, or any
+    /// component is empty.
+    pub fn parse(triple: &'a str) -> Option<Self> {
+        let parts: Vec<&str> = triple.split('-').collect();
+        if parts.iter().any(|part| part.is_empty()) {
+            return None;
+        }
+        let (sub_arch, vendor, os, env) = match parts.as_slice() {
+            [sub_arch, os] => (*sub_arch, None, Some(*os), None),
+            [sub_arch, vendor, os] if VENDORS.contains(vendor) => {
+                (*sub_arch, Some(*vendor), Some(*os), None)
+            }
+            [sub_arch, os, env] => (*sub_arch, None, Some(*os), Some(*env)),
+            [sub_arch, vendor, os, env] => (*sub_arch, Some(*vendor), Some(*os), Some(*env)),
+            _ => return None,
+        };
+        let (env, abi) = match env {
+            Some(env) => split_abi(env),
+            None => (None, None),
+        };
+        Some(TargetTriple {
+            arch: arch(sub_arch),
+            sub_arch,
+            vendor,
+            os,
+            env,
+            abi,
+        })
+    }
+}
+
+impl std::fmt::Display for TargetTriple<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.sub_arch)?;
+        for component in [self.vendor, self.os].into_iter().flatten() {
+            write!(f, "-{}", component)?;
+        }
+        match (self.env, self.abi) {
+            (Some(env), Some(abi)) => {
+                // An ABI this module doesn't know is written as it is
+                let written = ABIS
+                    .iter()
+                    .find(|(name, _)| *name == abi)
+                    .map_or(abi, |(_, written)| written);
+                write!(f, "-{}{}", env, written)
+            }
+            (Some(component), None) | (None, Some(component)) => write!(f, "-{}", component),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+/// Split the environment component into an environment and an ABI suffix.
+fn split_abi(component: &str) -> (Option<&str>, Option<&str>) {
+    for (abi, written) in ABIS {
+        if component == *abi {
+            return (None, Some(abi));
+        }
+        match component.strip_suffix(written) {
+            Some(env) if !env.is_empty() => return (Some(env), Some(abi)),
+            _ => {}
+        }
+    }
+    (Some(component), None)
+}
+
+/// Determine the `cfg(target_arch)` value of the architecture component of a target triple.
+fn arch(sub_arch: &str) -> &str {
+    match sub_arch {
+        "i386" | "i586" | "i686" => "x86",
+        "x86_64h" => "x86_64",
+        "aarch64_be" | "arm64_32" | "arm64e" => "aarch64",
+        "arm64ec" => "arm64ec",
+        "bpfeb" | "bpfel" => "bpf",
+        "mipsel" => "mips",
+        "mips64el" => "mips64",
+        "mipsisa32r6" | "mipsisa32r6el" => "mips32r6",
+        "mipsisa64r6" | "mipsisa64r6el" => "mips64r6",
+        "powerpc64le" => "powerpc64",
+        "sparcv9" => "sparc64",
+        "wasm32v1" => "wasm32",
+        _ if sub_arch.starts_with("riscv32") => "riscv32",
+        _ if sub_arch.starts_with("riscv64") => "riscv64",
+        _ if sub_arch.starts_with("arm") || sub_arch.starts_with("thumb") => "arm",
+        _ => sub_arch,
+    }
+}
+
+/// List the embedded targets of a Rust version.
+pub fn targets(rust_version: &str) -> Result<Vec<TargetTriple<'static>>, NotFoundError> {
+    let (_, targets) = crate::generated::all()
+        .find(|(version, _)| *version == rust_version)
+        .ok_or_else(|| NotFoundError::CompilerNotFound(rust_version.into()))?;
+    Ok(targets
+        .filter_map(|(triple, _)| TargetTriple::parse(triple))
+        .collect())
+}
+
+/// List the embedded targets of a Rust version with a `cfg(target_arch)`, like `"aarch64"`.
+pub fn targets_with_arch(
+    rust_version: &str,
+    arch: &str,
+) -> Result<Vec<TargetTriple<'static>>, NotFoundError> {
+    let mut targets = targets(rust_version)?;
+    targets.retain(|triple| triple.arch == arch);
+    Ok(targets)
+}
+
+/// List the embedded targets of a Rust version with an operating system, like `"linux"`.
+pub fn targets_with_os(
+    rust_version: &str,
+    os: &str,
+) -> Result<Vec<TargetTriple<'static>>, NotFoundError> {
+    let mut targets = targets(rust_version)?;
+    targets.retain(|triple| triple.os == Some(os));
+    Ok(targets)
+}
+
+/// Determine the `cfg(target_arch)` value of a target triple.
+pub(crate) fn target_arch(triple: &str) -> &str {
+    match TargetTriple::parse(triple) {
+        Some(triple) => triple.arch,
+        None => arch(triple.split('-').next().unwrap_or(triple)),
+    }
+}
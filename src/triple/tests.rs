@@ -0,0 +1,158 @@
+use super::*;
+
+#[test]
+fn every_embedded_triple_round_trips() {
+    for (version, targets) in crate::generated::all() {
+        for (triple, _) in targets {
+            let parsed = TargetTriple::parse(triple)
+                .unwrap_or_else(|| panic!("{} {} did not parse", version, triple));
+            assert_eq!(parsed.to_string(), triple, "{}", version);
+            assert_eq!(TargetTriple::parse(&parsed.to_string()), Some(parsed));
+        }
+    }
+}
+
+#[test]
+fn display_unknown_abi() {
+    let triple = TargetTriple {
+        abi: Some("n32"),
+        ..TargetTriple::parse("mips64-unknown-linux-gnuabi64").unwrap()
+    };
+    assert_eq!(triple.to_string(), "mips64-unknown-linux-gnun32");
+}
+
+#[test]
+fn components() {
+    let parse = |triple| {
+        let triple = TargetTriple::parse(triple).unwrap();
+        (
+            triple.arch,
+            triple.sub_arch,
+            triple.vendor,
+            triple.os,
+            triple.env,
+            triple.abi,
+        )
+    };
+    assert_eq!(
+        parse("x86_64-unknown-linux-gnu"),
+        (
+            "x86_64",
+            "x86_64",
+            Some("unknown"),
+            Some("linux"),
+            Some("gnu"),
+            None
+        )
+    );
+    assert_eq!(
+        parse("aarch64-apple-darwin"),
+        (
+            "aarch64",
+            "aarch64",
+            Some("apple"),
+            Some("darwin"),
+            None,
+            None
+        )
+    );
+    assert_eq!(
+        parse("arm-linux-androideabi"),
+        (
+            "arm",
+            "arm",
+            None,
+            Some("linux"),
+            Some("android"),
+            Some("eabi")
+        )
+    );
+    assert_eq!(
+        parse("aarch64_be-unknown-linux-gnu_ilp32"),
+        (
+            "aarch64",
+            "aarch64_be",
+            Some("unknown"),
+            Some("linux"),
+            Some("gnu"),
+            Some("ilp32")
+        )
+    );
+    assert_eq!(
+        parse("mips64el-unknown-linux-gnuabi64"),
+        (
+            "mips64",
+            "mips64el",
+            Some("unknown"),
+            Some("linux"),
+            Some("gnu"),
+            Some("abi64")
+        )
+    );
+    assert_eq!(
+        parse("armv6-unknown-netbsd-eabihf"),
+        (
+            "arm",
+            "armv6",
+            Some("unknown"),
+            Some("netbsd"),
+            None,
+            Some("eabihf")
+        )
+    );
+    assert_eq!(
+        parse("wasm32-wasip1-threads"),
+        (
+            "wasm32",
+            "wasm32",
+            None,
+            Some("wasip1"),
+            Some("threads"),
+            None
+        )
+    );
+    assert_eq!(
+        parse("avr-none"),
+        ("avr", "avr", None, Some("none"), None, None)
+    );
+    assert_eq!(
+        parse("riscv32imac-esp-espidf"),
+        (
+            "riscv32",
+            "riscv32imac",
+            Some("esp"),
+            Some("espidf"),
+            None,
+            None
+        )
+    );
+
+    assert_eq!(TargetTriple::parse("x86_64"), None);
+    assert_eq!(TargetTriple::parse("x86_64--linux"), None);
+    assert_eq!(TargetTriple::parse("a-b-c-d-e"), None);
+}
+
+#[test]
+fn queries() {
+    let aarch64 = targets_with_arch("1.86.0", "aarch64").unwrap();
+    assert!(aarch64.iter().any(|t| t.sub_arch == "arm64e"));
+    assert!(aarch64.iter().all(|t| t.arch == "aarch64"));
+
+    let linux = targets_with_os("1.86.0", "linux").unwrap();
+    assert!(
+        linux
+            .iter()
+            .any(|t| t.to_string() == "x86_64-unknown-linux-gnu")
+    );
+    assert!(linux.iter().all(|t| t.os == Some("linux")));
+
+    assert_eq!(
+        targets("1.86.0").unwrap().len(),
+        crate::generated::all()
+            .find(|(version, _)| *version == "1.86.0")
+            .unwrap()
+            .1
+            .count()
+    );
+    assert!(targets("1.0.0").is_err());
+}